@@ -10,6 +10,11 @@ args@{
     "nilang-parser/default"
     "nilang-generator/default"
     "nilang-transformer/default"
+    "nilang-interpreter/default"
+    "nilang-lsp/default"
+    "nilang-formatter/default"
+    "nilang-linter/default"
+    "nilang-optimizer/default"
     "nilang-runner/default"
   ],
   rustPackages,
@@ -58,6 +63,11 @@ in
     nilang-parser = rustPackages.unknown.nilang-parser."0.1.0";
     nilang-generator = rustPackages.unknown.nilang-generator."0.1.0";
     nilang-transformer = rustPackages.unknown.nilang-transformer."0.1.0";
+    nilang-interpreter = rustPackages.unknown.nilang-interpreter."0.1.0";
+    nilang-lsp = rustPackages.unknown.nilang-lsp."0.1.0";
+    nilang-formatter = rustPackages.unknown.nilang-formatter."0.1.0";
+    nilang-linter = rustPackages.unknown.nilang-linter."0.1.0";
+    nilang-optimizer = rustPackages.unknown.nilang-optimizer."0.1.0";
     nilang-runner = rustPackages.unknown.nilang-runner."0.1.0";
   };
   "registry+https://github.com/rust-lang/crates.io-index".colored."2.2.0" = overridableMkRustCrate (profileName: rec {
@@ -115,6 +125,22 @@ in
     src = fetchCratesIo { inherit name version; sha256 = "bbd2bcb4c963f2ddae06a2efc7e9f3591312473c50c6685e1f298068316e66fe"; };
   });
   
+  "unknown".nilang-formatter."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "nilang-formatter";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    dependencies = {
+      errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
+      nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+      nilang_parser = (rustPackages."unknown".nilang-parser."0.1.0" { inherit profileName; }).out;
+      nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
+    };
+    devDependencies = {
+      nilang_interpreter = (rustPackages."unknown".nilang-interpreter."0.1.0" { inherit profileName; }).out;
+    };
+  });
+  
   "unknown".nilang-generator."0.1.0" = overridableMkRustCrate (profileName: rec {
     name = "nilang-generator";
     version = "0.1.0";
@@ -126,6 +152,21 @@ in
     };
   });
   
+  "unknown".nilang-interpreter."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "nilang-interpreter";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    dependencies = {
+      errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
+      nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
+    };
+    devDependencies = {
+      nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+      nilang_parser = (rustPackages."unknown".nilang-parser."0.1.0" { inherit profileName; }).out;
+    };
+  });
+  
   "unknown".nilang-lexer."0.1.0" = overridableMkRustCrate (profileName: rec {
     name = "nilang-lexer";
     version = "0.1.0";
@@ -137,6 +178,48 @@ in
     };
   });
   
+  "unknown".nilang-linter."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "nilang-linter";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    dependencies = {
+      colored = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".colored."2.2.0" { inherit profileName; }).out;
+      errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
+      nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+      nilang_parser = (rustPackages."unknown".nilang-parser."0.1.0" { inherit profileName; }).out;
+      nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "unknown".nilang-lsp."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "nilang-lsp";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    dependencies = {
+      colored = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".colored."2.2.0" { inherit profileName; }).out;
+      errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
+      nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+      nilang_parser = (rustPackages."unknown".nilang-parser."0.1.0" { inherit profileName; }).out;
+      nilang_transformer = (rustPackages."unknown".nilang-transformer."0.1.0" { inherit profileName; }).out;
+      nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
+    };
+  });
+  
+  "unknown".nilang-optimizer."0.1.0" = overridableMkRustCrate (profileName: rec {
+    name = "nilang-optimizer";
+    version = "0.1.0";
+    registry = "unknown";
+    src = fetchCrateLocal workspaceSrc;
+    dependencies = {
+      nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
+    };
+    devDependencies = {
+      nilang_interpreter = (rustPackages."unknown".nilang-interpreter."0.1.0" { inherit profileName; }).out;
+    };
+  });
+  
   "unknown".nilang-parser."0.1.0" = overridableMkRustCrate (profileName: rec {
     name = "nilang-parser";
     version = "0.1.0";
@@ -146,6 +229,9 @@ in
       errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
       nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
     };
+    devDependencies = {
+      nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+    };
   });
   
   "unknown".nilang-runner."0.1.0" = overridableMkRustCrate (profileName: rec {
@@ -157,8 +243,12 @@ in
       colored = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".colored."2.2.0" { inherit profileName; }).out;
       errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
       eyre = (rustPackages."registry+https://github.com/rust-lang/crates.io-index".eyre."0.6.12" { inherit profileName; }).out;
+      nilang_formatter = (rustPackages."unknown".nilang-formatter."0.1.0" { inherit profileName; }).out;
       nilang_generator = (rustPackages."unknown".nilang-generator."0.1.0" { inherit profileName; }).out;
+      nilang_interpreter = (rustPackages."unknown".nilang-interpreter."0.1.0" { inherit profileName; }).out;
       nilang_lexer = (rustPackages."unknown".nilang-lexer."0.1.0" { inherit profileName; }).out;
+      nilang_linter = (rustPackages."unknown".nilang-linter."0.1.0" { inherit profileName; }).out;
+      nilang_optimizer = (rustPackages."unknown".nilang-optimizer."0.1.0" { inherit profileName; }).out;
       nilang_parser = (rustPackages."unknown".nilang-parser."0.1.0" { inherit profileName; }).out;
       nilang_transformer = (rustPackages."unknown".nilang-transformer."0.1.0" { inherit profileName; }).out;
       nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
@@ -174,6 +264,9 @@ in
       errors = (rustPackages."unknown".errors."0.1.0" { inherit profileName; }).out;
       nilang_types = (rustPackages."unknown".nilang-types."0.1.0" { inherit profileName; }).out;
     };
+    devDependencies = {
+      nilang_interpreter = (rustPackages."unknown".nilang-interpreter."0.1.0" { inherit profileName; }).out;
+    };
   });
   
   "unknown".nilang-types."0.1.0" = overridableMkRustCrate (profileName: rec {
@@ -0,0 +1,123 @@
+use colored::Colorize;
+use nilang_types::nodes::Type;
+
+#[derive(Debug, Clone)]
+pub enum InterpreterErrors {
+    VariableNotFound {
+        name: Box<str>,
+    },
+    FunctionNotFound {
+        name: Box<str>,
+    },
+    NoMatchingOverload {
+        name: Box<str>,
+        arguments: Box<[Type]>,
+    },
+    AmbiguousFunctionReference {
+        name: Box<str>,
+    },
+    TypeMismatch {
+        expected: Type,
+        found: Type,
+    },
+    FieldNotFound {
+        name: Box<str>,
+    },
+    TypeNotFound {
+        name: Box<str>,
+    },
+    AssignmentToConstant {
+        name: Box<str>,
+    },
+    MissingReturn {
+        name: Box<str>,
+    },
+    RecursiveGlobal {
+        name: Box<str>,
+    },
+    DivisionByZero,
+    LabelNotFound {
+        name: Box<str>,
+    },
+    InvalidAddress {
+        address: i64,
+    },
+    Output {
+        message: Box<str>,
+    },
+}
+
+impl std::fmt::Display for InterpreterErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                InterpreterErrors::VariableNotFound { name } => {
+                    format!("Variable not found: `{}`", name).as_str().red()
+                }
+                InterpreterErrors::FunctionNotFound { name } => {
+                    format!("Function not found: `{}`", name).as_str().red()
+                }
+                InterpreterErrors::NoMatchingOverload { name, arguments } => {
+                    format!(
+                        "No overload of `{}` accepts arguments `({})`",
+                        name,
+                        arguments
+                            .iter()
+                            .map(ToString::to_string)
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    )
+                    .as_str()
+                    .red()
+                }
+                InterpreterErrors::AmbiguousFunctionReference { name } => {
+                    format!("Function `{}` is overloaded", name).as_str().red()
+                }
+                InterpreterErrors::TypeMismatch { expected, found } => {
+                    format!("Type mismatch: expected `{}`, found `{}`", expected, found)
+                        .as_str()
+                        .red()
+                }
+                InterpreterErrors::FieldNotFound { name } => {
+                    format!("Field not found: `{}`", name).as_str().red()
+                }
+                InterpreterErrors::TypeNotFound { name } => {
+                    format!("Type not found: `{}`", name).as_str().red()
+                }
+                InterpreterErrors::AssignmentToConstant { name } => {
+                    format!("Can not assign to constant `{}`", name)
+                        .as_str()
+                        .red()
+                }
+                InterpreterErrors::MissingReturn { name } => {
+                    format!("Function `{}` finished without returning a value", name)
+                        .as_str()
+                        .red()
+                }
+                InterpreterErrors::RecursiveGlobal { name } => {
+                    format!("Global `{}` depends on itself", name)
+                        .as_str()
+                        .red()
+                }
+                InterpreterErrors::DivisionByZero => "Division by zero".red(),
+                InterpreterErrors::LabelNotFound { name } => {
+                    format!("Label not found: `{}`", name).as_str().red()
+                }
+                InterpreterErrors::InvalidAddress { address } => {
+                    format!("Invalid memory access at `{:#x}`", address)
+                        .as_str()
+                        .red()
+                }
+                InterpreterErrors::Output { message } => {
+                    format!("Failed to write output: {}", message)
+                        .as_str()
+                        .red()
+                }
+            }
+        )
+    }
+}
+
+impl std::error::Error for InterpreterErrors {}
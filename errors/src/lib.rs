@@ -1,10 +1,12 @@
 use colored::Colorize;
 pub use generator_errors::GeneratorErrors;
+pub use interpreter_errors::InterpreterErrors;
 pub use lexer_errors::LexerErrors;
 pub use parser_errors::ParserErrors;
 pub use transformer_errors::TransformerErrors;
 
 mod generator_errors;
+mod interpreter_errors;
 mod lexer_errors;
 mod parser_errors;
 mod transformer_errors;
@@ -18,6 +20,12 @@ impl CodeLocation {
     pub fn range(line_from: usize, char_from: usize, line_to: usize, char_to: usize) -> Self {
         Self(line_from, char_from, line_to, char_to)
     }
+    pub fn start(&self) -> (usize, usize) {
+        (self.0, self.1)
+    }
+    pub fn end(&self) -> (usize, usize) {
+        (self.2, self.3)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -75,7 +83,7 @@ fn highlight_with_context(
     code: &str,
     CodeLocation(line_from, char_from, line_to, char_to): CodeLocation,
 ) -> String {
-    let first_line = if line_from < 3 { 0 } else { line_from - 3 };
+    let first_line = line_from.saturating_sub(3);
     let window = code
         .lines()
         .skip(first_line)
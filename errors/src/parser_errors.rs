@@ -63,5 +63,6 @@ fn token_type_to_str(token: &TokenType) -> &str {
         TokenType::Equals => "equals",
         TokenType::Comma => "comma",
         TokenType::Dot => "dot",
+        TokenType::Comment(_) => "comment",
     }
 }
@@ -1,5 +1,5 @@
 use colored::Colorize;
-use nilang_types::nodes::Type;
+use nilang_types::nodes::{Span, Type};
 
 #[derive(Debug, Clone)]
 pub enum TransformerErrors {
@@ -25,6 +25,59 @@ pub enum TransformerErrors {
         expected: Box<[Box<str>]>,
         found: Box<[Box<str>]>,
     },
+    NoMatchingOverload {
+        name: Box<str>,
+        arguments: Box<[Type]>,
+    },
+    FunctionAlreadyDefined {
+        name: Box<str>,
+        parameters: Box<[Type]>,
+    },
+    AmbiguousFunctionReference {
+        name: Box<str>,
+    },
+    NotConstant {
+        name: Box<str>,
+    },
+    AssignmentToConstant {
+        name: Box<str>,
+    },
+    Overflow {
+        name: Box<str>,
+        span: Span,
+    },
+    DivisionByZero {
+        name: Box<str>,
+        span: Span,
+    },
+    EvaluationLimit {
+        name: Box<str>,
+    },
+    Located {
+        error: Box<TransformerErrors>,
+        span: Span,
+    },
+}
+
+impl TransformerErrors {
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            TransformerErrors::Overflow { span, .. }
+            | TransformerErrors::DivisionByZero { span, .. }
+            | TransformerErrors::Located { span, .. } => Some(*span),
+            _ => None,
+        }
+    }
+
+    pub fn at(self, span: Span) -> Self {
+        match self.span() {
+            Some(_) => self,
+            None => TransformerErrors::Located {
+                error: Box::new(self),
+                span,
+            },
+        }
+    }
 }
 
 impl std::fmt::Display for TransformerErrors {
@@ -37,27 +90,9 @@ impl std::fmt::Display for TransformerErrors {
                     format!("Temporary not found: `{}`", name).as_str().red()
                 }
                 TransformerErrors::TypeMismatch { expected, found } => {
-                    format!(
-                        "Type mismatch: expected `{}`, found `{}`",
-                        match expected {
-                            Type::Bool => "bool",
-                            Type::Int => "int",
-                            Type::Void => "void",
-                            Type::Char => "char",
-                            Type::String => "string",
-                            Type::Object(name) => name,
-                        },
-                        match found {
-                            Type::Bool => "bool",
-                            Type::Int => "int",
-                            Type::Void => "void",
-                            Type::Char => "char",
-                            Type::String => "string",
-                            Type::Object(name) => name,
-                        }
-                    )
-                    .as_str()
-                    .red()
+                    format!("Type mismatch: expected `{}`, found `{}`", expected, found)
+                        .as_str()
+                        .red()
                 }
                 TransformerErrors::FunctionNotFound { name } => {
                     format!("Function not found: `{}`", name).as_str().red()
@@ -86,9 +121,75 @@ impl std::fmt::Display for TransformerErrors {
                     .as_str()
                     .red()
                 }
+                TransformerErrors::NoMatchingOverload { name, arguments } => {
+                    format!(
+                        "No overload of `{}` accepts arguments `({})`",
+                        name,
+                        join_types(arguments)
+                    )
+                    .as_str()
+                    .red()
+                }
+                TransformerErrors::FunctionAlreadyDefined { name, parameters } => {
+                    format!(
+                        "Function `{}({})` is already defined",
+                        name,
+                        join_types(parameters)
+                    )
+                    .as_str()
+                    .red()
+                }
+                TransformerErrors::NotConstant { name } => {
+                    format!("Value of `{}` can not be computed at compile time", name)
+                        .as_str()
+                        .red()
+                }
+                TransformerErrors::AssignmentToConstant { name } => {
+                    format!("Can not assign to constant `{}`", name)
+                        .as_str()
+                        .red()
+                }
+                TransformerErrors::Overflow { name, span } => format!(
+                    "[{}:{}] Arithmetic overflow while evaluating `{}`",
+                    span.start.0, span.end.0, name
+                )
+                .as_str()
+                .red(),
+                TransformerErrors::DivisionByZero { name, span } => format!(
+                    "[{}:{}] Division by zero while evaluating `{}`",
+                    span.start.0, span.end.0, name
+                )
+                .as_str()
+                .red(),
+                TransformerErrors::EvaluationLimit { name } => {
+                    format!("Evaluation of `{}` exceeded the compile time limit", name)
+                        .as_str()
+                        .red()
+                }
+                TransformerErrors::Located { error, span } => {
+                    format!("[{}:{}] {}", span.start.0, span.end.0, error)
+                        .as_str()
+                        .red()
+                }
+                TransformerErrors::AmbiguousFunctionReference { name } => {
+                    format!(
+                        "Function `{}` is overloaded, annotate the expected function type",
+                        name
+                    )
+                    .as_str()
+                    .red()
+                }
             }
         )
     }
 }
 
 impl std::error::Error for TransformerErrors {}
+
+fn join_types(types: &[Type]) -> String {
+    types
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,804 @@
+use errors::NilangError;
+use nilang_types::{
+    nodes::{
+        expressions::{
+            Arithmetic, Boolean, Conditional, ExpressionNode, Operation, Operator, Primitive,
+        },
+        statements::{
+            FunctionDeclaration, GlobalDeclaration, ModulePath, Parameter, StatementNode,
+            StructureDeclaration,
+        },
+    },
+    tokens::{Keyword, Token, TokenType},
+};
+
+const INDENTATION: &str = "    ";
+
+type Position = (usize, usize);
+
+enum Item<'a> {
+    Use(&'a ModulePath),
+    Function(&'a FunctionDeclaration),
+    Structure(&'a StructureDeclaration),
+    Global(&'a GlobalDeclaration),
+}
+
+struct Formatter {
+    lines: Vec<Vec<char>>,
+    tokens: Vec<Token>,
+    comments: Vec<Token>,
+    cursor: usize,
+    comment: usize,
+}
+
+pub fn format(code: &str) -> Result<String, NilangError> {
+    let (functions, structures, uses, globals) = nilang_parser::parse(nilang_lexer::lex(code))?;
+
+    let mut tokens = Vec::new();
+    let mut comments = Vec::new();
+    for token in nilang_lexer::lex_with_trivia(code) {
+        let token = token?;
+        match token.token {
+            TokenType::Comment(_) => comments.push(token),
+            _ => tokens.push(token),
+        }
+    }
+
+    let mut items = tokens
+        .iter()
+        .filter(|token| token.token == TokenType::Keyword(Keyword::Use))
+        .zip(&uses)
+        .map(|(token, path)| (token.start, Item::Use(path)))
+        .chain(
+            functions
+                .iter()
+                .map(|function| (function.span.start, Item::Function(function))),
+        )
+        .chain(
+            structures
+                .iter()
+                .map(|structure| (structure.span.start, Item::Structure(structure))),
+        )
+        .chain(
+            globals
+                .iter()
+                .map(|global| (global.span.start, Item::Global(global))),
+        )
+        .collect::<Vec<_>>();
+    items.sort_by_key(|(position, _)| *position);
+
+    let mut formatter = Formatter {
+        lines: code
+            .lines()
+            .map(|line| line.replace('\t', INDENTATION).chars().collect())
+            .collect(),
+        tokens,
+        comments,
+        cursor: 0,
+        comment: 0,
+    };
+    Ok(formatter.program(items.into_iter().map(|(_, item)| item)))
+}
+
+impl Formatter {
+    fn program<'a>(&mut self, items: impl Iterator<Item = Item<'a>>) -> String {
+        let mut output = String::new();
+        let mut previous = None;
+        let mut spacious = false;
+
+        for item in items {
+            let separated = matches!(item, Item::Function(_) | Item::Structure(_));
+            output += &self.leading(self.position(), 0, &mut previous, spacious || separated);
+
+            output += &match item {
+                Item::Use(path) => {
+                    self.skip_statement();
+                    format!("use {};", path.join("::"))
+                }
+                Item::Function(function) => self.function(function),
+                Item::Structure(structure) => self.structure(structure),
+                Item::Global(global) => self.global(global),
+            };
+
+            let end = self.end();
+            output += &self.trailing(end);
+            output += "\n";
+            previous = Some(end.0);
+            spacious = separated;
+        }
+
+        output += &self.comments((usize::MAX, usize::MAX), 0, &mut previous, &mut false);
+        output
+    }
+
+    fn function(&mut self, function: &FunctionDeclaration) -> String {
+        let header = format!(
+            "{}{}fn {}({}): {} ",
+            if function.public { "pb " } else { "" },
+            if function.constant { "cn " } else { "" },
+            function.name,
+            parameters(&function.parameters),
+            function.return_type
+        );
+        self.cursor = self.find_block();
+        header + &self.block(&function.body, 0)
+    }
+
+    fn structure(&mut self, structure: &StructureDeclaration) -> String {
+        let header = self
+            .tokens
+            .iter()
+            .position(|token| token.start == structure.span.start)
+            .unwrap_or(self.cursor);
+        let open = header + 1;
+        let close = self.matching(open);
+        self.cursor = open + 1;
+
+        let mut fields = self.field_names(open);
+        let mut missing = structure
+            .fields
+            .keys()
+            .filter(|name| !fields.iter().any(|(_, field)| field == *name))
+            .map(|name| (close, name.clone()))
+            .collect::<Vec<_>>();
+        missing.sort();
+        fields.append(&mut missing);
+
+        let mut output = format!(
+            "{}st {} {{\n",
+            if structure.public { "pb " } else { "" },
+            structure.name
+        );
+        let mut previous = None;
+        for (i, (index, name)) in fields.iter().enumerate() {
+            let Some(r#type) = structure.fields.get(name) else {
+                continue;
+            };
+            let start = self
+                .tokens
+                .get(*index)
+                .map_or(self.position(), |token| token.start);
+            output += &self.leading(start, 1, &mut previous, false);
+
+            let last = fields
+                .get(i + 1)
+                .map_or(close, |(next, _)| *next)
+                .saturating_sub(1);
+            let end = self.tokens.get(last).map_or(start, |token| token.end);
+            self.cursor = last + 1;
+
+            output += &format!("{}{}: {},", INDENTATION, name, r#type);
+            output += &self.trailing(end);
+            output += "\n";
+            previous = Some(end.0);
+        }
+
+        let end = self
+            .tokens
+            .get(close)
+            .map_or(self.position(), |token| token.start);
+        output += &self.comments(end, 1, &mut previous, &mut false);
+        self.cursor = close + 1;
+        output + "}"
+    }
+
+    fn global(&mut self, global: &GlobalDeclaration) -> String {
+        if let Some(verbatim) = self.verbatim(self.cursor, self.statement_end()) {
+            return verbatim;
+        }
+
+        let value = self.top_expression(&global.value, 0);
+        self.skip_statement();
+        format!(
+            "{}{} {}: {} = {};",
+            if global.public { "pb " } else { "" },
+            if global.constant { "cn" } else { "vr" },
+            global.name,
+            global.r#type,
+            value
+        )
+    }
+
+    fn block(&mut self, body: &[StatementNode], indent: usize) -> String {
+        let open = self.cursor;
+        let close = self.matching(open);
+        self.cursor = open + 1;
+
+        let mut output = String::from("{\n");
+        let mut previous = None;
+        for statement in body {
+            output += &self.leading(self.position(), indent + 1, &mut previous, false);
+            output += &INDENTATION.repeat(indent + 1);
+            output += &self.statement(statement, indent + 1);
+
+            let end = self.end();
+            output += &self.trailing(end);
+            output += "\n";
+            previous = Some(end.0);
+        }
+
+        let end = self
+            .tokens
+            .get(close)
+            .map_or(self.position(), |token| token.start);
+        output += &self.comments(end, indent + 1, &mut previous, &mut false);
+        self.cursor = close + 1;
+
+        if body.is_empty() && output == "{\n" {
+            return String::from("{}");
+        }
+        output + &INDENTATION.repeat(indent) + "}"
+    }
+
+    fn statement(&mut self, statement: &StatementNode, indent: usize) -> String {
+        if !matches!(
+            statement,
+            StatementNode::Conditional(_) | StatementNode::WhileLoop { .. }
+        ) {
+            if let Some(verbatim) = self.verbatim(self.cursor, self.statement_end()) {
+                return verbatim;
+            }
+        }
+
+        match statement {
+            StatementNode::VariableDeclaration {
+                name,
+                r#type,
+                value,
+                ..
+            } => {
+                let value = self.top_expression(value, indent);
+                self.skip_statement();
+                format!("vr {}: {} = {};", name, r#type, value)
+            }
+            StatementNode::VariableAssignment { name, value, .. } => {
+                let value = self.top_expression(value, indent);
+                self.skip_statement();
+                format!("{} = {};", name, value)
+            }
+            StatementNode::Return { value, .. } => {
+                let value = self.top_expression(value, indent);
+                self.skip_statement();
+                format!("rt {};", value)
+            }
+            StatementNode::FunctionCall(call) => {
+                let call = self.expression(&ExpressionNode::FunctionCall(call.clone()), indent);
+                self.skip_statement();
+                format!("{};", call)
+            }
+            StatementNode::Conditional(conditional) => self.conditional(conditional, indent),
+            StatementNode::WhileLoop {
+                condition, body, ..
+            } => {
+                let condition = self.condition(condition, indent);
+                self.cursor = self.find_block();
+                format!("wl {} {}", condition, self.block(body, indent))
+            }
+        }
+    }
+
+    fn conditional(&mut self, conditional: &Conditional, indent: usize) -> String {
+        let mut output = String::new();
+        let mut next = Some(conditional);
+        while let Some(Conditional {
+            condition,
+            body,
+            chained,
+            ..
+        }) = next
+        {
+            if output.is_empty() {
+                output += &format!("if {} ", self.condition(condition, indent));
+            } else if let ExpressionNode::Primitive(Primitive::Boolean(true)) = condition {
+                output += " el ";
+            } else {
+                output += &format!(" ef {} ", self.condition(condition, indent));
+            }
+
+            self.cursor = self.find_block();
+            output += &self.block(body, indent);
+            next = chained.as_deref();
+        }
+        output
+    }
+
+    fn condition(&mut self, condition: &ExpressionNode, indent: usize) -> String {
+        if let Some(verbatim) = self.verbatim(self.cursor + 1, self.find_block() - 1) {
+            return verbatim;
+        }
+
+        format!("({})", self.top_expression(condition, indent))
+    }
+
+    fn top_expression(&mut self, expression: &ExpressionNode, indent: usize) -> String {
+        self.expression(strip_parenthesis(expression), indent)
+    }
+
+    fn expression(&mut self, expression: &ExpressionNode, indent: usize) -> String {
+        match expression {
+            ExpressionNode::Primitive(primitive) => match primitive {
+                Primitive::Boolean(boolean) => boolean.to_string(),
+                Primitive::Number(number) => number.to_string(),
+                Primitive::Char(char) => format!("'{}'", char),
+                Primitive::String(text) => format!("\"{}\"", text),
+            },
+            ExpressionNode::VariableReference(name) => name.to_string(),
+            ExpressionNode::FieldAccess { structure, field } => {
+                let structure = match &**structure {
+                    ExpressionNode::Parenthesis(_) => self.grouped(structure, indent),
+                    structure => self.expression(structure, indent),
+                };
+                format!("{}.{}", structure, field)
+            }
+            ExpressionNode::FunctionCall(call) => {
+                let arguments = call
+                    .arguments
+                    .iter()
+                    .map(|argument| self.top_expression(argument, indent))
+                    .collect::<Vec<_>>();
+                format!("{}({})", call.name, arguments.join(", "))
+            }
+            ExpressionNode::Parenthesis(_) => self.grouped(expression, indent),
+            ExpressionNode::Operation(operation) => self.operation(operation, true, indent),
+            ExpressionNode::Object { r#type, fields } => {
+                let name = r#type.to_string();
+                let open = (self.cursor..self.tokens.len())
+                    .find(|index| {
+                        self.tokens[*index].token == TokenType::Identifier(name.as_str().into())
+                            && self.tokens.get(index + 1).map(|token| &token.token)
+                                == Some(&TokenType::OpeningBrace)
+                    })
+                    .map_or(self.cursor, |index| index + 1);
+                let close = self.matching(open);
+                self.cursor = open + 1;
+
+                let mut names = self
+                    .field_names(open)
+                    .into_iter()
+                    .map(|(_, name)| name)
+                    .filter(|name| fields.contains_key(name))
+                    .collect::<Vec<_>>();
+                let mut missing = fields
+                    .keys()
+                    .filter(|name| !names.contains(name))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                missing.sort();
+                names.append(&mut missing);
+
+                let fields = names
+                    .iter()
+                    .map(|name| format!("{}: {}", name, self.top_expression(&fields[name], indent)))
+                    .collect::<Vec<_>>();
+                self.cursor = self.cursor.max(close + 1);
+
+                if fields.is_empty() {
+                    format!("{} {{}}", name)
+                } else {
+                    format!("{} {{ {} }}", name, fields.join(", "))
+                }
+            }
+            ExpressionNode::Closure {
+                parameters: closure_parameters,
+                return_type,
+                body,
+            } => {
+                if let Some(header) = (self.cursor..self.tokens.len()).find(|index| {
+                    self.tokens[*index].token == TokenType::Keyword(Keyword::Function)
+                }) {
+                    self.cursor = header;
+                }
+                self.cursor = self.find_block();
+                format!(
+                    "fn({}): {} {}",
+                    parameters(closure_parameters),
+                    return_type,
+                    self.block(body, indent)
+                )
+            }
+        }
+    }
+
+    fn operation(&mut self, operation: &Operation, leading: bool, indent: usize) -> String {
+        let a = self.operand(&operation.a, leading, operation.operator, indent);
+        let b = self.operand(&operation.b, false, operation.operator, indent);
+        format!("{} {} {}", a, operator(operation.operator), b)
+    }
+
+    /// Operations are grouped as the parser folds them from the left, so written
+    /// parentheses are only dropped around the leading operand, and only when it
+    /// binds tighter than the operator that follows it.
+    fn operand(
+        &mut self,
+        operand: &ExpressionNode,
+        leading: bool,
+        following: Operator,
+        indent: usize,
+    ) -> String {
+        match (operand, strip_parenthesis(operand)) {
+            (ExpressionNode::Operation(operation), _) => self.operation(operation, leading, indent),
+            (_, ExpressionNode::Operation(operation))
+                if leading && operation.operator.precedence() > following.precedence() =>
+            {
+                self.operation(operation, true, indent)
+            }
+            _ => self.grouped(operand, indent),
+        }
+    }
+
+    fn grouped(&mut self, expression: &ExpressionNode, indent: usize) -> String {
+        let inner = strip_parenthesis(expression);
+        match inner {
+            ExpressionNode::Primitive(_)
+            | ExpressionNode::VariableReference(_)
+            | ExpressionNode::FieldAccess { .. }
+            | ExpressionNode::FunctionCall(_) => self.expression(inner, indent),
+            _ => format!("({})", self.expression(inner, indent)),
+        }
+    }
+
+    fn leading(
+        &mut self,
+        position: Position,
+        indent: usize,
+        previous: &mut Option<usize>,
+        separated: bool,
+    ) -> String {
+        let mut separated = separated;
+        let mut output = self.comments(position, indent, previous, &mut separated);
+        output += separator(*previous, position.0, separated);
+        output
+    }
+
+    fn comments(
+        &mut self,
+        until: Position,
+        indent: usize,
+        previous: &mut Option<usize>,
+        separated: &mut bool,
+    ) -> String {
+        let mut output = String::new();
+        while let Some(Token {
+            token: TokenType::Comment(text),
+            start,
+            end,
+        }) = self
+            .comments
+            .get(self.comment)
+            .filter(|comment| comment.start < until)
+        {
+            output += separator(*previous, start.0, *separated);
+            output += &format!("{}//{}\n", INDENTATION.repeat(indent), text);
+            *previous = Some(end.0);
+            *separated = false;
+            self.comment += 1;
+        }
+        output
+    }
+
+    fn trailing(&mut self, end: Position) -> String {
+        match self.comments.get(self.comment) {
+            Some(Token {
+                token: TokenType::Comment(text),
+                start,
+                ..
+            }) if start.0 == end.0 && *start > end => {
+                self.comment += 1;
+                format!(" //{}", text)
+            }
+            _ => String::new(),
+        }
+    }
+
+    /// Code with comments between its tokens is kept as written, as the comments
+    /// would otherwise have to move out of the expression they annotate.
+    fn verbatim(&mut self, first: usize, last: usize) -> Option<String> {
+        let (start, end) = (self.tokens.get(first)?.start, self.tokens.get(last)?.end);
+        let bodies = (first..last)
+            .filter(|index| self.tokens[*index].token == TokenType::Keyword(Keyword::Function))
+            .map(|index| {
+                let open = self.find_block_from(index);
+                let close = self.tokens.get(self.matching(open));
+                (
+                    self.tokens[open].start,
+                    close.map_or(end, |token| token.start),
+                )
+            })
+            .collect::<Vec<_>>();
+        let interior = self.comments[self.comment..]
+            .iter()
+            .take_while(|comment| comment.start < end)
+            .any(|comment| {
+                comment.start > start
+                    && !bodies
+                        .iter()
+                        .any(|(open, close)| *open < comment.start && comment.start < *close)
+            });
+        if !interior {
+            return None;
+        }
+
+        let text = (start.0..=end.0)
+            .map(|line| {
+                let characters = self.lines.get(line).map_or(&[][..], Vec::as_slice);
+                let from = if line == start.0 { start.1 } else { 0 };
+                let to = if line == end.0 {
+                    end.1 + 1
+                } else {
+                    characters.len()
+                };
+                characters[from.min(characters.len())..to.min(characters.len())]
+                    .iter()
+                    .collect::<String>()
+                    .trim_end()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        while self
+            .comments
+            .get(self.comment)
+            .is_some_and(|comment| comment.start < end)
+        {
+            self.comment += 1;
+        }
+        self.cursor = last + 1;
+        Some(text)
+    }
+
+    fn field_names(&self, open: usize) -> Vec<(usize, Box<str>)> {
+        let mut names = Vec::new();
+        let mut depth = 0;
+        for index in open..self.tokens.len() {
+            match &self.tokens[index].token {
+                TokenType::OpeningBrace | TokenType::OpeningParenthesis => depth += 1,
+                TokenType::ClosingBrace | TokenType::ClosingParenthesis => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                TokenType::Identifier(name)
+                    if depth == 1
+                        && self.tokens.get(index + 1).map(|token| &token.token)
+                            == Some(&TokenType::Colon) =>
+                {
+                    names.push((index, name.clone()));
+                }
+                _ => {}
+            }
+        }
+        names
+    }
+
+    fn find_block(&self) -> usize {
+        self.find_block_from(self.cursor)
+    }
+
+    fn find_block_from(&self, from: usize) -> usize {
+        let mut depth = 0;
+        (from..self.tokens.len())
+            .find(|index| match self.tokens[*index].token {
+                TokenType::OpeningParenthesis => {
+                    depth += 1;
+                    false
+                }
+                TokenType::ClosingParenthesis => {
+                    depth -= 1;
+                    false
+                }
+                TokenType::OpeningBrace => depth <= 0,
+                _ => false,
+            })
+            .unwrap_or(from)
+    }
+
+    fn matching(&self, open: usize) -> usize {
+        let mut depth = 0;
+        (open..self.tokens.len())
+            .find(|index| match self.tokens[*index].token {
+                TokenType::OpeningBrace => {
+                    depth += 1;
+                    false
+                }
+                TokenType::ClosingBrace => {
+                    depth -= 1;
+                    depth == 0
+                }
+                _ => false,
+            })
+            .unwrap_or(self.tokens.len())
+    }
+
+    fn statement_end(&self) -> usize {
+        let mut depth = 0;
+        (self.cursor..self.tokens.len())
+            .find(|index| match self.tokens[*index].token {
+                TokenType::OpeningBrace => {
+                    depth += 1;
+                    false
+                }
+                TokenType::ClosingBrace => {
+                    depth -= 1;
+                    false
+                }
+                TokenType::Semicolon => depth == 0,
+                _ => false,
+            })
+            .unwrap_or(self.tokens.len().saturating_sub(1))
+    }
+
+    fn skip_statement(&mut self) {
+        if let Some(semicolon) = (self.cursor..self.tokens.len())
+            .find(|index| self.tokens[*index].token == TokenType::Semicolon)
+        {
+            self.cursor = semicolon + 1;
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.tokens
+            .get(self.cursor)
+            .map_or((usize::MAX, usize::MAX), |token| token.start)
+    }
+
+    fn end(&self) -> Position {
+        self.cursor
+            .checked_sub(1)
+            .and_then(|index| self.tokens.get(index))
+            .map_or((0, 0), |token| token.end)
+    }
+}
+
+fn separator(previous: Option<usize>, line: usize, separated: bool) -> &'static str {
+    match previous {
+        Some(previous) if separated || line > previous + 1 => "\n",
+        _ => "",
+    }
+}
+
+fn strip_parenthesis(expression: &ExpressionNode) -> &ExpressionNode {
+    match expression {
+        ExpressionNode::Parenthesis(inner) => strip_parenthesis(inner),
+        expression => expression,
+    }
+}
+
+fn parameters(parameters: &[Parameter]) -> String {
+    parameters
+        .iter()
+        .map(|(name, r#type)| format!("{}: {}", name, r#type))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn operator(operator: Operator) -> &'static str {
+    match operator {
+        Operator::Arithmetic(Arithmetic::Add) => "+",
+        Operator::Arithmetic(Arithmetic::Subtract) => "-",
+        Operator::Arithmetic(Arithmetic::Multiply) => "*",
+        Operator::Arithmetic(Arithmetic::Divide) => "/",
+        Operator::Arithmetic(Arithmetic::Modulo) => "%",
+        Operator::Boolean(Boolean::Equal) => "==",
+        Operator::Boolean(Boolean::NotEqual) => "!=",
+        Operator::Boolean(Boolean::Less) => "<",
+        Operator::Boolean(Boolean::More) => ">",
+        Operator::Boolean(Boolean::LessOrEqual) => "<=",
+        Operator::Boolean(Boolean::MoreOrEqual) => ">=",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::format;
+
+    #[test]
+    fn test_format_program() {
+        let code = "use math;
+// A point
+pb st Point { x: int,   y: int, }
+cn SIZE: int = (2 * 3) + 1; // trailing
+fn main(): int {
+  // leading
+  vr p: Point = Point { y: ((4)), x: (1 + 2) * 3 };
+
+
+  if (p.x == 9) { print(p.y); } ef (false) {} el { rt (1); }
+  wl (false) { // loop
+  }
+  vr f: fn(int): int = fn(a: int): int { rt a - (a - 1); };
+  rt f(SIZE); // done
+  // end
+}
+";
+
+        assert_eq!(
+            format(code).unwrap(),
+            "use math;
+
+// A point
+pb st Point {
+    x: int,
+    y: int,
+}
+
+cn SIZE: int = 2 * 3 + 1; // trailing
+
+fn main(): int {
+    // leading
+    vr p: Point = Point { y: 4, x: (1 + 2) * 3 };
+
+    if (p.x == 9) {
+        print(p.y);
+    } ef (false) {} el {
+        rt 1;
+    }
+    wl (false) {
+        // loop
+    }
+    vr f: fn(int): int = fn(a: int): int {
+        rt a - (a - 1);
+    };
+    rt f(SIZE); // done
+    // end
+}
+"
+        );
+    }
+
+    #[test]
+    fn test_format_is_stable() {
+        let code = "fn main(): int {
+    vr a: int = 1 - 2 - 3 * (4 + 5) / (6 - 8);
+    vr b: int = (1 - 2) - (3 * 4) + (5 % 6) * 7;
+    vr c: int = 1 + (2 * 3) + (4 * 5) + (6 * 7);
+    rt a + b + c;
+}
+";
+
+        let formatted = format(code).unwrap();
+        assert_eq!(format(&formatted).unwrap(), formatted);
+
+        let parse = |code: &str| nilang_parser::parse(nilang_lexer::lex(code)).unwrap();
+        let evaluate = |code: &str| {
+            let (functions, ..) = parse(code);
+            let mut interpreter = nilang_interpreter::Interpreter::new(Vec::new());
+            for function in &functions {
+                interpreter.declare_function(function);
+            }
+            interpreter.run().unwrap()
+        };
+        assert_eq!(evaluate(&formatted), evaluate(code));
+    }
+
+    #[test]
+    fn test_format_keeps_comments_inside_expressions() {
+        let code = "fn main(): int {
+  print(add(1, // one
+        2));
+    if (1 == // left
+        1) {
+        rt 0;
+    }
+    rt 1;
+}
+";
+
+        let formatted = format(code).unwrap();
+        assert_eq!(
+            formatted,
+            "fn main(): int {
+    print(add(1, // one
+        2));
+    if (1 == // left
+        1) {
+        rt 0;
+    }
+    rt 1;
+}
+"
+        );
+        assert_eq!(format(&formatted).unwrap(), formatted);
+    }
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(code) = std::str::from_utf8(data) {
+        let _ = nilang_parser::parse(nilang_lexer::lex(code));
+        let _ = nilang_parser::parse_input(nilang_lexer::lex(code));
+    }
+});
@@ -0,0 +1,356 @@
+use std::collections::{HashMap, HashSet};
+
+use nilang_types::instructions::Instruction;
+
+use crate::{
+    calling_convention::CallingConvention, liveness, memory_manager::Location, registers::Registers,
+};
+
+#[derive(Debug)]
+struct InterferenceGraph<'a, R: Registers> {
+    edges: HashMap<&'a str, HashSet<&'a str>>,
+    parents: HashMap<&'a str, &'a str>,
+    starts: HashMap<&'a str, usize>,
+    precoloured: HashMap<&'a str, Location<R>>,
+    hints: HashMap<&'a str, Vec<R>>,
+    forbidden: HashMap<&'a str, Vec<R>>,
+}
+
+impl<'a, R: Registers> InterferenceGraph<'a, R> {
+    fn new(intervals: &HashMap<&'a str, (usize, usize)>, moves: &[(&'a str, &'a str)]) -> Self {
+        let mut temporaries = intervals.iter().collect::<Vec<_>>();
+        temporaries.sort();
+
+        let mut graph = InterferenceGraph {
+            edges: temporaries
+                .iter()
+                .map(|(temporary, _)| (**temporary, HashSet::new()))
+                .collect(),
+            starts: temporaries
+                .iter()
+                .map(|(temporary, (start, _))| (**temporary, *start))
+                .collect(),
+            parents: HashMap::new(),
+            precoloured: HashMap::new(),
+            hints: HashMap::new(),
+            forbidden: HashMap::new(),
+        };
+
+        for (i, (a, (a_start, a_end))) in temporaries.iter().enumerate() {
+            for (b, (b_start, b_end)) in &temporaries[i + 1..] {
+                let overlapping = a_start <= b_end && b_start <= a_end;
+                let moved = moves
+                    .iter()
+                    .any(|&(to, from)| (to, from) == (a, b) || (to, from) == (b, a));
+                if overlapping && !moved {
+                    graph.edges.get_mut(*a).unwrap().insert(b);
+                    graph.edges.get_mut(*b).unwrap().insert(a);
+                }
+            }
+        }
+
+        graph
+    }
+
+    fn find(&self, temporary: &'a str) -> &'a str {
+        match self.parents.get(temporary) {
+            Some(parent) => self.find(parent),
+            None => temporary,
+        }
+    }
+
+    fn forbid(&mut self, temporary: &'a str, registers: &[R]) {
+        self.forbidden
+            .entry(temporary)
+            .or_default()
+            .extend(registers.iter().copied());
+    }
+
+    fn coalesce(&mut self, a: &'a str, b: &'a str) {
+        let (a, b) = (self.find(a), self.find(b));
+        let compatible = match (self.precoloured.get(a), self.precoloured.get(b)) {
+            (Some(a), Some(b)) => a == b,
+            _ => true,
+        };
+        if a == b || self.edges[a].contains(b) || !compatible {
+            return;
+        }
+
+        self.parents.insert(b, a);
+        for neighbour in self.edges.remove(b).unwrap_or_default() {
+            let edges = self.edges.get_mut(neighbour).unwrap();
+            edges.remove(b);
+            edges.insert(a);
+            self.edges.get_mut(a).unwrap().insert(neighbour);
+        }
+        if let Some(location) = self.precoloured.remove(b) {
+            self.precoloured.insert(a, location);
+        }
+        let mut hints = self.hints.remove(b).unwrap_or_default();
+        self.hints.entry(a).or_default().append(&mut hints);
+        let forbidden = self.forbidden.remove(b).unwrap_or_default();
+        self.forbidden.entry(a).or_default().extend(forbidden);
+        let start = self.starts[b].min(self.starts[a]);
+        self.starts.insert(a, start);
+    }
+
+    fn colour(&self) -> HashMap<&'a str, Location<R>> {
+        let mut colours = self.precoloured.clone();
+        let registers = R::all();
+
+        let mut nodes = self
+            .edges
+            .keys()
+            .filter(|node| !colours.contains_key(*node))
+            .copied()
+            .collect::<Vec<_>>();
+        nodes.sort_by_key(|node| (self.starts[node], *node));
+
+        for node in nodes {
+            let taken = self.edges[node]
+                .iter()
+                .filter_map(|neighbour| colours.get(neighbour))
+                .collect::<Vec<_>>();
+
+            let forbidden = self.forbidden.get(node);
+            let hints = self.hints.get(node).into_iter().flatten();
+            let register = hints.chain(registers.iter()).find(|register| {
+                !(taken.contains(&&Location::Register(**register))
+                    || forbidden.is_some_and(|forbidden| forbidden.contains(register)))
+            });
+
+            let location = match register {
+                Some(register) => Location::Register(*register),
+                None => (0..)
+                    .map(Location::Stack)
+                    .find(|slot| !taken.contains(&slot))
+                    .unwrap(),
+            };
+            colours.insert(node, location);
+        }
+
+        colours
+    }
+}
+
+pub fn allocate<C: CallingConvention>(
+    instructions: &[Instruction],
+) -> HashMap<Box<str>, Location<C::Registers>> {
+    let intervals = liveness::intervals(instructions);
+
+    let moves = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, instruction)| match instruction {
+            Instruction::Copy(to, from)
+                if intervals
+                    .get(from.as_ref())
+                    .is_some_and(|(_, end)| *end == i)
+                    && intervals
+                        .get(to.as_ref())
+                        .is_some_and(|(start, _)| *start == i) =>
+            {
+                Some((to.as_ref(), from.as_ref()))
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let mut graph = InterferenceGraph::new(&intervals, &moves);
+
+    for (instruction, live) in instructions.iter().zip(liveness::live_across(instructions)) {
+        let clobbered = C::clobbered_registers(instruction);
+        for temporary in live {
+            graph.forbid(temporary, &clobbered);
+        }
+    }
+
+    let arguments = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, instruction)| match instruction {
+            Instruction::TakeArgument(n, temporary) => Some((i, *n, temporary.as_ref())),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    for &(_, n, temporary) in &arguments {
+        match C::nth_argument_location(n) {
+            Location::Register(register) => {
+                graph.hints.entry(temporary).or_default().push(register)
+            }
+            location => {
+                graph.precoloured.insert(temporary, location);
+            }
+        }
+    }
+
+    for (i, instruction) in instructions.iter().enumerate() {
+        let unread = arguments
+            .iter()
+            .filter(|(j, _, _)| *j > i)
+            .filter_map(|(_, n, _)| match C::nth_argument_location(*n) {
+                Location::Register(register) => Some(register),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if let Some(temporary) = instruction.definition() {
+            graph.forbid(temporary, &unread);
+        }
+    }
+
+    for instruction in instructions {
+        for (temporary, location) in C::location_hints(instruction) {
+            if let (Some(temporary), Location::Register(register)) =
+                (intervals.get_key_value(temporary), location)
+            {
+                graph.hints.entry(temporary.0).or_default().push(register);
+            }
+        }
+    }
+
+    for (to, from) in moves {
+        graph.coalesce(to, from);
+    }
+
+    let colours = graph.colour();
+    intervals
+        .keys()
+        .map(|temporary| ((*temporary).into(), colours[graph.find(temporary)].clone()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::parse_instructions;
+
+    use crate::{
+        calling_convention::{CallingConvention, SystemVAmd64Abi},
+        memory_manager::Location,
+        registers::X86Registers,
+    };
+
+    use super::allocate;
+
+    #[test]
+    fn test_allocate() {
+        let instructions = parse_instructions(
+            "a = arg 0
+            b = arg 1
+            declare c
+            c = copy a
+            declare d
+            d = add c, b
+            declare e
+            e = num 2
+            declare f
+            f = div d, e
+            ret f",
+        )
+        .unwrap();
+
+        let allocation = allocate::<SystemVAmd64Abi>(&instructions);
+
+        assert_eq!(allocation["a"], Location::Register(X86Registers::Rdi));
+        assert_eq!(allocation["b"], Location::Register(X86Registers::Rsi));
+        assert_eq!(allocation["c"], Location::Register(X86Registers::Rdi));
+        assert_eq!(allocation["d"], Location::Register(X86Registers::Rax));
+        assert_ne!(allocation["e"], allocation["d"]);
+        assert_ne!(allocation["f"], allocation["d"]);
+    }
+
+    #[test]
+    fn test_allocate_around_division() {
+        let instructions = parse_instructions(
+            "declare v
+            v = num 96
+            declare go
+            go = bool true
+            loop:
+            jz go, end
+            declare four
+            four = num 4
+            v = div v, four
+            go = bool false
+            jmp loop
+            end:
+            ret v",
+        )
+        .unwrap();
+
+        let allocation = allocate::<SystemVAmd64Abi>(&instructions);
+
+        assert_eq!(allocation["v"], Location::Register(X86Registers::Rax));
+        for clobbered in [X86Registers::Rax, X86Registers::Rdx, X86Registers::R11] {
+            assert_ne!(allocation["go"], Location::Register(clobbered));
+        }
+    }
+
+    #[test]
+    fn test_allocate_call_arguments() {
+        let instructions = parse_instructions(
+            "declare x
+            x = num 1
+            declare y
+            y = num 2
+            declare z
+            z = call f(y, x)
+            ret z",
+        )
+        .unwrap();
+
+        let allocation = allocate::<SystemVAmd64Abi>(&instructions);
+
+        assert_eq!(allocation["y"], Location::Register(X86Registers::Rdi));
+        assert_eq!(allocation["x"], Location::Register(X86Registers::Rsi));
+        assert_eq!(allocation["z"], Location::Register(X86Registers::Rax));
+    }
+
+    #[test]
+    fn test_allocate_across_calls() {
+        let instructions = parse_instructions(
+            "declare x
+            x = num 1
+            declare y
+            y = call f(x)
+            declare z
+            z = add x, y
+            ret z",
+        )
+        .unwrap();
+
+        let allocation = allocate::<SystemVAmd64Abi>(&instructions);
+
+        assert_eq!(allocation["x"], Location::Register(X86Registers::Rbx));
+        assert_eq!(allocation["y"], Location::Register(X86Registers::Rax));
+    }
+
+    #[test]
+    fn test_allocate_across_calls_in_loop() {
+        let instructions = parse_instructions(
+            "declare go
+            go = bool true
+            declare a
+            a = num 1
+            declare b
+            b = num 2
+            loop:
+            jz go, end
+            b = call id(b)
+            go = bool false
+            jmp loop
+            end:
+            declare c
+            c = add a, b
+            ret c",
+        )
+        .unwrap();
+
+        let allocation = allocate::<SystemVAmd64Abi>(&instructions);
+        let caller_saved = SystemVAmd64Abi::caller_saved_registers();
+
+        match allocation["a"] {
+            Location::Register(register) => assert!(!caller_saved.contains(&register)),
+            ref location => assert!(matches!(location, Location::Stack(_))),
+        }
+    }
+}
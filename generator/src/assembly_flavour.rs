@@ -1,3 +1,5 @@
+use std::iter::once;
+
 use errors::GeneratorErrors;
 
 use crate::registers::Registers;
@@ -15,6 +17,8 @@ pub trait AssemblyFlavour<R: Registers> {
     fn generate_function_header(name: &str) -> String;
     fn generate_function_body<'a>(
         instructions: impl Iterator<Item = Result<FullInstruction<R>, GeneratorErrors>> + 'a,
+        frame_size: usize,
+        saved_registers: Vec<R>,
     ) -> impl Iterator<Item = Result<String, GeneratorErrors>> + 'a;
 }
 
@@ -24,12 +28,20 @@ impl<R: Registers> AssemblyFlavour<R> for AtAndTFlavour {
     fn generate_parameter(parameter: &AssemblyInstructionParameter<R>) -> String {
         match parameter {
             AssemblyInstructionParameter::Register(register) => format!("%{register}"),
-            AssemblyInstructionParameter::Memory(memory) => format!("-{memory}(%rax)"),
+            AssemblyInstructionParameter::Memory(slot) => format!("-{}(%rbp)", (slot + 1) * 8),
+            AssemblyInstructionParameter::Argument(n) => format!("{}(%rbp)", 16 + n * 8),
             AssemblyInstructionParameter::Number(number) => format!("${number}"),
             AssemblyInstructionParameter::Char(char) => format!("$'{char}'"),
             AssemblyInstructionParameter::Function(name) => name.to_string(),
             AssemblyInstructionParameter::Label(name) => format!(".{name}"),
             AssemblyInstructionParameter::Data(pointer) => format!("${pointer}"),
+            AssemblyInstructionParameter::Indirect(register, offset) => {
+                format!("{offset}(%{register})")
+            }
+            AssemblyInstructionParameter::Global(name) => format!("{name}(%rip)"),
+            AssemblyInstructionParameter::LowerByte(register) => {
+                format!("%{}", register.lower_byte())
+            }
         }
     }
 
@@ -38,6 +50,31 @@ impl<R: Registers> AssemblyFlavour<R> for AtAndTFlavour {
         parameters: &[AssemblyInstructionParameter<R>],
         comment: &str,
     ) -> String {
+        let direct_call = matches!(
+            parameters.first(),
+            Some(AssemblyInstructionParameter::Function(_))
+        );
+        let memory_to_memory = parameters.len() == 2
+            && parameters.iter().all(|parameter| {
+                matches!(
+                    parameter,
+                    AssemblyInstructionParameter::Memory(_)
+                        | AssemblyInstructionParameter::Argument(_)
+                )
+            });
+        let memory_destination = matches!(
+            parameters.first(),
+            Some(
+                AssemblyInstructionParameter::Memory(_) | AssemblyInstructionParameter::Argument(_)
+            )
+        );
+        let scratch = R::all()
+            .iter()
+            .find(|register| {
+                !parameters.contains(&AssemblyInstructionParameter::Register(**register))
+            })
+            .map(|register| format!("%{register}"))
+            .unwrap_or_default();
         let parameters = parameters
             .iter()
             .map(Self::generate_parameter)
@@ -47,26 +84,67 @@ impl<R: Registers> AssemblyFlavour<R> for AtAndTFlavour {
             AssemblyInstruction::Label => format!("{}:", parameters[0]).into(),
             AssemblyInstruction::Jmp => instruction_with_arguments("jmp", &[&parameters[0]]),
             AssemblyInstruction::Je => instruction_with_arguments("je", &[&parameters[0]]),
+            AssemblyInstruction::Test if memory_to_memory => {
+                instruction_with_arguments("cmpq", &["$0", &parameters[0]])
+            }
             AssemblyInstruction::Test => {
                 instruction_with_arguments("testq", &[&parameters[1], &parameters[0]])
             }
+            AssemblyInstruction::Move if memory_to_memory => {
+                format!("pushq {}\n    popq {}", parameters[1], parameters[0]).into()
+            }
             AssemblyInstruction::Move => {
                 instruction_with_arguments("movq", &[&parameters[1], &parameters[0]])
             }
+            AssemblyInstruction::MoveZeroExtendByte => {
+                instruction_with_arguments("movzbq", &[&parameters[1], &parameters[0]])
+            }
+            AssemblyInstruction::MoveByte => {
+                instruction_with_arguments("movb", &[&parameters[1], &parameters[0]])
+            }
+            AssemblyInstruction::Swap if memory_to_memory => format!(
+                "pushq {0}\n    pushq {1}\n    popq {0}\n    popq {1}",
+                parameters[0], parameters[1]
+            )
+            .into(),
             AssemblyInstruction::Swap => {
                 instruction_with_arguments("xchgq", &[&parameters[0], &parameters[1]])
             }
-            AssemblyInstruction::Call => instruction_with_arguments("call", &[&parameters[0]]),
+            AssemblyInstruction::Call if direct_call => {
+                instruction_with_arguments("call", &[&parameters[0]])
+            }
+            AssemblyInstruction::Call => {
+                instruction_with_arguments("call", &[&format!("*{}", parameters[0])])
+            }
+            AssemblyInstruction::Push => instruction_with_arguments("pushq", &[&parameters[0]]),
+            AssemblyInstruction::Add if memory_to_memory => {
+                through_scratch("addq", &parameters[0], &parameters[1], &scratch)
+            }
             AssemblyInstruction::Add => {
                 instruction_with_arguments("addq", &[&parameters[1], &parameters[0]])
             }
+            AssemblyInstruction::Sub if memory_to_memory => {
+                through_scratch("subq", &parameters[0], &parameters[1], &scratch)
+            }
             AssemblyInstruction::Sub => {
                 instruction_with_arguments("subq", &[&parameters[1], &parameters[0]])
             }
+            AssemblyInstruction::Mul if memory_destination => {
+                through_scratch("imulq", &parameters[0], &parameters[1], &scratch)
+            }
             AssemblyInstruction::Mul => {
                 instruction_with_arguments("imulq", &[&parameters[1], &parameters[0]])
             }
             AssemblyInstruction::Div => instruction_with_arguments("idivq", &[&parameters[0]]),
+            AssemblyInstruction::SignExtend => "cqto".into(),
+            AssemblyInstruction::Lea => instruction_with_arguments(
+                "leaq",
+                &[
+                    &format!("({},{})", parameters[1], parameters[2]),
+                    &parameters[0],
+                ],
+            ),
+            AssemblyInstruction::Return => "ret".into(),
 
             AssemblyInstruction::Raw(instruction) => format!("{instruction}").into(),
         };
@@ -86,16 +164,11 @@ impl<R: Registers> AssemblyFlavour<R> for AtAndTFlavour {
             Self::generate_instruction(&AssemblyInstruction::Raw("_start:".into()), &mpty, ""),
             Self::generate_instruction(&AssemblyInstruction::Raw("call main".into()), &mpty, ""),
             Self::generate_instruction(
-                &AssemblyInstruction::Raw("movq $60, %rax".into()),
+                &AssemblyInstruction::Raw("movq %rax, %rdi".into()),
                 &mpty,
                 "",
             ),
-            Self::generate_instruction(
-                &AssemblyInstruction::Raw("xorq %rdi, %rdi".into()),
-                &mpty,
-                "",
-            ),
-            Self::generate_instruction(&AssemblyInstruction::Raw("syscall".into()), &mpty, ""),
+            Self::generate_instruction(&AssemblyInstruction::Raw("call exit".into()), &mpty, ""),
         ]
     }
 
@@ -105,32 +178,47 @@ impl<R: Registers> AssemblyFlavour<R> for AtAndTFlavour {
 
     fn generate_function_body<'a>(
         instructions: impl Iterator<Item = Result<FullInstruction<R>, GeneratorErrors>> + 'a,
+        frame_size: usize,
+        saved_registers: Vec<R>,
     ) -> impl Iterator<Item = Result<String, GeneratorErrors>> + 'a {
         let prologue = r#"
     # Prologue
     pushq %rbp
     movq %rsp, %rbp
-        "#;
-        let body = instructions.map(|v| {
-            v.map(|(instruction, parameters, comment)| {
-                Self::generate_instruction(&instruction, &parameters, &comment)
-            })
-            .map(|v| format!("    {}", v))
+        "#
+        .lines()
+        .map(ToOwned::to_owned)
+        .chain((frame_size > 0).then(|| format!("    subq ${frame_size}, %rsp")))
+        .chain(
+            saved_registers
+                .iter()
+                .map(|register| format!("    pushq %{register}")),
+        )
+        .collect::<Vec<_>>();
+
+        let epilogue = saved_registers
+            .iter()
+            .rev()
+            .map(|register| format!("popq %{register}"))
+            .chain(["movq %rbp, %rsp", "pop %rbp", "ret"].map(ToOwned::to_owned))
+            .collect::<Vec<_>>();
+
+        let body = instructions.map({
+            let epilogue = epilogue.join("\n    ");
+            move |v| {
+                v.map(|(instruction, parameters, comment)| match instruction {
+                    AssemblyInstruction::Return => asm_with_comment(&epilogue, &comment).into(),
+                    instruction => Self::generate_instruction(&instruction, &parameters, &comment),
+                })
+                .map(|v| format!("    {}", v))
+            }
         });
-        let epilogue = r#"
-    # Epilogue
-    # leave
-    movq %rbp, %rsp
-    pop %rbp
-    ret
-        "#;
-
-        prologue
-            .lines()
-            .map(ToOwned::to_owned)
-            .map(Ok)
-            .chain(body)
-            .chain(epilogue.lines().map(ToOwned::to_owned).map(Ok))
+
+        prologue.into_iter().map(Ok).chain(body).chain(
+            once("# Epilogue".to_owned())
+                .chain(epilogue)
+                .map(|line| Ok(format!("    {line}"))),
+        )
     }
 }
 
@@ -138,8 +226,21 @@ fn instruction_with_arguments(instruction: &str, arguments: &[&str]) -> Box<str>
     format!("{} {}", instruction, arguments.join(", ")).into()
 }
 
+fn through_scratch(instruction: &str, destination: &str, source: &str, scratch: &str) -> Box<str> {
+    format!(
+        "pushq {scratch}\n    movq {destination}, {scratch}\n    {instruction} {source}, {scratch}\n    movq {scratch}, {destination}\n    popq {scratch}"
+    )
+    .into()
+}
+
 fn asm_with_comment(asm: &str, comment: &str) -> Box<str> {
-    format!("{:<29} # {comment}", asm).into()
+    let (first, rest) = asm.split_once('\n').unwrap_or((asm, ""));
+    let asm = format!("{:<29} # {comment}", first);
+    if rest.is_empty() {
+        asm.into()
+    } else {
+        format!("{asm}\n{rest}").into()
+    }
 }
 
 pub type FullInstruction<R> = (
@@ -150,17 +251,23 @@ pub type FullInstruction<R> = (
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum AssemblyInstruction {
-    Label, // label
-    Jmp,   // label
-    Je,    // label
-    Test,  // a, b
-    Move,  // destination, source
-    Swap,  // a, b
-    Call,  // function
-    Add,   // destination & a, b
-    Sub,   // destination & a, b
-    Mul,   // destination & a, b
-    Div,   // destination & a
+    Label,              // label
+    Jmp,                // label
+    Je,                 // label
+    Test,               // a, b
+    Move,               // destination, source
+    MoveZeroExtendByte, // destination, source
+    MoveByte,           // destination, source
+    Swap,               // a, b
+    Call,               // function
+    Push,               // source
+    Add,                // destination & a, b
+    Sub,                // destination & a, b
+    Mul,                // destination & a, b
+    Div,                // destination & a
+    SignExtend,         //
+    Lea,                // destination, a, b
+    Return,             //
 
     Raw(Box<str>), //  TODO: Remove
 }
@@ -169,11 +276,15 @@ pub enum AssemblyInstruction {
 pub enum AssemblyInstructionParameter<R: Registers> {
     Register(R),
     Memory(usize),
+    Argument(usize),
     Number(f64),
     Char(char),
     Function(Box<str>),
     Label(Box<str>),
     Data(Box<str>),
+    Indirect(R, usize),
+    Global(Box<str>),
+    LowerByte(R),
 }
 
 impl<R: Registers> From<crate::memory_manager::Location<R>> for AssemblyInstructionParameter<R> {
@@ -185,6 +296,9 @@ impl<R: Registers> From<crate::memory_manager::Location<R>> for AssemblyInstruct
             crate::memory_manager::Location::Stack(offset) => {
                 AssemblyInstructionParameter::Memory(offset)
             }
+            crate::memory_manager::Location::Argument(n) => {
+                AssemblyInstructionParameter::Argument(n)
+            }
             crate::memory_manager::Location::Hardcoded(hardcoded) => {
                 AssemblyInstructionParameter::Data(hardcoded)
             }
@@ -200,9 +314,80 @@ impl<R: Registers> From<&crate::memory_manager::Location<R>> for AssemblyInstruc
             crate::memory_manager::Location::Stack(offset) => {
                 AssemblyInstructionParameter::Memory(*offset)
             }
+            crate::memory_manager::Location::Argument(n) => {
+                AssemblyInstructionParameter::Argument(*n)
+            }
             crate::memory_manager::Location::Hardcoded(hardcoded) => {
                 AssemblyInstructionParameter::Data(hardcoded.clone())
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::registers::X86Registers;
+
+    use super::{
+        AssemblyFlavour, AssemblyInstruction, AssemblyInstructionParameter, AtAndTFlavour,
+    };
+
+    fn generate(
+        instruction: AssemblyInstruction,
+        parameters: &[AssemblyInstructionParameter<X86Registers>],
+    ) -> String {
+        AtAndTFlavour::generate_instruction(&instruction, parameters, "")
+            .lines()
+            .map(|line| line.trim().trim_end_matches('#').trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_frame_operands() {
+        assert_eq!(
+            generate(
+                AssemblyInstruction::Move,
+                &[
+                    AssemblyInstructionParameter::Memory(0),
+                    AssemblyInstructionParameter::Argument(1),
+                ]
+            ),
+            "pushq 24(%rbp)\npopq -8(%rbp)"
+        );
+        assert_eq!(
+            generate(
+                AssemblyInstruction::Mul,
+                &[
+                    AssemblyInstructionParameter::Memory(2),
+                    AssemblyInstructionParameter::Register(X86Registers::Rax),
+                ]
+            ),
+            "pushq %rbx\nmovq -24(%rbp), %rbx\nimulq %rax, %rbx\nmovq %rbx, -24(%rbp)\npopq %rbx"
+        );
+        assert_eq!(
+            generate(
+                AssemblyInstruction::Test,
+                &[
+                    AssemblyInstructionParameter::Memory(1),
+                    AssemblyInstructionParameter::Memory(1),
+                ]
+            ),
+            "cmpq $0, -16(%rbp)"
+        );
+    }
+    #[test]
+    fn test_lea() {
+        assert_eq!(
+            generate(
+                AssemblyInstruction::Lea,
+                &[
+                    AssemblyInstructionParameter::Register(X86Registers::Rax),
+                    AssemblyInstructionParameter::Register(X86Registers::Rdi),
+                    AssemblyInstructionParameter::Register(X86Registers::Rsi),
+                ]
+            ),
+            "leaq (%rdi,%rsi), %rax"
+        );
+    }
+}
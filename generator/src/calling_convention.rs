@@ -1,12 +1,12 @@
-use std::iter::zip;
+use std::{iter::zip, slice::from_ref};
 
 use errors::GeneratorErrors;
-use nilang_types::instructions::Instruction;
+use nilang_types::instructions::{Instruction, Width};
 
 use crate::{
     assembly_flavour::{AssemblyInstruction, AssemblyInstructionParameter, FullInstruction},
-    builtin_functions,
-    memory_manager::{free_locations, Location, MemoryManager},
+    builtin_format, builtin_functions,
+    memory_manager::{Location, MemoryManager},
     registers::{Registers, X86Registers},
 };
 
@@ -18,9 +18,11 @@ pub trait CallingConvention: Sized {
         name: &str,
         args: &[Box<str>],
         return_temporary: Option<Box<str>>,
+        variadic: bool,
     ) -> Result<Vec<FullInstruction<Self::Registers>>, GeneratorErrors>;
 
     fn return_location() -> Location<Self::Registers>;
+    fn callee_saved_registers() -> Box<[Self::Registers]>;
     fn nth_argument_location(n: usize) -> Location<Self::Registers>;
 
     fn arguments_locations(arguments: &[Box<str>]) -> Vec<Location<Self::Registers>> {
@@ -31,15 +33,33 @@ pub trait CallingConvention: Sized {
             .collect()
     }
 
+    fn location_hints(instruction: &Instruction) -> Vec<(&str, Location<Self::Registers>)> {
+        call_hints::<Self>(instruction)
+    }
+
+    fn clobbered_registers(instruction: &Instruction) -> Box<[Self::Registers]> {
+        call_clobbers::<Self>(instruction)
+    }
+
+    fn caller_saved_registers() -> Box<[Self::Registers]> {
+        let callee_saved = Self::callee_saved_registers();
+        Self::Registers::all()
+            .iter()
+            .filter(|register| !callee_saved.contains(register))
+            .copied()
+            .collect()
+    }
+
+    fn frame_size(stack_slots: usize, _saved_registers: usize) -> usize {
+        stack_slots * 8
+    }
+
     fn generate_instruction(
         mm: &mut MemoryManager<Self::Registers>,
         instruction: Instruction,
     ) -> Result<Vec<FullInstruction<Self::Registers>>, GeneratorErrors> {
         Ok(match instruction {
-            Instruction::Declare(variable) => {
-                mm.reserve(&variable)?;
-                Vec::new()
-            }
+            Instruction::Declare(_) => Vec::new(),
             Instruction::FunctionCall(name, arguments, return_temporary) => {
                 if let Some(instructions) =
                     builtin_functions::<Self>(mm, &name, &arguments, return_temporary.clone())
@@ -47,7 +67,7 @@ pub trait CallingConvention: Sized {
                     return instructions;
                 }
 
-                Self::generate_function_call(mm, &name, &arguments, return_temporary)?
+                Self::generate_function_call(mm, &name, &arguments, return_temporary, false)?
             }
             Instruction::LoadBoolean(temporary, boolean) => {
                 let location = mm.get_location_or_err(&temporary)?;
@@ -94,24 +114,37 @@ pub trait CallingConvention: Sized {
             }
             Instruction::ReturnVariable(temporary) => {
                 let location = mm.get_location_or_err(&temporary)?;
-                vec![(
-                    AssemblyInstruction::Move,
-                    vec![Self::return_location().into(), location.into()],
-                    format!("Return `{temporary}`").into(),
-                )]
+                vec![
+                    (
+                        AssemblyInstruction::Move,
+                        vec![Self::return_location().into(), location.into()],
+                        format!("Move `{temporary}` to return register").into(),
+                    ),
+                    (
+                        AssemblyInstruction::Return,
+                        vec![],
+                        format!("Return `{temporary}`").into(),
+                    ),
+                ]
             }
             Instruction::TakeArgument(argument, temporary) => {
-                let location = Self::nth_argument_location(argument);
-                mm.reserve_location(&temporary, location.clone())?;
+                let argument_location = Self::nth_argument_location(argument);
+                let location = mm.get_location_or_err(&temporary)?;
+                if *location == argument_location {
+                    return Ok(Vec::new());
+                }
                 vec![(
                     AssemblyInstruction::Move,
-                    vec![location.clone().into(), location.into()],
+                    vec![location.into(), argument_location.into()],
                     format!("Load `{temporary}` as argument {argument}").into(),
                 )]
             }
             Instruction::Copy(to, from) => {
                 let from_loc = mm.get_location_or_err(&from)?;
                 let to_loc = mm.get_location_or_err(&to)?;
+                if from_loc == to_loc {
+                    return Ok(Vec::new());
+                }
                 vec![(
                     AssemblyInstruction::Move,
                     vec![to_loc.into(), from_loc.into()],
@@ -201,6 +234,24 @@ pub trait CallingConvention: Sized {
                     ),
                 ]
             }
+            Instruction::LoadFunctionLocation(temporary, function) => {
+                let location = mm.get_location_or_err(&temporary)?;
+                vec![(
+                    AssemblyInstruction::Move,
+                    vec![
+                        location.into(),
+                        AssemblyInstructionParameter::Data(function.clone()),
+                    ],
+                    format!("Load `{function}` function pointer into `{temporary}`").into(),
+                )]
+            }
+            Instruction::IndirectFunctionCall(_, _, _)
+            | Instruction::LoadGlobal(_, _, _)
+            | Instruction::StoreGlobal(_, _, _)
+            | Instruction::LoadFromAddress(_, _, _)
+            | Instruction::StoreToAddress(_, _, _) => {
+                Self::generate_instruction_specific(mm, instruction)?
+            }
             _ => todo!(),
         })
     }
@@ -221,11 +272,38 @@ impl CallingConvention for SystemVAmd64Abi {
         name: &str,
         arguments: &[Box<str>],
         return_temporary: Option<Box<str>>,
+        variadic: bool,
     ) -> Result<Vec<FullInstruction<Self::Registers>>, GeneratorErrors> {
         let arguments_locations = Self::arguments_locations(arguments);
-        let arguments_allocations = allocate_in(mm, arguments, &arguments_locations)?;
+        let in_registers = register_arguments(&arguments_locations);
+
+        let (stack_arguments, stack_cleanup) = push_arguments(mm, arguments, in_registers)?;
+        let arguments_allocations = parallel_move(
+            zip(arguments, arguments_locations)
+                .take(in_registers)
+                .enumerate()
+                .map(|(i, (argument, location))| {
+                    Ok((
+                        location,
+                        mm.get_location_or_err(argument)?.clone(),
+                        format!("Load `{argument}` as argument {i}").into(),
+                    ))
+                })
+                .collect::<Result<_, GeneratorErrors>>()?,
+        );
 
-        let stack_alignment = [];
+        let vector_registers = if variadic {
+            vec![(
+                AssemblyInstruction::MoveByte,
+                vec![
+                    AssemblyInstructionParameter::LowerByte(X86Registers::Rax),
+                    AssemblyInstructionParameter::Number(0.),
+                ],
+                "No vector registers used by variadic call".into(),
+            )]
+        } else {
+            vec![]
+        };
 
         let function_call = [(
             AssemblyInstruction::Call,
@@ -233,8 +311,6 @@ impl CallingConvention for SystemVAmd64Abi {
             format!("Call function `{name}`").into(),
         )];
 
-        let stack_cleanup = [];
-
         let move_result = if let Some(return_temporary) = return_temporary {
             let return_register = mm.get_location_or_err(&return_temporary)?;
             [(
@@ -250,15 +326,12 @@ impl CallingConvention for SystemVAmd64Abi {
             [].into()
         };
 
-        for i in 0..arguments.len() {
-            mm.free(&format!("arg_{i}"));
-        }
-
         Ok([
+            stack_arguments,
             arguments_allocations,
-            stack_alignment.into(),
+            vector_registers,
             function_call.into(),
-            stack_cleanup.into(),
+            stack_cleanup,
             move_result,
         ]
         .concat())
@@ -268,6 +341,51 @@ impl CallingConvention for SystemVAmd64Abi {
         Location::Register(X86Registers::Rax)
     }
 
+    fn callee_saved_registers() -> Box<[Self::Registers]> {
+        Box::new([
+            X86Registers::Rbx,
+            X86Registers::R12,
+            X86Registers::R13,
+            X86Registers::R14,
+            X86Registers::R15,
+        ])
+    }
+
+    fn frame_size(stack_slots: usize, saved_registers: usize) -> usize {
+        (stack_slots * 8 + saved_registers * 8).next_multiple_of(16) - saved_registers * 8
+    }
+
+    fn clobbered_registers(instruction: &Instruction) -> Box<[Self::Registers]> {
+        match instruction {
+            Instruction::DivideVariables(_, _, _) | Instruction::ModuloVariables(_, _, _) => {
+                Box::new([X86Registers::Rax, X86Registers::Rdx, X86Registers::R11])
+            }
+            Instruction::LoadGlobal(_, _, _)
+            | Instruction::StoreGlobal(_, _, _)
+            | Instruction::LoadFromAddress(_, _, _) => Box::new([X86Registers::R11]),
+            Instruction::StoreToAddress(_, _, _) => {
+                Box::new([X86Registers::R10, X86Registers::R11])
+            }
+            instruction => call_clobbers::<Self>(instruction),
+        }
+    }
+
+    fn location_hints(instruction: &Instruction) -> Vec<(&str, Location<Self::Registers>)> {
+        let rax = Location::Register(X86Registers::Rax);
+        match instruction {
+            Instruction::DivideVariables(result, divident, _) => {
+                vec![(divident.as_ref(), rax.clone()), (result.as_ref(), rax)]
+            }
+            Instruction::ModuloVariables(result, divident, _) => {
+                vec![
+                    (divident.as_ref(), rax),
+                    (result.as_ref(), Location::Register(X86Registers::Rdx)),
+                ]
+            }
+            instruction => call_hints::<Self>(instruction),
+        }
+    }
+
     fn nth_argument_location(n: usize) -> Location<Self::Registers> {
         match n {
             0 => Location::Register(X86Registers::Rdi),
@@ -276,7 +394,7 @@ impl CallingConvention for SystemVAmd64Abi {
             3 => Location::Register(X86Registers::Rcx),
             4 => Location::Register(X86Registers::R8),
             5 => Location::Register(X86Registers::R9),
-            _ => Location::Stack(n - 6),
+            _ => Location::Argument(n - 6),
         }
     }
 
@@ -285,97 +403,179 @@ impl CallingConvention for SystemVAmd64Abi {
         instruction: Instruction,
     ) -> Result<Vec<FullInstruction<X86Registers>>, GeneratorErrors> {
         Ok(match instruction {
-            Instruction::DivideVariables(result, divident, divisor) => {
-                let empty_temp = "@empty".to_string();
-                mm.reserve(&empty_temp)?;
-                let mut alloc = allocate_in(
-                    mm,
-                    &[divident.clone(), empty_temp.clone().into()],
-                    &[
-                        Location::Register(X86Registers::Rax),
-                        Location::Register(X86Registers::Rdx),
-                    ],
-                )?;
-                mm.free(&empty_temp);
+            Instruction::DivideVariables(result, divident, divisor) => divide(
+                mm,
+                (&result, &divident, &divisor),
+                X86Registers::Rax,
+                "division",
+            )?,
+            Instruction::ModuloVariables(result, divident, divisor) => divide(
+                mm,
+                (&result, &divident, &divisor),
+                X86Registers::Rdx,
+                "modulo",
+            )?,
+            Instruction::IndirectFunctionCall(function, arguments, return_temporary) => {
+                let callee = Location::Register(X86Registers::R11);
+                let environment_arguments = [arguments.as_ref(), from_ref(&function)].concat();
+                let arguments_locations = Self::arguments_locations(&environment_arguments);
+                let in_registers = register_arguments(&arguments_locations);
+
+                let (mut alloc, stack_cleanup) =
+                    push_arguments(mm, &environment_arguments, in_registers)?;
+                let mut moves = zip(&environment_arguments, arguments_locations)
+                    .take(in_registers)
+                    .enumerate()
+                    .map(|(i, (argument, location))| {
+                        Ok((
+                            location,
+                            mm.get_location_or_err(argument)?.clone(),
+                            format!("Load `{argument}` as argument {i}").into(),
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, GeneratorErrors>>()?;
+                moves.push((
+                    callee.clone(),
+                    mm.get_location_or_err(&function)?.clone(),
+                    format!("Load `{function}` as callee").into(),
+                ));
+                alloc.append(&mut parallel_move(moves));
 
                 alloc.append(&mut vec![
                     (
                         AssemblyInstruction::Move,
                         vec![
-                            Location::Register(X86Registers::Rdx).into(),
-                            AssemblyInstructionParameter::Number(0f64),
+                            callee.clone().into(),
+                            AssemblyInstructionParameter::Indirect(X86Registers::R11, 0),
                         ],
-                        format!("Prepare `{result}` for division").into(),
+                        format!("Load code pointer of `{function}`").into(),
                     ),
                     (
+                        AssemblyInstruction::Call,
+                        vec![callee.into()],
+                        format!("Call function value `{function}`").into(),
+                    ),
+                ]);
+                alloc.extend(stack_cleanup);
+
+                if let Some(return_temporary) = return_temporary {
+                    alloc.push((
                         AssemblyInstruction::Move,
                         vec![
-                            Location::Register(X86Registers::Rax).into(),
-                            mm.get_location_or_err(&divident)?.into(),
+                            mm.get_location_or_err(&return_temporary)?.into(),
+                            Self::return_location().into(),
                         ],
-                        format!("Prepare `{result}` for division").into(),
-                    ),
+                        format!("Move result of `{function}` to return register").into(),
+                    ));
+                }
+
+                alloc
+            }
+            Instruction::LoadGlobal(result, global, width) => {
+                let scratch = Location::Register(X86Registers::R11);
+                let mut alloc = Vec::new();
+
+                alloc.append(&mut vec![
                     (
-                        AssemblyInstruction::Div,
-                        vec![mm.get_location_or_err(&divisor)?.into()],
-                        format!("Divide `{divident}` by `{divisor}`").into(),
+                        match width {
+                            Width::Byte => AssemblyInstruction::MoveZeroExtendByte,
+                            Width::Quad => AssemblyInstruction::Move,
+                        },
+                        vec![
+                            scratch.clone().into(),
+                            AssemblyInstructionParameter::Global(global.clone()),
+                        ],
+                        format!("Load global `{global}`").into(),
                     ),
                     (
                         AssemblyInstruction::Move,
+                        vec![mm.get_location_or_err(&result)?.into(), scratch.into()],
+                        format!("Move global `{global}` into `{result}`").into(),
+                    ),
+                ]);
+
+                alloc
+            }
+            Instruction::StoreGlobal(global, width, value) => {
+                let mut alloc = parallel_move(vec![(
+                    Location::Register(X86Registers::R11),
+                    mm.get_location_or_err(&value)?.clone(),
+                    format!("Load `{value}` for store").into(),
+                )]);
+
+                alloc.push(match width {
+                    Width::Byte => (
+                        AssemblyInstruction::MoveByte,
                         vec![
-                            mm.get_location_or_err(&result)?.into(),
-                            Location::Register(X86Registers::Rax).into(),
+                            AssemblyInstructionParameter::Global(global.clone()),
+                            AssemblyInstructionParameter::LowerByte(X86Registers::R11),
                         ],
-                        format!("Move result of division into `{result}`").into(),
+                        format!("Store `{value}` in global `{global}`").into(),
                     ),
-                ]);
+                    Width::Quad => (
+                        AssemblyInstruction::Move,
+                        vec![
+                            AssemblyInstructionParameter::Global(global.clone()),
+                            Location::Register(X86Registers::R11).into(),
+                        ],
+                        format!("Store `{value}` in global `{global}`").into(),
+                    ),
+                });
 
                 alloc
             }
-            Instruction::ModuloVariables(result, divident, divisor) => {
-                let empty_temp = "@empty".to_string();
-                mm.reserve(&empty_temp)?;
-                let mut alloc = allocate_in(
-                    mm,
-                    &[divident.clone(), empty_temp.clone().into()],
-                    &[
-                        Location::Register(X86Registers::Rax),
-                        Location::Register(X86Registers::Rdx),
-                    ],
-                )?;
-                mm.free(&empty_temp);
+            Instruction::LoadFromAddress(result, address, offset) => {
+                let address_location = Location::Register(X86Registers::R11);
+                let mut alloc = parallel_move(vec![(
+                    address_location.clone(),
+                    mm.get_location_or_err(&address)?.clone(),
+                    format!("Load address `{address}`").into(),
+                )]);
 
                 alloc.append(&mut vec![
                     (
                         AssemblyInstruction::Move,
                         vec![
-                            Location::Register(X86Registers::Rdx).into(),
-                            AssemblyInstructionParameter::Number(0f64),
+                            address_location.clone().into(),
+                            AssemblyInstructionParameter::Indirect(X86Registers::R11, offset * 8),
                         ],
-                        format!("Prepare `{result}` for modulo").into(),
+                        format!("Load value {offset} of `{address}`").into(),
                     ),
                     (
                         AssemblyInstruction::Move,
                         vec![
-                            Location::Register(X86Registers::Rax).into(),
-                            mm.get_location_or_err(&divident)?.into(),
+                            mm.get_location_or_err(&result)?.into(),
+                            address_location.into(),
                         ],
-                        format!("Prepare `{result}` for modulo").into(),
+                        format!("Move value {offset} of `{address}` into `{result}`").into(),
                     ),
+                ]);
+
+                alloc
+            }
+            Instruction::StoreToAddress(address, offset, value) => {
+                let mut alloc = parallel_move(vec![
                     (
-                        AssemblyInstruction::Div,
-                        vec![mm.get_location_or_err(&divisor)?.into()],
-                        format!("Divide `{divident}` by `{divisor}`").into(),
+                        Location::Register(X86Registers::R10),
+                        mm.get_location_or_err(&address)?.clone(),
+                        format!("Load address `{address}`").into(),
                     ),
                     (
-                        AssemblyInstruction::Move,
-                        vec![
-                            mm.get_location_or_err(&result)?.into(),
-                            Location::Register(X86Registers::Rdx).into(),
-                        ],
-                        format!("Move result of modulo into `{result}`").into(),
+                        Location::Register(X86Registers::R11),
+                        mm.get_location_or_err(&value)?.clone(),
+                        format!("Load `{value}` for store").into(),
                     ),
                 ]);
+
+                alloc.push((
+                    AssemblyInstruction::Move,
+                    vec![
+                        AssemblyInstructionParameter::Indirect(X86Registers::R10, offset * 8),
+                        Location::Register(X86Registers::R11).into(),
+                    ],
+                    format!("Store `{value}` as value {offset} of `{address}`").into(),
+                ));
+
                 alloc
             }
             _ => unreachable!(),
@@ -383,37 +583,196 @@ impl CallingConvention for SystemVAmd64Abi {
     }
 }
 
-pub fn allocate_in<R: Registers>(
-    mm: &mut MemoryManager<R>,
-    temporaries: &[Box<str>],
-    locations: &[Location<R>],
-) -> Result<Vec<FullInstruction<R>>, GeneratorErrors> {
-    let free = free_locations(mm, locations)?;
-    let allocations = zip(temporaries.iter(), locations.iter())
+fn register_arguments(locations: &[Location<X86Registers>]) -> usize {
+    locations
+        .iter()
+        .take_while(|location| matches!(location, Location::Register(_)))
+        .count()
+}
+
+type StackArguments = (
+    Vec<FullInstruction<X86Registers>>,
+    Vec<FullInstruction<X86Registers>>,
+);
+
+fn push_arguments(
+    mm: &MemoryManager<X86Registers>,
+    arguments: &[Box<str>],
+    in_registers: usize,
+) -> Result<StackArguments, GeneratorErrors> {
+    let on_stack = arguments.len() - in_registers;
+    if on_stack == 0 {
+        return Ok((vec![], vec![]));
+    }
+
+    let rsp = AssemblyInstructionParameter::Register(X86Registers::Rsp);
+    let padding = on_stack % 2 * 8;
+    let alignment = (padding > 0).then(|| {
+        (
+            AssemblyInstruction::Sub,
+            vec![rsp.clone(), AssemblyInstructionParameter::Number(8.)],
+            "Align stack for call".into(),
+        )
+    });
+
+    let pushes = arguments
+        .iter()
         .enumerate()
-        .map(|(i, (arg_temp, desired_arg_location))| {
-            let current_arg_loc = mm.get_location_or_err(arg_temp)?.to_owned();
-            mm.reserve_location(&format!("@arg_{i}"), desired_arg_location.clone())?;
+        .skip(in_registers)
+        .rev()
+        .map(|(i, argument)| {
             Ok((
-                AssemblyInstruction::Move,
-                vec![desired_arg_location.into(), current_arg_loc.clone().into()],
-                format!("Load `{arg_temp}` as argument {i}").into(),
+                AssemblyInstruction::Push,
+                vec![mm.get_location_or_err(argument)?.into()],
+                format!("Push `{argument}` as argument {i}").into(),
             ))
         })
         .collect::<Result<Vec<_>, GeneratorErrors>>()?;
 
-    for i in 0..temporaries.len() {
-        mm.free(&format!("@arg_{i}"));
+    let cleanup = (
+        AssemblyInstruction::Add,
+        vec![
+            rsp,
+            AssemblyInstructionParameter::Number((on_stack * 8 + padding) as f64),
+        ],
+        "Remove stack arguments".into(),
+    );
+
+    Ok((alignment.into_iter().chain(pushes).collect(), vec![cleanup]))
+}
+
+fn call_hints<C: CallingConvention>(
+    instruction: &Instruction,
+) -> Vec<(&str, Location<C::Registers>)> {
+    match instruction {
+        Instruction::FunctionCall(name, arguments, result) => {
+            let offset = usize::from(builtin_format(name).is_some());
+            arguments
+                .iter()
+                .enumerate()
+                .map(|(i, argument)| (argument.as_ref(), C::nth_argument_location(i + offset)))
+                .chain(
+                    result
+                        .iter()
+                        .map(|result| (result.as_ref(), C::return_location())),
+                )
+                .collect()
+        }
+        Instruction::ReturnVariable(temporary) => vec![(temporary.as_ref(), C::return_location())],
+        _ => Vec::new(),
+    }
+}
+
+fn call_clobbers<C: CallingConvention>(instruction: &Instruction) -> Box<[C::Registers]> {
+    match instruction {
+        Instruction::FunctionCall(_, _, _) | Instruction::IndirectFunctionCall(_, _, _) => {
+            C::caller_saved_registers()
+        }
+        _ => Box::new([]),
+    }
+}
+
+fn divide(
+    mm: &MemoryManager<X86Registers>,
+    (result, divident, divisor): (&str, &str, &str),
+    output: X86Registers,
+    operation: &str,
+) -> Result<Vec<FullInstruction<X86Registers>>, GeneratorErrors> {
+    let rax = Location::Register(X86Registers::Rax);
+    let rdx = Location::Register(X86Registers::Rdx);
+
+    let mut divisor_location = mm.get_location_or_err(divisor)?.clone();
+    let mut instructions = Vec::new();
+    if divisor_location == rax || divisor_location == rdx {
+        let scratch = Location::Register(X86Registers::R11);
+        instructions.push((
+            AssemblyInstruction::Move,
+            vec![scratch.clone().into(), divisor_location.into()],
+            format!("Move `{divisor}` out of the way of {operation}").into(),
+        ));
+        divisor_location = scratch;
     }
 
-    Ok([free, allocations].concat())
+    instructions.append(&mut vec![
+        (
+            AssemblyInstruction::Move,
+            vec![rax.into(), mm.get_location_or_err(divident)?.into()],
+            format!("Prepare `{result}` for {operation}").into(),
+        ),
+        (
+            AssemblyInstruction::SignExtend,
+            vec![],
+            format!("Sign extend `{divident}` into rdx").into(),
+        ),
+        (
+            AssemblyInstruction::Div,
+            vec![divisor_location.into()],
+            format!("Divide `{divident}` by `{divisor}`").into(),
+        ),
+        (
+            AssemblyInstruction::Move,
+            vec![
+                mm.get_location_or_err(result)?.into(),
+                Location::Register(output).into(),
+            ],
+            format!("Move result of {operation} into `{result}`").into(),
+        ),
+    ]);
+
+    Ok(instructions)
+}
+
+pub fn parallel_move<R: Registers>(
+    moves: Vec<(Location<R>, Location<R>, Box<str>)>,
+) -> Vec<FullInstruction<R>> {
+    let mut pending = moves
+        .into_iter()
+        .filter(|(to, from, _)| to != from)
+        .collect::<Vec<_>>();
+
+    let mut instructions = Vec::new();
+    while !pending.is_empty() {
+        let ready = pending
+            .iter()
+            .position(|(to, _, _)| pending.iter().all(|(_, from, _)| from != to));
+
+        match ready {
+            Some(i) => {
+                let (to, from, comment) = pending.remove(i);
+                instructions.push((
+                    AssemblyInstruction::Move,
+                    vec![to.into(), from.into()],
+                    comment,
+                ));
+            }
+            None => {
+                // Every destination is still read by another move, so the rest are cycles
+                let (to, from, comment) = pending.remove(0);
+                for (_, source, _) in &mut pending {
+                    if *source == to {
+                        *source = from.clone();
+                    } else if *source == from {
+                        *source = to.clone();
+                    }
+                }
+                pending.retain(|(to, from, _)| to != from);
+                instructions.push((
+                    AssemblyInstruction::Swap,
+                    vec![to.into(), from.into()],
+                    comment,
+                ));
+            }
+        }
+    }
+
+    instructions
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{
         assembly_flavour::AssemblyInstruction,
-        calling_convention::allocate_in,
+        calling_convention::parallel_move,
         memory_manager::{Location, MemoryManager},
         registers::tests::TestRegisters,
     };
@@ -429,6 +788,7 @@ mod tests {
             _name: &str,
             _args: &[Box<str>],
             _return_temporary: Option<Box<str>>,
+            _variadic: bool,
         ) -> Result<
             Vec<crate::assembly_flavour::FullInstruction<Self::Registers>>,
             errors::GeneratorErrors,
@@ -440,6 +800,10 @@ mod tests {
             Location::Register(TestRegisters::R(0))
         }
 
+        fn callee_saved_registers() -> Box<[Self::Registers]> {
+            Box::new([])
+        }
+
         fn nth_argument_location(n: usize) -> Location<Self::Registers> {
             if n == 0 {
                 Location::Register(TestRegisters::R(1))
@@ -477,61 +841,59 @@ mod tests {
     }
 
     #[test]
-    fn arguments_allocations() {
-        let mut mm = MemoryManager::<TestRegisters>::default();
-        mm.reserve("a").unwrap();
-        mm.reserve("b").unwrap();
-        mm.reserve("c").unwrap();
-
-        let arguments = ["a".into(), "b".into(), "c".into()];
-        let arguments_locations = TestCallingConvention::arguments_locations(&arguments);
-        let instructions = allocate_in(&mut mm, &arguments, &arguments_locations).unwrap();
+    fn test_parallel_move() {
+        let r = |n| Location::Register(TestRegisters::R(n));
+        let instructions = parallel_move(vec![
+            (r(1), r(0), "a".into()),
+            (r(2), r(1), "b".into()),
+            (Location::Stack(0), r(2), "c".into()),
+        ]);
 
         assert_eq!(
             instructions,
             [
                 (
-                    AssemblyInstruction::Swap,
-                    vec![
-                        Location::Register(TestRegisters::R(1)).into(),
-                        Location::Stack(0).into()
-                    ],
-                    "Swap b and @swap_temp_0".into()
-                ),
-                (
-                    AssemblyInstruction::Swap,
-                    vec![
-                        Location::Register(TestRegisters::R(2)).into(),
-                        Location::Stack(1).into()
-                    ],
-                    "Swap c and @swap_temp_1".into()
+                    AssemblyInstruction::Move,
+                    vec![Location::Stack(0).into(), r(2).into()],
+                    "c".into()
                 ),
                 (
-                    AssemblyInstruction::Swap,
-                    vec![Location::Stack(0).into(), Location::Stack(2).into()],
-                    "Swap b and @swap_temp_2".into()
+                    AssemblyInstruction::Move,
+                    vec![r(2).into(), r(1).into()],
+                    "b".into()
                 ),
                 (
                     AssemblyInstruction::Move,
-                    vec![
-                        Location::Register(TestRegisters::R(1)).into(),
-                        Location::Register(TestRegisters::R(0)).into()
-                    ],
-                    "Load `a` as argument 0".into()
+                    vec![r(1).into(), r(0).into()],
+                    "a".into()
                 ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parallel_move_cycle() {
+        let r = |n| Location::Register(TestRegisters::R(n));
+        let instructions = parallel_move(vec![
+            (r(0), r(1), "a".into()),
+            (r(1), r(2), "b".into()),
+            (r(2), r(0), "c".into()),
+            (Location::Stack(1), Location::Stack(1), "d".into()),
+        ]);
+
+        assert_eq!(
+            instructions,
+            [
                 (
-                    AssemblyInstruction::Move,
-                    vec![
-                        Location::Register(TestRegisters::R(2)).into(),
-                        Location::Stack(2).into()
-                    ],
-                    "Load `b` as argument 1".into()
+                    AssemblyInstruction::Swap,
+                    vec![r(0).into(), r(1).into()],
+                    "a".into()
                 ),
                 (
-                    AssemblyInstruction::Move,
-                    vec![Location::Stack(0).into(), Location::Stack(1).into()],
-                    "Load `c` as argument 2".into()
-                )
+                    AssemblyInstruction::Swap,
+                    vec![r(1).into(), r(2).into()],
+                    "b".into()
+                ),
             ]
         );
     }
@@ -1,6 +1,9 @@
+mod allocator;
 mod assembly_flavour;
 mod calling_convention;
+mod liveness;
 mod memory_manager;
+mod peephole;
 mod registers;
 
 pub mod options {
@@ -11,13 +14,11 @@ pub mod options {
 
 use std::iter::once;
 
-use assembly_flavour::{
-    AssemblyFlavour, AssemblyInstruction, AssemblyInstructionParameter, FullInstruction,
-};
+use assembly_flavour::{AssemblyFlavour, AssemblyInstructionParameter, FullInstruction};
 use calling_convention::CallingConvention;
 use errors::GeneratorErrors;
 use memory_manager::MemoryManager;
-use nilang_types::instructions::Instruction;
+use nilang_types::instructions::{DataValue, Instruction};
 use registers::{Registers, X86Registers};
 
 pub fn generate_program<A>() -> impl Iterator<Item = String> + 'static
@@ -27,19 +28,23 @@ where
     A::generate_program_scaffold().into_iter()
 }
 
-pub fn generate_data<A>(data: &[(Box<str>, Box<str>)]) -> impl Iterator<Item = String> + '_
+pub fn generate_data<A>(data: &[(Box<str>, DataValue)]) -> impl Iterator<Item = String> + '_
 where
     A: AssemblyFlavour<X86Registers>,
 {
     let builtin = [
-        ("printd_format".into(), "%d\\n".into()),
-        ("print_format".into(), "%s\\n".into()),
-        ("printc_format".into(), "%c\\n".into()),
+        ("printi_format".into(), DataValue::String("%ld\\n".into())),
+        ("print_format".into(), DataValue::String("%s\\n".into())),
+        ("printc_format".into(), DataValue::String("%c\\n".into())),
     ];
     [data, &builtin]
         .concat()
         .into_iter()
-        .map(move |(name, value)| format!("{}: .asciz \"{}\"\n", name, value))
+        .map(move |(name, value)| match value {
+            DataValue::String(text) => format!("{}: .asciz \"{}\"\n", name, text),
+            DataValue::Quad(number) => format!("{}: .quad {}\n", name, number),
+            DataValue::Byte(byte) => format!("{}: .byte {}\n", name, byte),
+        })
 }
 
 pub fn generate_function<'a, R, C, A>(
@@ -53,8 +58,12 @@ where
     A: AssemblyFlavour<R>,
 {
     let header = A::generate_function_header(&name);
-    let body =
-        A::generate_function_body(generate_instructions::<R, C>(data, instructions)).map(|line| {
+    let instructions = instructions.map(|instruction| prefix_labels(&name, instruction));
+    let (instructions, frame_size, saved_registers) =
+        generate_instructions::<R, C>(data, instructions);
+    let instructions = peephole::optimize(instructions);
+    let body = A::generate_function_body(instructions.into_iter(), frame_size, saved_registers)
+        .map(|line| {
             line.map(|line| {
                 let line = line.trim();
                 if line.is_empty() {
@@ -68,35 +77,62 @@ where
     once(Ok(header)).chain(body)
 }
 
-fn generate_instructions<'a, R, C>(
-    data: &'a [Box<str>],
-    instructions: impl Iterator<Item = Instruction> + 'a,
-) -> impl Iterator<Item = Result<FullInstruction<C::Registers>, GeneratorErrors>> + 'a
+type GeneratedInstructions<R> = Vec<Result<FullInstruction<R>, GeneratorErrors>>;
+
+fn generate_instructions<R, C>(
+    data: &[Box<str>],
+    instructions: impl Iterator<Item = Instruction>,
+) -> (GeneratedInstructions<R>, usize, Vec<R>)
 where
-    R: Registers + 'a,
+    R: Registers,
     C: CallingConvention<Registers = R>,
 {
     let mut mm = MemoryManager::<R>::new(&[builtin_variables(), data.into()].concat());
-    Box::new(instructions.flat_map(move |instruction| {
-        let generated_instruction = C::generate_instruction(&mut mm, instruction);
-
-        match generated_instruction {
-            Ok(v) => v
-                .into_iter()
-                .map(
-                    Ok::<
-                        (
-                            AssemblyInstruction,
-                            Vec<AssemblyInstructionParameter<C::Registers>>,
-                            Box<str>,
-                        ),
-                        GeneratorErrors,
-                    >,
-                )
-                .collect(),
-            Err(e) => vec![Err(e)],
+    let instructions = instructions.collect::<Vec<_>>();
+    mm.assign(allocator::allocate::<C>(&instructions));
+
+    let generated = instructions
+        .into_iter()
+        .flat_map(
+            |instruction| match C::generate_instruction(&mut mm, instruction) {
+                Ok(v) => v.into_iter().map(Ok).collect(),
+                Err(e) => vec![Err(e)],
+            },
+        )
+        .collect::<Vec<_>>();
+
+    let saved_registers = C::callee_saved_registers()
+        .iter()
+        .filter(|register| {
+            generated.iter().flatten().any(|(_, parameters, _)| {
+                parameters.iter().any(|parameter| match parameter {
+                    AssemblyInstructionParameter::Register(used)
+                    | AssemblyInstructionParameter::LowerByte(used)
+                    | AssemblyInstructionParameter::Indirect(used, _) => used == *register,
+                    _ => false,
+                })
+            })
+        })
+        .copied()
+        .collect::<Vec<_>>();
+
+    (
+        generated,
+        C::frame_size(mm.stack_slots(), saved_registers.len()),
+        saved_registers,
+    )
+}
+
+fn prefix_labels(function: &str, instruction: Instruction) -> Instruction {
+    let prefixed = |label: Box<str>| format!("{function}__{label}").into();
+    match instruction {
+        Instruction::Label(label) => Instruction::Label(prefixed(label)),
+        Instruction::Jump(label) => Instruction::Jump(prefixed(label)),
+        Instruction::ConditionalJump(check, label) => {
+            Instruction::ConditionalJump(check, prefixed(label))
         }
-    }))
+        instruction => instruction,
+    }
 }
 
 fn builtin_functions<C: CallingConvention>(
@@ -105,31 +141,22 @@ fn builtin_functions<C: CallingConvention>(
     arguments: &[Box<str>],
     _return_temporary: Option<Box<str>>,
 ) -> Option<Result<Vec<FullInstruction<C::Registers>>, GeneratorErrors>> {
-    match name {
-        "printb" => Some(C::generate_function_call(
+    builtin_format(name).map(|format| {
+        C::generate_function_call(
             mm,
             "printf",
-            &["printi_format".into(), arguments.first().unwrap().clone()],
+            &[format.into(), arguments.first().unwrap().clone()],
             None,
-        )),
-        "printi" => Some(C::generate_function_call(
-            mm,
-            "printf",
-            &["printi_format".into(), arguments.first().unwrap().clone()],
-            None,
-        )),
-        "printc" => Some(C::generate_function_call(
-            mm,
-            "printf",
-            &["printc_format".into(), arguments.first().unwrap().clone()],
-            None,
-        )),
-        "print" => Some(C::generate_function_call(
-            mm,
-            "printf",
-            &["print_format".into(), arguments.first().unwrap().clone()],
-            None,
-        )),
+            true,
+        )
+    })
+}
+
+fn builtin_format(name: &str) -> Option<&'static str> {
+    match name {
+        "printb" | "printi" => Some("printi_format"),
+        "printc" => Some("printc_format"),
+        "print" => Some("print_format"),
         _ => None,
     }
 }
@@ -0,0 +1,167 @@
+use std::{
+    collections::{HashMap, HashSet},
+    iter::zip,
+};
+
+use nilang_types::instructions::Instruction;
+
+pub fn intervals(instructions: &[Instruction]) -> HashMap<&str, (usize, usize)> {
+    let live_out = live_out(instructions);
+
+    let mut intervals = HashMap::<&str, (usize, usize)>::new();
+    for (i, instruction) in instructions.iter().enumerate() {
+        for temporary in instruction
+            .uses()
+            .into_iter()
+            .chain(instruction.definition())
+            .map(|temporary| temporary.as_ref())
+            .chain(live_out[i].iter().copied())
+        {
+            intervals.entry(temporary).or_insert((i, i)).1 = i;
+        }
+    }
+    intervals
+}
+
+pub fn live_across(instructions: &[Instruction]) -> Vec<Vec<&str>> {
+    zip(instructions, live_out(instructions))
+        .map(|(instruction, live)| {
+            let definition = instruction.definition().map(|temporary| temporary.as_ref());
+            let mut live = live
+                .into_iter()
+                .filter(|temporary| Some(*temporary) != definition)
+                .collect::<Vec<_>>();
+            live.sort();
+            live
+        })
+        .collect()
+}
+
+fn successors(instructions: &[Instruction], labels: &HashMap<&str, usize>, i: usize) -> Vec<usize> {
+    let next = Some(i + 1).filter(|next| *next < instructions.len());
+    let target = |label: &str| labels.get(label).copied();
+
+    match &instructions[i] {
+        Instruction::Jump(label) => target(label).into_iter().collect(),
+        Instruction::ConditionalJump(_, label) => next.into_iter().chain(target(label)).collect(),
+        Instruction::ReturnVariable(_) => Vec::new(),
+        _ => next.into_iter().collect(),
+    }
+}
+
+fn live_out(instructions: &[Instruction]) -> Vec<HashSet<&str>> {
+    let labels = instructions
+        .iter()
+        .enumerate()
+        .filter_map(|(i, instruction)| match instruction {
+            Instruction::Label(label) => Some((label.as_ref(), i)),
+            _ => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let mut live_in = vec![HashSet::<&str>::new(); instructions.len()];
+    let mut live_out = vec![HashSet::<&str>::new(); instructions.len()];
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for i in (0..instructions.len()).rev() {
+            let out = successors(instructions, &labels, i)
+                .into_iter()
+                .flat_map(|successor| live_in[successor].iter().copied())
+                .collect::<HashSet<_>>();
+
+            let definition = instructions[i]
+                .definition()
+                .map(|temporary| temporary.as_ref());
+            let r#in = instructions[i]
+                .uses()
+                .into_iter()
+                .map(|temporary| temporary.as_ref())
+                .chain(out.iter().copied().filter(|live| Some(*live) != definition))
+                .collect::<HashSet<_>>();
+
+            if r#in != live_in[i] || out != live_out[i] {
+                changed = true;
+                live_in[i] = r#in;
+                live_out[i] = out;
+            }
+        }
+    }
+
+    live_out
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::parse_instructions;
+
+    use super::{intervals, live_across};
+
+    #[test]
+    fn test_intervals() {
+        let instructions = parse_instructions(
+            "declare a
+            a = num 1
+            declare b
+            b = add a, a
+            declare c
+            ret b",
+        )
+        .unwrap();
+
+        let intervals = intervals(&instructions);
+        assert_eq!(intervals["a"], (1, 3));
+        assert_eq!(intervals["b"], (3, 5));
+        assert_eq!(intervals.len(), 2);
+    }
+
+    #[test]
+    fn test_intervals_in_loop() {
+        let instructions = parse_instructions(
+            "n = arg 0
+            declare total
+            total = num 0
+            declare one
+            one = num 1
+            loop:
+            declare done
+            done = copy n
+            jz done, end
+            total = add total, n
+            n = sub n, one
+            jmp loop
+            end:
+            ret total",
+        )
+        .unwrap();
+
+        let intervals = intervals(&instructions);
+        assert_eq!(intervals["done"], (7, 8));
+        assert_eq!(intervals["n"], (0, 11));
+        assert_eq!(intervals["one"], (4, 11));
+        assert_eq!(intervals["total"], (2, 13));
+    }
+
+    #[test]
+    fn test_live_across() {
+        let instructions = parse_instructions(
+            "n = arg 0
+            declare one
+            one = num 1
+            loop:
+            jz n, end
+            n = div n, one
+            jmp loop
+            end:
+            ret n",
+        )
+        .unwrap();
+
+        let live = live_across(&instructions);
+        assert_eq!(live[4], ["n", "one"]);
+        assert_eq!(live[5], ["one"]);
+        assert_eq!(live[8], Vec::<&str>::new());
+    }
+}
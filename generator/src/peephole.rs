@@ -0,0 +1,184 @@
+use errors::GeneratorErrors;
+
+use crate::{
+    assembly_flavour::{AssemblyInstruction, AssemblyInstructionParameter, FullInstruction},
+    registers::Registers,
+};
+
+type Rewrite<R> = (usize, Vec<FullInstruction<R>>);
+type Rule<R> = fn(&[FullInstruction<R>]) -> Option<Rewrite<R>>;
+
+fn rules<R: Registers>() -> [(&'static str, Rule<R>); 3] {
+    [
+        ("self-move", self_move),
+        ("add-to-lea", add_to_lea),
+        ("jump-to-next", jump_to_next),
+    ]
+}
+
+pub fn optimize<R: Registers>(
+    instructions: Vec<Result<FullInstruction<R>, GeneratorErrors>>,
+) -> Vec<Result<FullInstruction<R>, GeneratorErrors>> {
+    match instructions.into_iter().collect::<Result<Vec<_>, _>>() {
+        Ok(instructions) => rewrite(instructions).into_iter().map(Ok).collect(),
+        Err(err) => vec![Err(err)],
+    }
+}
+
+fn rewrite<R: Registers>(mut instructions: Vec<FullInstruction<R>>) -> Vec<FullInstruction<R>> {
+    let rules = rules::<R>();
+    let mut i = 0;
+    while i < instructions.len() {
+        match rules.iter().find_map(|(_, rule)| rule(&instructions[i..])) {
+            Some((length, replacement)) => {
+                instructions.splice(i..i + length, replacement);
+                i = i.saturating_sub(1);
+            }
+            None => i += 1,
+        }
+    }
+    instructions
+}
+
+fn self_move<R: Registers>(window: &[FullInstruction<R>]) -> Option<Rewrite<R>> {
+    match window.first()? {
+        (AssemblyInstruction::Move, parameters, _) if parameters[0] == parameters[1] => {
+            Some((1, Vec::new()))
+        }
+        _ => None,
+    }
+}
+
+fn add_to_lea<R: Registers>(window: &[FullInstruction<R>]) -> Option<Rewrite<R>> {
+    use AssemblyInstructionParameter::Register;
+
+    match window.get(..2)? {
+        [(AssemblyInstruction::Move, moved, _), (AssemblyInstruction::Add, added, comment)] => {
+            match (&moved[..], &added[..]) {
+                ([Register(destination), Register(a)], [Register(target), Register(b)])
+                    if destination == target && destination != b =>
+                {
+                    Some((
+                        2,
+                        vec![(
+                            AssemblyInstruction::Lea,
+                            vec![Register(*destination), Register(*a), Register(*b)],
+                            comment.clone(),
+                        )],
+                    ))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn jump_to_next<R: Registers>(window: &[FullInstruction<R>]) -> Option<Rewrite<R>> {
+    match window.get(..2)? {
+        [(AssemblyInstruction::Jmp | AssemblyInstruction::Je, target, _), (AssemblyInstruction::Label, label, _)]
+            if target == label =>
+        {
+            Some((1, Vec::new()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        assembly_flavour::{AssemblyInstruction, AssemblyInstructionParameter, FullInstruction},
+        registers::X86Registers,
+    };
+
+    use super::{rewrite, rules};
+
+    use AssemblyInstructionParameter::{Label, Memory, Register};
+
+    fn instruction(
+        instruction: AssemblyInstruction,
+        parameters: &[AssemblyInstructionParameter<X86Registers>],
+    ) -> FullInstruction<X86Registers> {
+        (instruction, parameters.to_vec(), "".into())
+    }
+
+    fn apply(name: &str, window: &[FullInstruction<X86Registers>]) -> Option<usize> {
+        let (_, rule) = rules::<X86Registers>()
+            .into_iter()
+            .find(|(rule, _)| *rule == name)
+            .unwrap();
+        rule(window).map(|(length, _)| length)
+    }
+
+    #[test]
+    fn test_self_move() {
+        let moved = instruction(
+            AssemblyInstruction::Move,
+            &[Register(X86Registers::Rax), Register(X86Registers::Rax)],
+        );
+        assert_eq!(apply("self-move", &[moved]), Some(1));
+        assert_eq!(
+            apply(
+                "self-move",
+                &[instruction(
+                    AssemblyInstruction::Move,
+                    &[Memory(0), Register(X86Registers::Rax)],
+                )]
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_add_to_lea() {
+        let prepare = instruction(
+            AssemblyInstruction::Move,
+            &[Register(X86Registers::Rcx), Register(X86Registers::Rdi)],
+        );
+        let add = instruction(
+            AssemblyInstruction::Add,
+            &[Register(X86Registers::Rcx), Register(X86Registers::Rsi)],
+        );
+        assert_eq!(
+            rewrite(vec![prepare.clone(), add]),
+            [instruction(
+                AssemblyInstruction::Lea,
+                &[
+                    Register(X86Registers::Rcx),
+                    Register(X86Registers::Rdi),
+                    Register(X86Registers::Rsi),
+                ],
+            )]
+        );
+
+        let doubled = instruction(
+            AssemblyInstruction::Add,
+            &[Register(X86Registers::Rcx), Register(X86Registers::Rcx)],
+        );
+        assert_eq!(apply("add-to-lea", &[prepare.clone(), doubled]), None);
+        let from_memory = instruction(
+            AssemblyInstruction::Add,
+            &[Register(X86Registers::Rcx), Memory(1)],
+        );
+        assert_eq!(apply("add-to-lea", &[prepare, from_memory]), None);
+    }
+
+    #[test]
+    fn test_jump_to_next() {
+        let label = instruction(AssemblyInstruction::Label, &[Label("label_0".into())]);
+        let jump = instruction(AssemblyInstruction::Jmp, &[Label("label_0".into())]);
+        let other = instruction(AssemblyInstruction::Jmp, &[Label("label_1".into())]);
+
+        assert_eq!(
+            rewrite(vec![
+                other.clone(),
+                jump,
+                instruction(AssemblyInstruction::Je, &[Label("label_0".into())]),
+                label.clone(),
+            ]),
+            [other.clone(), label.clone()]
+        );
+        assert_eq!(apply("jump-to-next", &[other, label]), None);
+    }
+}
@@ -1,8 +1,7 @@
-pub trait Registers:
-    Copy + Clone + PartialEq + Eq + PartialOrd + Ord + std::fmt::Display + std::fmt::Debug
-{
+pub trait Registers: Copy + Clone + PartialEq + Eq + std::fmt::Display + std::fmt::Debug {
     const COUNT: usize;
     fn all() -> Box<[Self]>;
+    fn lower_byte(&self) -> Box<str>;
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -45,6 +44,20 @@ impl Registers for X86Registers {
             X86Registers::R15,
         ])
     }
+
+    fn lower_byte(&self) -> Box<str> {
+        match self {
+            X86Registers::Rax => "al".into(),
+            X86Registers::Rbx => "bl".into(),
+            X86Registers::Rcx => "cl".into(),
+            X86Registers::Rdx => "dl".into(),
+            X86Registers::Rsi => "sil".into(),
+            X86Registers::Rdi => "dil".into(),
+            X86Registers::Rbp => "bpl".into(),
+            X86Registers::Rsp => "spl".into(),
+            register => format!("{}b", register).into(),
+        }
+    }
 }
 
 impl std::fmt::Display for X86Registers {
@@ -74,87 +87,8 @@ impl std::fmt::Display for X86Registers {
     }
 }
 
-impl PartialOrd for X86Registers {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for X86Registers {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        use std::cmp::Ordering;
-        match (self, other) {
-            (X86Registers::Rax, X86Registers::Rax) => Ordering::Equal,
-            (X86Registers::Rax, _) => Ordering::Greater,
-            (_, X86Registers::Rax) => Ordering::Less,
-
-            (X86Registers::Rbx, X86Registers::Rbx) => Ordering::Equal,
-            (X86Registers::Rbx, _) => Ordering::Greater,
-            (_, X86Registers::Rbx) => Ordering::Less,
-
-            (X86Registers::Rcx, X86Registers::Rcx) => Ordering::Equal,
-            (X86Registers::Rcx, _) => Ordering::Greater,
-            (_, X86Registers::Rcx) => Ordering::Less,
-
-            (X86Registers::Rdx, X86Registers::Rdx) => Ordering::Equal,
-            (X86Registers::Rdx, _) => Ordering::Greater,
-            (_, X86Registers::Rdx) => Ordering::Less,
-
-            (X86Registers::Rsi, X86Registers::Rsi) => Ordering::Equal,
-            (X86Registers::Rsi, _) => Ordering::Greater,
-            (_, X86Registers::Rsi) => Ordering::Less,
-
-            (X86Registers::Rdi, X86Registers::Rdi) => Ordering::Equal,
-            (X86Registers::Rdi, _) => Ordering::Greater,
-            (_, X86Registers::Rdi) => Ordering::Less,
-
-            (X86Registers::Rbp, X86Registers::Rbp) => Ordering::Equal,
-            (X86Registers::Rbp, _) => Ordering::Greater,
-            (_, X86Registers::Rbp) => Ordering::Less,
-
-            (X86Registers::Rsp, X86Registers::Rsp) => Ordering::Equal,
-            (X86Registers::Rsp, _) => Ordering::Greater,
-            (_, X86Registers::Rsp) => Ordering::Less,
-
-            (X86Registers::R8, X86Registers::R8) => Ordering::Equal,
-            (X86Registers::R8, _) => Ordering::Greater,
-            (_, X86Registers::R8) => Ordering::Less,
-
-            (X86Registers::R9, X86Registers::R9) => Ordering::Equal,
-            (X86Registers::R9, _) => Ordering::Greater,
-            (_, X86Registers::R9) => Ordering::Less,
-
-            (X86Registers::R10, X86Registers::R10) => Ordering::Equal,
-            (X86Registers::R10, _) => Ordering::Greater,
-            (_, X86Registers::R10) => Ordering::Less,
-
-            (X86Registers::R11, X86Registers::R11) => Ordering::Equal,
-            (X86Registers::R11, _) => Ordering::Greater,
-            (_, X86Registers::R11) => Ordering::Less,
-
-            (X86Registers::R12, X86Registers::R12) => Ordering::Equal,
-            (X86Registers::R12, _) => Ordering::Greater,
-            (_, X86Registers::R12) => Ordering::Less,
-
-            (X86Registers::R13, X86Registers::R13) => Ordering::Equal,
-            (X86Registers::R13, _) => Ordering::Greater,
-            (_, X86Registers::R13) => Ordering::Less,
-
-            (X86Registers::R14, X86Registers::R14) => Ordering::Equal,
-            (X86Registers::R14, _) => Ordering::Greater,
-            (_, X86Registers::R14) => Ordering::Less,
-
-            (X86Registers::R15, X86Registers::R15) => Ordering::Equal,
-            // (X86Registers::R15, _) => Ordering::Greater,
-            // (_, X86Registers::R15) => Ordering::Less,
-        }
-    }
-}
-
 #[cfg(test)]
 pub mod tests {
-    use std::collections::BinaryHeap;
-
     use super::Registers;
 
     #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -162,20 +96,6 @@ pub mod tests {
         R(usize),
     }
 
-    impl PartialOrd for TestRegisters {
-        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-            Some(self.cmp(other))
-        }
-    }
-
-    impl Ord for TestRegisters {
-        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-            match (self, other) {
-                (TestRegisters::R(a), TestRegisters::R(b)) => a.cmp(b).reverse(),
-            }
-        }
-    }
-
     impl Registers for TestRegisters {
         const COUNT: usize = 4;
         fn all() -> Box<[Self]> {
@@ -185,6 +105,10 @@ pub mod tests {
                 TestRegisters::R(2),
             ])
         }
+
+        fn lower_byte(&self) -> Box<str> {
+            format!("{}b", self).into()
+        }
     }
 
     impl std::fmt::Display for TestRegisters {
@@ -198,21 +122,4 @@ pub mod tests {
             )
         }
     }
-
-    #[test]
-    fn test_registers_ord() {
-        assert!(TestRegisters::R(0) > TestRegisters::R(1));
-        assert!(TestRegisters::R(1) > TestRegisters::R(2));
-
-        let mut registers = BinaryHeap::from([
-            TestRegisters::R(1),
-            TestRegisters::R(0),
-            TestRegisters::R(2),
-        ]);
-
-        assert_eq!(registers.pop(), Some(TestRegisters::R(0)));
-        assert_eq!(registers.pop(), Some(TestRegisters::R(1)));
-        assert_eq!(registers.pop(), Some(TestRegisters::R(2)));
-        assert_eq!(registers.pop(), None);
-    }
 }
@@ -0,0 +1,50 @@
+use std::fs::{read_dir, read_to_string, write};
+
+use nilang_generator::options::{AtAndTFlavour, SystemVAmd64Abi, X86Registers};
+use nilang_types::instructions::parse_instructions;
+
+#[test]
+fn test_generate_from_ir_files() {
+    let directory = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/ir");
+    let mut files = read_dir(directory)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ir"))
+        .collect::<Vec<_>>();
+    files.sort();
+    assert!(!files.is_empty());
+
+    for file in files {
+        let code = read_to_string(&file).unwrap();
+        let instructions = parse_instructions(&code).unwrap();
+
+        let printed = instructions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parse_instructions(&printed.join("\n")).unwrap(),
+            instructions
+        );
+
+        let name = file.file_stem().unwrap().to_str().unwrap();
+        let generated = nilang_generator::generate_function::<
+            X86Registers,
+            SystemVAmd64Abi,
+            AtAndTFlavour,
+        >(name.into(), &[], instructions.into_iter())
+        .collect::<Result<String, _>>()
+        .unwrap();
+
+        let expected = file.with_extension("s");
+        if std::env::var_os("UPDATE_EXPECT").is_some() {
+            write(&expected, &generated).unwrap();
+        }
+        assert_eq!(
+            generated,
+            read_to_string(&expected).unwrap(),
+            "{}",
+            file.display()
+        );
+    }
+}
@@ -0,0 +1,21 @@
+.globl branch
+branch:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    movq $1, %rax                 # Load boolean 'true' into `flag`
+    testq %rax, %rax              # Test if `flag` is `0`
+    je .branch__label_0           # Jump to label `branch__label_0` if `flag` test passed
+    movq $1, %rax                 # Load number '1' into `temp_0`
+    movq %rbp, %rsp               # Return `temp_0`
+    pop %rbp
+    ret
+    .branch__label_0:             # Create label `branch__label_0`
+    movq $0, %rax                 # Load number '0' into `temp_1`
+    movq %rbp, %rsp               # Return `temp_1`
+    pop %rbp
+    ret
+    # Epilogue
+    movq %rbp, %rsp
+    pop %rbp
+    ret
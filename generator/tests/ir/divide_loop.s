@@ -0,0 +1,27 @@
+.globl divide_loop
+divide_loop:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $8, %rsp
+    pushq %rbx
+    movq $96, %rax                # Load number '96' into `v`
+    movq $1, %rbx                 # Load boolean 'true' into `go`
+    .divide_loop__loop:           # Create label `divide_loop__loop`
+    testq %rbx, %rbx              # Test if `go` is `0`
+    je .divide_loop__end          # Jump to label `divide_loop__end` if `go` test passed
+    movq $4, %rcx                 # Load number '4' into `four`
+    cqto                          # Sign extend `v` into rdx
+    idivq %rcx                    # Divide `v` by `four`
+    movq $0, %rbx                 # Load boolean 'false' into `go`
+    jmp .divide_loop__loop        # Jump to label `divide_loop__loop`
+    .divide_loop__end:            # Create label `divide_loop__end`
+    popq %rbx                     # Return `v`
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
@@ -0,0 +1,79 @@
+.globl spill
+spill:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $72, %rsp
+    pushq %rbx
+    pushq %r12
+    pushq %r13
+    pushq %r14
+    pushq %r15
+    movq $0, %rax                 # Load number '0' into `n0`
+    movq $1, %rbx                 # Load number '1' into `n1`
+    movq $2, %r10                 # Load number '2' into `n2`
+    movq $3, %r11                 # Load number '3' into `n3`
+    movq $4, %r12                 # Load number '4' into `n4`
+    movq $5, %r13                 # Load number '5' into `n5`
+    movq $6, %r14                 # Load number '6' into `n6`
+    movq $7, %r15                 # Load number '7' into `n7`
+    movq $8, -8(%rbp)             # Load number '8' into `n8`
+    movq $9, -16(%rbp)            # Load number '9' into `n9`
+    movq $10, -24(%rbp)           # Load number '10' into `n10`
+    movq $11, -32(%rbp)           # Load number '11' into `n11`
+    movq $12, -40(%rbp)           # Load number '12' into `n12`
+    movq $13, -48(%rbp)           # Load number '13' into `n13`
+    movq $14, -56(%rbp)           # Load number '14' into `n14`
+    movq $15, -64(%rbp)           # Load number '15' into `n15`
+    movq %rdi, -72(%rbp)          # Prepare `s0` for addition
+    addq %rsi, -72(%rbp)          # Add `a0` and `a1` into `s0`
+    movq -72(%rbp), %rsi          # Prepare `s1` for addition
+    addq %rdx, %rsi               # Add `s0` and `a2` into `s1`
+    leaq (%rsi,%rcx), %rdx        # Add `s1` and `a3` into `s2`
+    leaq (%rdx,%r8), %rcx         # Add `s2` and `a4` into `s3`
+    leaq (%rcx,%r9), %rdx         # Add `s3` and `a5` into `s4`
+    movq %rdx, %rcx               # Prepare `s5` for addition
+    addq 16(%rbp), %rcx           # Add `s4` and `a6` into `s5`
+    movq %rcx, %rdx               # Prepare `s6` for addition
+    addq 24(%rbp), %rdx           # Add `s5` and `a7` into `s6`
+    leaq (%rdx,%rax), %rcx        # Add `s6` and `n0` into `s7`
+    leaq (%rcx,%rbx), %rax        # Add `s7` and `n1` into `s8`
+    leaq (%rax,%r10), %rbx        # Add `s8` and `n2` into `s9`
+    leaq (%rbx,%r11), %rax        # Add `s9` and `n3` into `s10`
+    leaq (%rax,%r12), %rbx        # Add `s10` and `n4` into `s11`
+    leaq (%rbx,%r13), %rax        # Add `s11` and `n5` into `s12`
+    leaq (%rax,%r14), %rbx        # Add `s12` and `n6` into `s13`
+    leaq (%rbx,%r15), %rax        # Add `s13` and `n7` into `s14`
+    movq %rax, %rbx               # Prepare `s15` for addition
+    addq -8(%rbp), %rbx           # Add `s14` and `n8` into `s15`
+    movq %rbx, %rax               # Prepare `s16` for addition
+    addq -16(%rbp), %rax          # Add `s15` and `n9` into `s16`
+    movq %rax, %rbx               # Prepare `s17` for addition
+    addq -24(%rbp), %rbx          # Add `s16` and `n10` into `s17`
+    movq %rbx, %rax               # Prepare `s18` for addition
+    addq -32(%rbp), %rax          # Add `s17` and `n11` into `s18`
+    movq %rax, %rbx               # Prepare `s19` for addition
+    addq -40(%rbp), %rbx          # Add `s18` and `n12` into `s19`
+    movq %rbx, %rax               # Prepare `s20` for addition
+    addq -48(%rbp), %rax          # Add `s19` and `n13` into `s20`
+    movq %rax, %rbx               # Prepare `s21` for addition
+    addq -56(%rbp), %rbx          # Add `s20` and `n14` into `s21`
+    movq %rbx, %rax               # Prepare `s22` for addition
+    addq -64(%rbp), %rax          # Add `s21` and `n15` into `s22`
+    popq %r15                     # Return `s22`
+    popq %r14
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %r15
+    popq %r14
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
@@ -0,0 +1,14 @@
+.globl square
+square:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    movq %rdi, %rax               # Prepare `temp_0` for multiplication
+    imulq %rdi, %rax              # Multiply `x` and `x` into `temp_0`
+    movq %rbp, %rsp               # Return `temp_0`
+    pop %rbp
+    ret
+    # Epilogue
+    movq %rbp, %rsp
+    pop %rbp
+    ret
@@ -0,0 +1,386 @@
+use std::{collections::HashMap, io::Write};
+
+use errors::InterpreterErrors;
+use nilang_types::instructions::{DataValue, Instruction, Width};
+
+const WORD: i64 = 8;
+
+pub struct IrInterpreter<W: Write> {
+    functions: HashMap<Box<str>, Vec<Instruction>>,
+    symbols: HashMap<Box<str>, i64>,
+    code: HashMap<i64, Box<str>>,
+    strings: HashMap<i64, Box<str>>,
+    globals: HashMap<Box<str>, i64>,
+    memory: HashMap<i64, i64>,
+    next_address: i64,
+    output: W,
+}
+
+impl<W: Write> IrInterpreter<W> {
+    pub fn new(output: W) -> Self {
+        Self {
+            functions: HashMap::new(),
+            symbols: HashMap::new(),
+            code: HashMap::new(),
+            strings: HashMap::new(),
+            globals: HashMap::new(),
+            memory: HashMap::new(),
+            next_address: 0x1000,
+            output,
+        }
+    }
+
+    pub fn declare_function(&mut self, name: &str, instructions: Vec<Instruction>) {
+        let address = self.symbol(name);
+        self.code.insert(address, name.into());
+        self.functions.insert(name.into(), instructions);
+    }
+
+    pub fn declare_data(&mut self, data: &[(Box<str>, DataValue)]) {
+        for (name, value) in data {
+            match value {
+                DataValue::String(text) => {
+                    let address = self.symbol(name);
+                    self.strings.insert(address, text.clone());
+                }
+                DataValue::Quad(number) => {
+                    self.globals.insert(name.clone(), *number);
+                }
+                DataValue::Byte(byte) => {
+                    self.globals.insert(name.clone(), *byte as i64);
+                }
+            }
+        }
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    pub fn call(
+        &mut self,
+        name: &str,
+        arguments: &[i64],
+    ) -> Result<Option<i64>, InterpreterErrors> {
+        if let Some(result) = self.call_builtin(name, arguments)? {
+            return Ok(result);
+        }
+
+        let Some(instructions) = self.functions.get(name).cloned() else {
+            return Err(InterpreterErrors::FunctionNotFound { name: name.into() });
+        };
+
+        let labels = instructions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, instruction)| match instruction {
+                Instruction::Label(label) => Some((label.clone(), i)),
+                _ => None,
+            })
+            .collect::<HashMap<_, _>>();
+        let jump = |label: &str| {
+            labels
+                .get(label)
+                .copied()
+                .ok_or_else(|| InterpreterErrors::LabelNotFound { name: label.into() })
+        };
+
+        let mut temporaries = HashMap::<Box<str>, i64>::new();
+        let mut pc = 0;
+        while let Some(instruction) = instructions.get(pc) {
+            pc += 1;
+
+            let read = |temporary: &str| {
+                temporaries.get(temporary).copied().ok_or_else(|| {
+                    InterpreterErrors::VariableNotFound {
+                        name: temporary.into(),
+                    }
+                })
+            };
+
+            let (result, value) = match instruction {
+                Instruction::Label(_) => continue,
+                Instruction::Jump(label) => {
+                    pc = jump(label)?;
+                    continue;
+                }
+                Instruction::ConditionalJump(check, label) => {
+                    if read(check)? == 0 {
+                        pc = jump(label)?;
+                    }
+                    continue;
+                }
+                Instruction::Declare(temporary) => {
+                    temporaries.entry(temporary.clone()).or_insert(0);
+                    continue;
+                }
+                Instruction::FunctionCall(function, arguments, result) => {
+                    let arguments = arguments
+                        .iter()
+                        .map(|argument| read(argument))
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let returned = self.call(function, &arguments)?;
+                    match result {
+                        Some(result) => (result, returned.unwrap_or_default()),
+                        None => continue,
+                    }
+                }
+                Instruction::IndirectFunctionCall(environment, arguments, result) => {
+                    let environment = read(environment)?;
+                    let arguments = arguments
+                        .iter()
+                        .map(|argument| read(argument))
+                        .chain([Ok(environment)])
+                        .collect::<Result<Vec<_>, _>>()?;
+                    let function = self.function_at(self.load(environment)?)?;
+                    let returned = self.call(&function, &arguments)?;
+                    match result {
+                        Some(result) => (result, returned.unwrap_or_default()),
+                        None => continue,
+                    }
+                }
+                Instruction::TakeArgument(n, temporary) => (
+                    temporary,
+                    arguments.get(*n).copied().ok_or_else(|| {
+                        InterpreterErrors::VariableNotFound {
+                            name: temporary.clone(),
+                        }
+                    })?,
+                ),
+                Instruction::ReturnVariable(temporary) => return read(temporary).map(Some),
+                Instruction::LoadBoolean(temporary, boolean) => (temporary, *boolean as i64),
+                Instruction::LoadNumber(temporary, number) => (temporary, *number as i64),
+                Instruction::LoadChar(temporary, char) => (temporary, *char as i64),
+                Instruction::LoadStringLocation(temporary, string) => {
+                    match self.symbols.get(string) {
+                        Some(address) => (temporary, *address),
+                        None => {
+                            return Err(InterpreterErrors::VariableNotFound {
+                                name: string.clone(),
+                            })
+                        }
+                    }
+                }
+                Instruction::LoadFunctionLocation(temporary, function) => {
+                    (temporary, self.symbol(function))
+                }
+                Instruction::LoadGlobal(temporary, global, _) => match self.globals.get(global) {
+                    Some(value) => (temporary, *value),
+                    None => {
+                        return Err(InterpreterErrors::VariableNotFound {
+                            name: global.clone(),
+                        })
+                    }
+                },
+                Instruction::StoreGlobal(global, width, value) => {
+                    let value = match width {
+                        Width::Byte => read(value)? as u8 as i64,
+                        Width::Quad => read(value)?,
+                    };
+                    self.globals.insert(global.clone(), value);
+                    continue;
+                }
+                Instruction::LoadFromAddress(temporary, base, offset) => {
+                    (temporary, self.load(read(base)? + *offset as i64 * WORD)?)
+                }
+                Instruction::StoreToAddress(base, offset, value) => {
+                    self.store(read(base)? + *offset as i64 * WORD, read(value)?)?;
+                    continue;
+                }
+                Instruction::Copy(to, from) => (to, read(from)?),
+                Instruction::AddVariables(result, a, b) => {
+                    (result, read(a)?.wrapping_add(read(b)?))
+                }
+                Instruction::SubtractVariables(result, a, b) => {
+                    (result, read(a)?.wrapping_sub(read(b)?))
+                }
+                Instruction::MultiplyVariables(result, a, b) => {
+                    (result, read(a)?.wrapping_mul(read(b)?))
+                }
+                Instruction::DivideVariables(result, a, b) => match read(b)? {
+                    0 => return Err(InterpreterErrors::DivisionByZero),
+                    b => (result, read(a)?.wrapping_div(b)),
+                },
+                Instruction::ModuloVariables(result, a, b) => match read(b)? {
+                    0 => return Err(InterpreterErrors::DivisionByZero),
+                    b => (result, read(a)?.wrapping_rem(b)),
+                },
+                Instruction::TestEqual(result, a, b) => (result, (read(a)? == read(b)?) as i64),
+                Instruction::TestNotEqual(result, a, b) => (result, (read(a)? != read(b)?) as i64),
+                Instruction::TestLess(result, a, b) => (result, (read(a)? < read(b)?) as i64),
+                Instruction::TestMore(result, a, b) => (result, (read(a)? > read(b)?) as i64),
+                Instruction::TestLessOrEqual(result, a, b) => {
+                    (result, (read(a)? <= read(b)?) as i64)
+                }
+                Instruction::TestMoreOrEqual(result, a, b) => {
+                    (result, (read(a)? >= read(b)?) as i64)
+                }
+            };
+
+            temporaries.insert(result.clone(), value);
+        }
+
+        Ok(None)
+    }
+
+    fn call_builtin(
+        &mut self,
+        name: &str,
+        arguments: &[i64],
+    ) -> Result<Option<Option<i64>>, InterpreterErrors> {
+        let [argument] = arguments else {
+            return Ok(None);
+        };
+
+        let printed = match name {
+            "printb" | "printi" => writeln!(self.output, "{}", argument),
+            "printc" => writeln!(
+                self.output,
+                "{}",
+                char::from_u32(*argument as u32).unwrap_or_default()
+            ),
+            "print" => match self.strings.get(argument) {
+                Some(text) => writeln!(self.output, "{}", text),
+                None => return Err(InterpreterErrors::InvalidAddress { address: *argument }),
+            },
+            "malloc" => {
+                let address = self.next_address;
+                self.next_address += (*argument + WORD - 1) / WORD * WORD;
+                for offset in (0..*argument).step_by(WORD as usize) {
+                    self.memory.insert(address + offset, 0);
+                }
+                return Ok(Some(Some(address)));
+            }
+            _ => return Ok(None),
+        };
+
+        printed.map_err(|err| InterpreterErrors::Output {
+            message: err.to_string().into(),
+        })?;
+        Ok(Some(None))
+    }
+
+    fn symbol(&mut self, name: &str) -> i64 {
+        if let Some(address) = self.symbols.get(name) {
+            return *address;
+        }
+
+        let address = self.next_address;
+        self.next_address += WORD;
+        self.symbols.insert(name.into(), address);
+        address
+    }
+
+    fn function_at(&self, address: i64) -> Result<Box<str>, InterpreterErrors> {
+        self.code
+            .get(&address)
+            .cloned()
+            .ok_or(InterpreterErrors::InvalidAddress { address })
+    }
+
+    fn load(&self, address: i64) -> Result<i64, InterpreterErrors> {
+        self.memory
+            .get(&address)
+            .copied()
+            .ok_or(InterpreterErrors::InvalidAddress { address })
+    }
+
+    fn store(&mut self, address: i64, value: i64) -> Result<(), InterpreterErrors> {
+        match self.memory.get_mut(&address) {
+            Some(word) => {
+                *word = value;
+                Ok(())
+            }
+            None => Err(InterpreterErrors::InvalidAddress { address }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::{DataValue, Instruction, Width};
+
+    use super::IrInterpreter;
+
+    #[test]
+    fn test_interpret_loop() {
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        interpreter.declare_function(
+            "sum",
+            vec![
+                Instruction::TakeArgument(0, "n".into()),
+                Instruction::Declare("total".into()),
+                Instruction::LoadNumber("total".into(), 0.),
+                Instruction::Declare("one".into()),
+                Instruction::LoadNumber("one".into(), 1.),
+                Instruction::Label("loop".into()),
+                Instruction::Declare("done".into()),
+                Instruction::Declare("zero".into()),
+                Instruction::LoadNumber("zero".into(), 0.),
+                Instruction::TestNotEqual("done".into(), "n".into(), "zero".into()),
+                Instruction::ConditionalJump("done".into(), "end".into()),
+                Instruction::AddVariables("total".into(), "total".into(), "n".into()),
+                Instruction::SubtractVariables("n".into(), "n".into(), "one".into()),
+                Instruction::Jump("loop".into()),
+                Instruction::Label("end".into()),
+                Instruction::ReturnVariable("total".into()),
+            ],
+        );
+
+        assert_eq!(interpreter.call("sum", &[4]).unwrap(), Some(10));
+    }
+
+    #[test]
+    fn test_interpret_closure_and_data() {
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        interpreter.declare_data(&[
+            ("string__a".into(), DataValue::String("hello".into())),
+            ("global__counter".into(), DataValue::Quad(2)),
+        ]);
+        interpreter.declare_function(
+            "main__closure_0",
+            vec![
+                Instruction::TakeArgument(0, "x".into()),
+                Instruction::TakeArgument(1, "closure__env".into()),
+                Instruction::Declare("offset".into()),
+                Instruction::LoadFromAddress("offset".into(), "closure__env".into(), 1),
+                Instruction::Declare("result".into()),
+                Instruction::AddVariables("result".into(), "x".into(), "offset".into()),
+                Instruction::ReturnVariable("result".into()),
+            ],
+        );
+        interpreter.declare_function(
+            "main",
+            vec![
+                Instruction::Declare("a".into()),
+                Instruction::LoadStringLocation("a".into(), "string__a".into()),
+                Instruction::FunctionCall("print".into(), ["a".into()].into(), None),
+                Instruction::Declare("size".into()),
+                Instruction::LoadNumber("size".into(), 16.),
+                Instruction::FunctionCall(
+                    "malloc".into(),
+                    ["size".into()].into(),
+                    Some("f".into()),
+                ),
+                Instruction::Declare("code".into()),
+                Instruction::LoadFunctionLocation("code".into(), "main__closure_0".into()),
+                Instruction::StoreToAddress("f".into(), 0, "code".into()),
+                Instruction::Declare("offset".into()),
+                Instruction::LoadGlobal("offset".into(), "global__counter".into(), Width::Quad),
+                Instruction::StoreToAddress("f".into(), 1, "offset".into()),
+                Instruction::Declare("x".into()),
+                Instruction::LoadNumber("x".into(), 5.),
+                Instruction::Declare("y".into()),
+                Instruction::IndirectFunctionCall(
+                    "f".into(),
+                    ["x".into()].into(),
+                    Some("y".into()),
+                ),
+                Instruction::FunctionCall("printi".into(), ["y".into()].into(), None),
+            ],
+        );
+
+        assert_eq!(interpreter.call("main", &[]).unwrap(), None);
+        assert_eq!(interpreter.output(), b"hello\n7\n");
+    }
+}
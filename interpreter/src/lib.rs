@@ -0,0 +1,797 @@
+mod ir;
+mod value;
+
+use std::{
+    collections::{HashMap, HashSet},
+    io::Write,
+    rc::Rc,
+};
+
+use errors::InterpreterErrors;
+use nilang_types::nodes::{
+    expressions::{
+        Arithmetic, Boolean, Conditional, ExpressionNode, FunctionCall, Operation, Operator,
+        Primitive,
+    },
+    statements::{FunctionDeclaration, GlobalDeclaration, StatementNode, StructureDeclaration},
+    Type,
+};
+
+pub use ir::IrInterpreter;
+pub use value::{Function, Scope, Value};
+
+pub struct Interpreter<W: Write> {
+    modules: HashMap<Box<str>, Module>,
+    structures: HashMap<Box<str>, StructureDeclaration>,
+    values: Scope,
+    initializing: HashSet<Box<str>>,
+    module: Box<str>,
+    output: W,
+}
+
+#[derive(Default)]
+struct Module {
+    functions: HashMap<Box<str>, Vec<(bool, Rc<Function>)>>,
+    globals: HashMap<Box<str>, GlobalDeclaration>,
+    imports: Vec<Box<str>>,
+}
+
+impl<W: Write> Interpreter<W> {
+    pub fn new(output: W) -> Self {
+        Self {
+            modules: HashMap::new(),
+            structures: HashMap::new(),
+            values: Scope::new(),
+            initializing: HashSet::new(),
+            module: "".into(),
+            output,
+        }
+    }
+
+    pub fn declare_module(&mut self, module: &str, imports: &[Box<str>]) {
+        self.modules.entry(module.into()).or_default().imports = imports.to_vec();
+    }
+
+    pub fn declare_function(&mut self, function: &FunctionDeclaration) {
+        self.declare_module_function("", function);
+    }
+
+    pub fn declare_module_function(&mut self, module: &str, declaration: &FunctionDeclaration) {
+        let function = Function {
+            module: module.into(),
+            ..declaration.into()
+        };
+        let overloads = self
+            .modules
+            .entry(module.into())
+            .or_default()
+            .functions
+            .entry(function.name.clone())
+            .or_default();
+        overloads.retain(|(_, overload)| overload.type_of() != function.type_of());
+        overloads.push((declaration.public, Rc::new(function)));
+    }
+
+    pub fn declare_structure(&mut self, structure: &StructureDeclaration) {
+        self.structures
+            .insert(structure.name.clone(), structure.clone());
+    }
+
+    pub fn declare_global(&mut self, global: &GlobalDeclaration) {
+        self.declare_module_global("", global);
+    }
+
+    pub fn declare_module_global(&mut self, module: &str, global: &GlobalDeclaration) {
+        self.values.remove(&global_symbol(module, &global.name));
+        self.modules
+            .entry(module.into())
+            .or_default()
+            .globals
+            .insert(global.name.clone(), global.clone());
+    }
+
+    pub fn output(&self) -> &W {
+        &self.output
+    }
+
+    pub fn run(&mut self) -> Result<i64, InterpreterErrors> {
+        match self.call("main", Vec::new())? {
+            Value::Int(code) => Ok(code),
+            value => Err(InterpreterErrors::TypeMismatch {
+                expected: Type::Int,
+                found: value.type_of(),
+            }),
+        }
+    }
+
+    pub fn call(&mut self, name: &str, arguments: Vec<Value>) -> Result<Value, InterpreterErrors> {
+        let types = arguments.iter().map(Value::type_of).collect::<Vec<_>>();
+
+        let overloads = self.functions(name);
+        let overload = overloads
+            .iter()
+            .find(|overload| {
+                overload.parameters.len() == types.len()
+                    && overload
+                        .parameters
+                        .iter()
+                        .zip(&types)
+                        .all(|((_, parameter), argument)| parameter == argument)
+            })
+            .cloned();
+
+        if let Some(function) = overload {
+            return self.call_function(&function, arguments);
+        }
+
+        if self.call_builtin(name, &arguments)? {
+            return Ok(Value::Void);
+        }
+
+        if !overloads.is_empty() || is_builtin(name) {
+            Err(InterpreterErrors::NoMatchingOverload {
+                name: name.into(),
+                arguments: types.into(),
+            })
+        } else {
+            Err(InterpreterErrors::FunctionNotFound { name: name.into() })
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        body: &[StatementNode],
+        scope: &mut Scope,
+    ) -> Result<Option<Value>, InterpreterErrors> {
+        for statement in body {
+            match statement {
+                StatementNode::VariableDeclaration {
+                    name,
+                    r#type,
+                    value,
+                    ..
+                } => {
+                    let value = self.evaluate(value, scope)?;
+                    expect_type(r#type, &value)?;
+                    scope.insert(name.clone(), value);
+                }
+                StatementNode::VariableAssignment { name, value, .. } => {
+                    let value = self.evaluate(value, scope)?;
+                    self.assign(name, value, scope)?;
+                }
+                StatementNode::Return { value, .. } => {
+                    return self.evaluate(value, scope).map(Some)
+                }
+                StatementNode::FunctionCall(call) => {
+                    self.evaluate_call(call, scope)?;
+                }
+                StatementNode::Conditional(conditional) => {
+                    if let Some(returned) = self.execute_conditional(conditional, scope)? {
+                        return Ok(Some(returned));
+                    }
+                }
+                StatementNode::WhileLoop {
+                    condition, body, ..
+                } => {
+                    while self.evaluate_condition(condition, scope)? {
+                        if let Some(returned) = self.execute(body, scope)? {
+                            return Ok(Some(returned));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub fn evaluate(
+        &mut self,
+        expression: &ExpressionNode,
+        scope: &Scope,
+    ) -> Result<Value, InterpreterErrors> {
+        match expression {
+            ExpressionNode::Primitive(primitive) => Ok(match primitive {
+                Primitive::Boolean(boolean) => Value::Bool(*boolean),
+                Primitive::Number(number) => Value::Int(*number as i64),
+                Primitive::Char(char) => Value::Char(*char),
+                Primitive::String(text) => Value::String(text.as_ref().into()),
+            }),
+            ExpressionNode::VariableReference(name) => self.lookup(name, scope),
+            ExpressionNode::FieldAccess { structure, field } => {
+                match self.evaluate(structure, scope)? {
+                    Value::Object(_, mut fields) => {
+                        fields
+                            .remove(field)
+                            .ok_or_else(|| InterpreterErrors::FieldNotFound {
+                                name: field.clone(),
+                            })
+                    }
+                    value => Err(InterpreterErrors::FieldNotFound {
+                        name: format!("{}.{}", value.type_of(), field).into(),
+                    }),
+                }
+            }
+            ExpressionNode::FunctionCall(call) => self.evaluate_call(call, scope),
+            ExpressionNode::Parenthesis(expression) => self.evaluate(expression, scope),
+            ExpressionNode::Operation(Operation { operator, a, b, .. }) => {
+                let a = self.evaluate(a, scope)?;
+                let b = self.evaluate(b, scope)?;
+                evaluate_operation(*operator, a, b)
+            }
+            ExpressionNode::Object { r#type, fields } => {
+                let Type::Object(name) = r#type else {
+                    return Err(InterpreterErrors::TypeNotFound {
+                        name: r#type.to_string().into(),
+                    });
+                };
+                let Some(structure) = self.structures.get(name) else {
+                    return Err(InterpreterErrors::TypeNotFound { name: name.clone() });
+                };
+                let declared = structure.fields.clone();
+
+                let mut values = HashMap::new();
+                for (field, value) in fields {
+                    let Some(field_type) = declared.get(field) else {
+                        return Err(InterpreterErrors::FieldNotFound {
+                            name: field.clone(),
+                        });
+                    };
+                    let value = self.evaluate(value, scope)?;
+                    expect_type(field_type, &value)?;
+                    values.insert(field.clone(), value);
+                }
+
+                if let Some(missing) = declared.keys().find(|field| !values.contains_key(*field)) {
+                    return Err(InterpreterErrors::FieldNotFound {
+                        name: missing.clone(),
+                    });
+                }
+
+                Ok(Value::Object(name.clone(), values))
+            }
+            ExpressionNode::Closure {
+                parameters,
+                return_type,
+                body,
+            } => Ok(Value::Function(Rc::new(Function {
+                name: "closure".into(),
+                module: self.module.clone(),
+                parameters: parameters.clone(),
+                return_type: return_type.clone(),
+                body: body.clone(),
+                captures: scope.clone(),
+            }))),
+        }
+    }
+
+    fn evaluate_call(
+        &mut self,
+        FunctionCall {
+            name, arguments, ..
+        }: &FunctionCall,
+        scope: &Scope,
+    ) -> Result<Value, InterpreterErrors> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.evaluate(argument, scope))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let callee = match scope.get(name) {
+            Some(value) => Some(value.clone()),
+            None if self.global_declaration(name).is_some() => Some(self.global(name)?),
+            None => None,
+        };
+
+        match callee {
+            Some(Value::Function(function)) => self.call_function(&function, arguments),
+            Some(value) => Err(InterpreterErrors::TypeMismatch {
+                expected: Type::Function {
+                    parameters: arguments.iter().map(Value::type_of).collect(),
+                    return_type: Box::new(Type::Void),
+                },
+                found: value.type_of(),
+            }),
+            None => self.call(name, arguments),
+        }
+    }
+
+    fn call_function(
+        &mut self,
+        function: &Function,
+        arguments: Vec<Value>,
+    ) -> Result<Value, InterpreterErrors> {
+        let caller = std::mem::replace(&mut self.module, function.module.clone());
+        let returned = self.execute_function(function, arguments);
+        self.module = caller;
+        returned
+    }
+
+    fn execute_function(
+        &mut self,
+        function: &Function,
+        arguments: Vec<Value>,
+    ) -> Result<Value, InterpreterErrors> {
+        if function.parameters.len() != arguments.len() {
+            return Err(InterpreterErrors::NoMatchingOverload {
+                name: function.name.clone(),
+                arguments: arguments.iter().map(Value::type_of).collect(),
+            });
+        }
+
+        let mut scope = function.captures.clone();
+        for ((parameter, r#type), argument) in function.parameters.iter().zip(arguments) {
+            expect_type(r#type, &argument)?;
+            scope.insert(parameter.clone(), argument);
+        }
+
+        match (
+            self.execute(&function.body, &mut scope)?,
+            &function.return_type,
+        ) {
+            (_, Type::Void) => Ok(Value::Void),
+            (Some(value), return_type) => {
+                expect_type(return_type, &value)?;
+                Ok(value)
+            }
+            (None, _) => Err(InterpreterErrors::MissingReturn {
+                name: function.name.clone(),
+            }),
+        }
+    }
+
+    fn call_builtin(&mut self, name: &str, arguments: &[Value]) -> Result<bool, InterpreterErrors> {
+        let printed = match (name, arguments) {
+            ("printb" | "print", [Value::Bool(boolean)]) => {
+                writeln!(self.output, "{}", *boolean as i64)
+            }
+            ("printi" | "print", [Value::Int(number)]) => writeln!(self.output, "{}", number),
+            ("printc" | "print", [Value::Char(char)]) => writeln!(self.output, "{}", char),
+            ("print", [Value::String(text)]) => writeln!(self.output, "{}", text),
+            ("print", [object @ Value::Object(..)]) => {
+                let mut fields = Vec::new();
+                flatten_fields("", object, &mut fields);
+                fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+                for (_, value) in fields {
+                    self.call("print", vec![value])?;
+                }
+                return Ok(true);
+            }
+            _ => return Ok(false),
+        };
+
+        printed.map_err(|err| InterpreterErrors::Output {
+            message: err.to_string().into(),
+        })?;
+        Ok(true)
+    }
+
+    fn execute_conditional(
+        &mut self,
+        Conditional {
+            condition,
+            body,
+            chained,
+            ..
+        }: &Conditional,
+        scope: &mut Scope,
+    ) -> Result<Option<Value>, InterpreterErrors> {
+        if self.evaluate_condition(condition, scope)? {
+            self.execute(body, scope)
+        } else if let Some(chained) = chained {
+            self.execute_conditional(chained, scope)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn evaluate_condition(
+        &mut self,
+        condition: &ExpressionNode,
+        scope: &Scope,
+    ) -> Result<bool, InterpreterErrors> {
+        match self.evaluate(condition, scope)? {
+            Value::Bool(condition) => Ok(condition),
+            value => Err(InterpreterErrors::TypeMismatch {
+                expected: Type::Bool,
+                found: value.type_of(),
+            }),
+        }
+    }
+
+    fn lookup(&mut self, name: &str, scope: &Scope) -> Result<Value, InterpreterErrors> {
+        if let Some(value) = scope.get(name) {
+            return Ok(value.clone());
+        }
+
+        if self.global_declaration(name).is_some() {
+            return self.global(name);
+        }
+
+        match self.functions(name).as_slice() {
+            [function] => Ok(Value::Function(function.clone())),
+            [] => Err(InterpreterErrors::VariableNotFound { name: name.into() }),
+            _ => Err(InterpreterErrors::AmbiguousFunctionReference { name: name.into() }),
+        }
+    }
+
+    fn functions(&self, name: &str) -> Vec<Rc<Function>> {
+        let Some(module) = self.modules.get(&self.module) else {
+            return Vec::new();
+        };
+
+        let imported = module
+            .imports
+            .iter()
+            .filter_map(|import| self.modules.get(import))
+            .flat_map(|imported| imported.functions.get(name))
+            .flatten()
+            .filter(|(public, _)| *public);
+
+        module
+            .functions
+            .get(name)
+            .into_iter()
+            .flatten()
+            .chain(imported)
+            .map(|(_, function)| function.clone())
+            .collect()
+    }
+
+    fn global_declaration(&self, name: &str) -> Option<(Box<str>, GlobalDeclaration)> {
+        let module = self.modules.get(&self.module)?;
+        if let Some(global) = module.globals.get(name) {
+            return Some((self.module.clone(), global.clone()));
+        }
+
+        module.imports.iter().find_map(|import| {
+            let global = self.modules.get(import)?.globals.get(name)?;
+            global.public.then(|| (import.clone(), global.clone()))
+        })
+    }
+
+    fn global(&mut self, name: &str) -> Result<Value, InterpreterErrors> {
+        let Some((module, GlobalDeclaration { r#type, value, .. })) = self.global_declaration(name)
+        else {
+            return Err(InterpreterErrors::VariableNotFound { name: name.into() });
+        };
+
+        let symbol = global_symbol(&module, name);
+        if let Some(value) = self.values.get(&symbol) {
+            return Ok(value.clone());
+        }
+
+        if !self.initializing.insert(symbol.clone()) {
+            return Err(InterpreterErrors::RecursiveGlobal { name: name.into() });
+        }
+        let caller = std::mem::replace(&mut self.module, module);
+        let value = self.evaluate(&value, &Scope::new());
+        self.module = caller;
+        self.initializing.remove(&symbol);
+
+        let value = value?;
+        expect_type(&r#type, &value)?;
+        self.values.insert(symbol, value.clone());
+        Ok(value)
+    }
+
+    fn assign(
+        &mut self,
+        name: &str,
+        value: Value,
+        scope: &mut Scope,
+    ) -> Result<(), InterpreterErrors> {
+        if let Some(variable) = scope.get_mut(name) {
+            expect_type(&variable.type_of(), &value)?;
+            *variable = value;
+            return Ok(());
+        }
+
+        let Some((
+            module,
+            GlobalDeclaration {
+                constant, r#type, ..
+            },
+        )) = self.global_declaration(name)
+        else {
+            return Err(InterpreterErrors::VariableNotFound { name: name.into() });
+        };
+
+        if constant {
+            return Err(InterpreterErrors::AssignmentToConstant { name: name.into() });
+        }
+
+        expect_type(&r#type, &value)?;
+        self.values.insert(global_symbol(&module, name), value);
+        Ok(())
+    }
+}
+
+fn evaluate_operation(operator: Operator, a: Value, b: Value) -> Result<Value, InterpreterErrors> {
+    match (operator, a, b) {
+        (Operator::Arithmetic(arithmetic), Value::Int(a), Value::Int(b)) => {
+            Ok(Value::Int(match arithmetic {
+                Arithmetic::Add => a.wrapping_add(b),
+                Arithmetic::Subtract => a.wrapping_sub(b),
+                Arithmetic::Multiply => a.wrapping_mul(b),
+                Arithmetic::Divide | Arithmetic::Modulo if b == 0 => {
+                    return Err(InterpreterErrors::DivisionByZero)
+                }
+                Arithmetic::Divide => a.wrapping_div(b),
+                Arithmetic::Modulo => a.wrapping_rem(b),
+            }))
+        }
+        (Operator::Arithmetic(_), a, b) => Err(InterpreterErrors::TypeMismatch {
+            expected: Type::Int,
+            found: if let Value::Int(_) = a {
+                b.type_of()
+            } else {
+                a.type_of()
+            },
+        }),
+        (Operator::Boolean(boolean), a, b) => {
+            if a.type_of() != b.type_of() {
+                return Err(InterpreterErrors::TypeMismatch {
+                    expected: a.type_of(),
+                    found: b.type_of(),
+                });
+            }
+
+            let ordering = match (&a, &b) {
+                (Value::Int(a), Value::Int(b)) => a.partial_cmp(b),
+                (Value::Char(a), Value::Char(b)) => a.partial_cmp(b),
+                (Value::Bool(a), Value::Bool(b)) => a.partial_cmp(b),
+                _ => None,
+            };
+
+            Ok(Value::Bool(match (boolean, ordering) {
+                (Boolean::Equal, _) => a == b,
+                (Boolean::NotEqual, _) => a != b,
+                (Boolean::Less, Some(ordering)) => ordering.is_lt(),
+                (Boolean::More, Some(ordering)) => ordering.is_gt(),
+                (Boolean::LessOrEqual, Some(ordering)) => ordering.is_le(),
+                (Boolean::MoreOrEqual, Some(ordering)) => ordering.is_ge(),
+                (_, None) => {
+                    return Err(InterpreterErrors::TypeMismatch {
+                        expected: Type::Int,
+                        found: a.type_of(),
+                    })
+                }
+            }))
+        }
+    }
+}
+
+fn expect_type(expected: &Type, value: &Value) -> Result<(), InterpreterErrors> {
+    let found = value.type_of();
+    if *expected != found {
+        return Err(InterpreterErrors::TypeMismatch {
+            expected: expected.clone(),
+            found,
+        });
+    }
+    Ok(())
+}
+
+fn global_symbol(module: &str, name: &str) -> Box<str> {
+    if module.is_empty() {
+        name.into()
+    } else {
+        format!("{}__global__{}", module, name).into()
+    }
+}
+
+fn flatten_fields(path: &str, value: &Value, fields: &mut Vec<(Box<str>, Value)>) {
+    match value {
+        Value::Object(_, values) => {
+            for (field, value) in values {
+                let path = if path.is_empty() {
+                    field.to_string()
+                } else {
+                    format!("{}.{}", path, field)
+                };
+                flatten_fields(&path, value, fields);
+            }
+        }
+        value => fields.push((path.into(), value.clone())),
+    }
+}
+
+fn is_builtin(name: &str) -> bool {
+    matches!(name, "print" | "printb" | "printi" | "printc")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use errors::InterpreterErrors;
+
+    use crate::Interpreter;
+
+    fn interpret(code: &str) -> Result<String, InterpreterErrors> {
+        let (functions, structures, _, globals) =
+            nilang_parser::parse(nilang_lexer::lex(code)).unwrap();
+
+        let mut interpreter = Interpreter::new(Vec::new());
+        functions
+            .iter()
+            .for_each(|function| interpreter.declare_function(function));
+        structures
+            .iter()
+            .for_each(|structure| interpreter.declare_structure(structure));
+        globals
+            .iter()
+            .for_each(|global| interpreter.declare_global(global));
+
+        interpreter.run()?;
+        Ok(String::from_utf8(interpreter.output().clone()).unwrap())
+    }
+
+    #[test]
+    fn test_interpret_program() {
+        assert_eq!(
+            interpret(
+                r#"
+                st Point { x: int, y: int }
+
+                cn SCALE: int = 3;
+                vr calls: int = 0;
+
+                fn area(p: Point): int {
+                    calls = calls + 1;
+                    rt p.x * p.y * SCALE;
+                }
+
+                fn area(side: int): int {
+                    rt side * side;
+                }
+
+                fn apply(f: fn(int): int, v: int): int {
+                    rt f(v);
+                }
+
+                fn main(): int {
+                    vr i: int = 0;
+                    vr total: int = 0;
+                    wl (i < 4) {
+                        total = total + i;
+                        i = i + 1;
+                    }
+                    print(total);
+
+                    vr offset: int = 10;
+                    vr add: fn(int): int = fn(x: int): int {
+                        rt x + offset;
+                    };
+                    print(apply(add, 5));
+
+                    print(area(Point { x: 2, y: 5 }));
+                    print(area(4));
+                    print(calls);
+
+                    if (total == 6) {
+                        print('y');
+                    } el {
+                        print('n');
+                    }
+                    print("done");
+                    rt 0;
+                }
+                "#
+            )
+            .unwrap(),
+            "6\n15\n30\n16\n1\ny\ndone\n"
+        );
+    }
+
+    #[test]
+    fn test_interpret_print_structure() {
+        assert_eq!(
+            interpret(
+                r#"
+                st Point { x: int, y: int }
+                st Label { text: char, anchor: Point }
+
+                fn main(): int {
+                    print(Label { text: 'a', anchor: Point { x: 1, y: 2 } });
+                    rt 0;
+                }
+                "#
+            )
+            .unwrap(),
+            "1\n2\na\n"
+        );
+    }
+
+    #[test]
+    fn test_interpret_output_errors() {
+        struct Closed;
+
+        impl Write for Closed {
+            fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+                Err(std::io::ErrorKind::BrokenPipe.into())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let (functions, ..) =
+            nilang_parser::parse(nilang_lexer::lex("fn main(): int { print(1); rt 0; }")).unwrap();
+        let mut interpreter = Interpreter::new(Closed);
+        interpreter.declare_function(&functions[0]);
+
+        assert!(matches!(
+            interpreter.run(),
+            Err(InterpreterErrors::Output { .. })
+        ));
+    }
+
+    #[test]
+    fn test_interpret_errors() {
+        assert!(matches!(
+            interpret("fn main(): int { rt 1 / 0; }"),
+            Err(InterpreterErrors::DivisionByZero)
+        ));
+        assert!(matches!(
+            interpret("fn main(): int { print(missing); rt 0; }"),
+            Err(InterpreterErrors::VariableNotFound { .. })
+        ));
+        assert!(matches!(
+            interpret("fn f(): int { } fn main(): int { rt f(); }"),
+            Err(InterpreterErrors::MissingReturn { .. })
+        ));
+    }
+
+    #[test]
+    fn test_interpret_modules() {
+        let parse = |code| nilang_parser::parse(nilang_lexer::lex(code)).unwrap();
+        let (math, _, _, math_globals) = parse(
+            r#"
+            vr BASE: int = 3;
+            pb cn OFFSET: int = 10;
+            fn helper(x: int): int { rt x * BASE; }
+            pb fn triple(x: int): int { rt helper(x); }
+            "#,
+        );
+        let (root, _, _, _) = parse(
+            r#"
+            fn helper(x: int): int { rt x + 1; }
+            fn main(): int {
+                print(helper(4));
+                print(triple(4));
+                print(OFFSET);
+                rt 0;
+            }
+            "#,
+        );
+
+        let mut interpreter = Interpreter::new(Vec::new());
+        interpreter.declare_module("", &["math".into()]);
+        interpreter.declare_module("math", &[]);
+        for function in &math {
+            interpreter.declare_module_function("math", function);
+        }
+        for global in &math_globals {
+            interpreter.declare_module_global("math", global);
+        }
+        for function in &root {
+            interpreter.declare_function(function);
+        }
+
+        interpreter.run().unwrap();
+        assert_eq!(
+            String::from_utf8(interpreter.output().clone()).unwrap(),
+            "5\n12\n10\n"
+        );
+
+        let (private, _, _, _) = parse("fn main(): int { rt BASE; }");
+        interpreter.declare_function(&private[0]);
+        assert!(matches!(
+            interpreter.run(),
+            Err(InterpreterErrors::VariableNotFound { .. })
+        ));
+    }
+}
@@ -0,0 +1,104 @@
+use std::{collections::HashMap, fmt::Display, rc::Rc};
+
+use nilang_types::nodes::{
+    statements::{FunctionDeclaration, Parameter, StatementNode},
+    Type,
+};
+
+pub type Scope = HashMap<Box<str>, Value>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Void,
+    Bool(bool),
+    Int(i64),
+    Char(char),
+    String(Rc<str>),
+    Object(Box<str>, HashMap<Box<str>, Value>),
+    Function(Rc<Function>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub name: Box<str>,
+    pub module: Box<str>,
+    pub parameters: Box<[Parameter]>,
+    pub return_type: Type,
+    pub body: Box<[StatementNode]>,
+    pub captures: Scope,
+}
+
+impl Value {
+    pub fn type_of(&self) -> Type {
+        match self {
+            Value::Void => Type::Void,
+            Value::Bool(_) => Type::Bool,
+            Value::Int(_) => Type::Int,
+            Value::Char(_) => Type::Char,
+            Value::String(_) => Type::String,
+            Value::Object(r#type, _) => Type::Object(r#type.clone()),
+            Value::Function(function) => function.type_of(),
+        }
+    }
+}
+
+impl Function {
+    pub fn type_of(&self) -> Type {
+        Type::Function {
+            parameters: self
+                .parameters
+                .iter()
+                .map(|(_, r#type)| r#type.clone())
+                .collect(),
+            return_type: Box::new(self.return_type.clone()),
+        }
+    }
+}
+
+impl From<&FunctionDeclaration> for Function {
+    fn from(
+        FunctionDeclaration {
+            name,
+            parameters,
+            return_type,
+            body,
+            ..
+        }: &FunctionDeclaration,
+    ) -> Self {
+        Function {
+            name: name.clone(),
+            module: "".into(),
+            parameters: parameters.clone(),
+            return_type: return_type.clone(),
+            body: body.clone(),
+            captures: Scope::new(),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Void => write!(f, "void"),
+            Value::Bool(boolean) => write!(f, "{}", boolean),
+            Value::Int(number) => write!(f, "{}", number),
+            Value::Char(char) => write!(f, "'{}'", char),
+            Value::String(text) => write!(f, "\"{}\"", text),
+            Value::Object(r#type, fields) => {
+                let mut fields = fields.iter().collect::<Vec<_>>();
+                fields.sort_by_key(|(name, _)| *name);
+                write!(
+                    f,
+                    "{} {{ {} }}",
+                    r#type,
+                    fields
+                        .iter()
+                        .map(|(name, value)| format!("{}: {}", name, value))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            }
+            Value::Function(function) => write!(f, "{}", function.type_of()),
+        }
+    }
+}
@@ -8,5 +8,9 @@ pub fn lex(input: &str) -> impl Iterator<Item = Result<Token, NilangError>> + '_
     Tokenizer::new(input)
 }
 
+pub fn lex_with_trivia(input: &str) -> impl Iterator<Item = Result<Token, NilangError>> + '_ {
+    Tokenizer::with_trivia(input)
+}
+
 #[cfg(test)]
 mod tests;
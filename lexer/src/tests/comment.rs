@@ -0,0 +1,74 @@
+use nilang_types::{
+    nodes::expressions::{Arithmetic, Operator},
+    tokens::{Token, TokenType},
+};
+
+use crate::{lex, lex_with_trivia};
+
+#[test]
+fn skip_comment() {
+    let mut iter = lex("// leading\n5 / 4 // trailing");
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Literal("5".into()),
+            start: (1, 0),
+            end: (1, 0),
+        },
+    );
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Operator(Operator::Arithmetic(Arithmetic::Divide)),
+            start: (1, 2),
+            end: (1, 2),
+        },
+    );
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Literal("4".into()),
+            start: (1, 4),
+            end: (1, 4),
+        },
+    );
+
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn preserve_comment() {
+    let mut iter = lex_with_trivia("// leading \n5 // trailing");
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Comment(" leading".into()),
+            start: (0, 0),
+            end: (0, 10),
+        },
+    );
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Literal("5".into()),
+            start: (1, 0),
+            end: (1, 0),
+        },
+    );
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Comment(" trailing".into()),
+            start: (1, 2),
+            end: (1, 12),
+        },
+    );
+
+    assert!(iter.next().is_none());
+}
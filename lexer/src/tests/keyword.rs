@@ -66,4 +66,31 @@ fn keyword() {
             end: (0, 1),
         }
     );
+
+    assert_eq!(
+        lex("use").next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Keyword(Keyword::Use),
+            start: (0, 0),
+            end: (0, 2),
+        }
+    );
+
+    assert_eq!(
+        lex("pb").next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Keyword(Keyword::Public),
+            start: (0, 0),
+            end: (0, 1),
+        }
+    );
+
+    assert_eq!(
+        lex("cn").next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Keyword(Keyword::Constant),
+            start: (0, 0),
+            end: (0, 1),
+        }
+    );
 }
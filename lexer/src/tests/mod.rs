@@ -1,3 +1,4 @@
+mod comment;
 mod identifier;
 mod keyword;
 mod literal;
@@ -141,3 +141,21 @@ fn special_character() {
         },
     );
 }
+
+#[test]
+fn unexpected_character() {
+    let mut iter = lex("#a");
+
+    assert!(iter.next().unwrap().is_err());
+
+    assert_eq!(
+        iter.next().unwrap().unwrap(),
+        Token {
+            token: TokenType::Identifier("a".into()),
+            start: (0, 1),
+            end: (0, 1),
+        },
+    );
+
+    assert!(iter.next().is_none());
+}
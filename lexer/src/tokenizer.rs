@@ -9,6 +9,7 @@ use nilang_types::{
 pub struct Tokenizer<'a> {
     iter: Peekable<std::str::Chars<'a>>,
     loc: (usize, usize),
+    trivia: bool,
 }
 
 impl<'a> Iterator for Tokenizer<'a> {
@@ -133,6 +134,9 @@ impl<'a> Iterator for Tokenizer<'a> {
                             "ef" => TokenType::Keyword(Keyword::ElseIf),
                             "el" => TokenType::Keyword(Keyword::Else),
                             "wl" => TokenType::Keyword(Keyword::While),
+                            "use" => TokenType::Keyword(Keyword::Use),
+                            "pb" => TokenType::Keyword(Keyword::Public),
+                            "cn" => TokenType::Keyword(Keyword::Constant),
                             "true" | "false" => TokenType::Literal(aggregation.into()),
                             _ => TokenType::Identifier(aggregation.into()),
                         },
@@ -251,6 +255,28 @@ impl<'a> Iterator for Tokenizer<'a> {
                     let start = self.loc;
                     self.loc.1 += 1;
                     self.iter.next();
+
+                    if let Some('/') = self.iter.peek() {
+                        let mut aggregation = String::new();
+                        self.iter.next();
+                        self.loc.1 += 1;
+
+                        while let Some(c) = self.iter.next_if(|c| *c != '\n') {
+                            self.loc.1 += 1;
+                            aggregation.push(c);
+                        }
+
+                        if !self.trivia {
+                            continue;
+                        }
+
+                        return Some(Ok(Token {
+                            token: TokenType::Comment(aggregation.trim_end().into()),
+                            start,
+                            end: (self.loc.0, self.loc.1 - 1),
+                        }));
+                    }
+
                     return Some(Ok(Token {
                         token: TokenType::Operator(Operator::Arithmetic(Arithmetic::Divide)),
                         start,
@@ -421,10 +447,13 @@ impl<'a> Iterator for Tokenizer<'a> {
                     }));
                 }
                 char => {
-                    return Some(Err(NilangError {
+                    let error = NilangError {
                         location: CodeLocation::at(self.loc.0, self.loc.1),
                         error: LexerErrors::UnexpectedCharacter(*char).into(),
-                    }));
+                    };
+                    self.loc.1 += 1;
+                    self.iter.next();
+                    return Some(Err(error));
                 }
             };
         }
@@ -439,6 +468,15 @@ impl Tokenizer<'_> {
         Tokenizer {
             iter: iter.chars().peekable(),
             loc: (0, 0),
+            trivia: false,
+        }
+    }
+
+    #[inline]
+    pub fn with_trivia(iter: &str) -> Tokenizer<'_> {
+        Tokenizer {
+            trivia: true,
+            ..Tokenizer::new(iter)
         }
     }
 }
@@ -0,0 +1,504 @@
+mod lints;
+
+use std::collections::{HashMap, HashSet};
+
+use errors::NilangError;
+pub use lints::{Config, Level, Lint, Warning};
+use nilang_types::{
+    nodes::{
+        expressions::{Conditional, ExpressionNode},
+        statements::{FunctionDeclaration, Parameter, StatementNode},
+        Span,
+    },
+    tokens::{Keyword, Token, TokenType},
+};
+
+struct Variable {
+    name: Box<str>,
+    span: Span,
+    accesses: usize,
+    parameter: bool,
+}
+
+struct Linter<'a> {
+    config: &'a Config,
+    tokens: &'a [Token],
+    conditionals: usize,
+    loops: usize,
+    closures: usize,
+    functions: HashMap<Box<str>, usize>,
+    globals: HashSet<Box<str>>,
+    scopes: Vec<Vec<Variable>>,
+    current: Option<Box<str>>,
+    warnings: Vec<Warning>,
+}
+
+pub fn lint(code: &str, config: &Config) -> Result<Vec<Warning>, NilangError> {
+    let tokens = nilang_lexer::lex(code).collect::<Result<Vec<_>, _>>()?;
+    let (functions, _, _, globals) = nilang_parser::parse(tokens.iter().cloned().map(Ok))?;
+
+    let mut linter = Linter {
+        config,
+        tokens: &tokens,
+        conditionals: 0,
+        loops: 0,
+        closures: 0,
+        functions: functions
+            .iter()
+            .map(|function| (function.name.clone(), 0))
+            .collect(),
+        globals: globals.iter().map(|global| global.name.clone()).collect(),
+        scopes: Vec::new(),
+        current: None,
+        warnings: Vec::new(),
+    };
+
+    let mut items = functions
+        .iter()
+        .map(|function| (function.span.start, Some(function), None))
+        .chain(
+            globals
+                .iter()
+                .map(|global| (global.span.start, None, Some(&global.value))),
+        )
+        .collect::<Vec<_>>();
+    items.sort_by_key(|(start, ..)| *start);
+
+    for item in items {
+        match item {
+            (_, Some(function), _) => linter.function(function),
+            (_, _, Some(value)) => linter.expression(value),
+            _ => {}
+        }
+    }
+
+    for function in &functions {
+        if !function.public
+            && &*function.name != "main"
+            && !function.name.starts_with('_')
+            && linter.functions.get(&function.name) == Some(&0)
+        {
+            linter.warn(
+                Lint::UnusedFunction,
+                function.span,
+                format!("Function `{}` is never used", function.name),
+            );
+        }
+    }
+
+    let mut warnings = linter.warnings;
+    warnings.sort_by_key(|warning| warning.span.start);
+    Ok(warnings)
+}
+
+impl Linter<'_> {
+    fn function(&mut self, function: &FunctionDeclaration) {
+        let header = self
+            .tokens
+            .iter()
+            .position(|token| token.start == function.span.start)
+            .unwrap_or_default();
+
+        self.current = Some(function.name.clone());
+        if function.body.is_empty() {
+            self.warn(
+                Lint::EmptyBody,
+                function.span,
+                format!("Function `{}` has an empty body", function.name),
+            );
+        }
+        self.callable(header, &function.parameters, &function.body);
+        self.current = None;
+    }
+
+    fn callable(&mut self, header: usize, parameters: &[Parameter], body: &[StatementNode]) {
+        let spans = self.parameter_spans(header);
+        self.scopes.push(Vec::new());
+        for (i, (name, _)) in parameters.iter().enumerate() {
+            let span = spans.get(i).copied().unwrap_or_default();
+            self.declare(name, span, true);
+        }
+        self.block(body);
+        self.pop_scope();
+    }
+
+    fn block(&mut self, body: &[StatementNode]) {
+        self.scopes.push(Vec::new());
+        for statement in body {
+            self.statement(statement);
+        }
+        self.pop_scope();
+    }
+
+    fn statement(&mut self, statement: &StatementNode) {
+        match statement {
+            StatementNode::VariableDeclaration {
+                name, value, span, ..
+            } => {
+                self.expression(value);
+                self.declare(name, *span, false);
+            }
+            StatementNode::VariableAssignment { value, .. }
+            | StatementNode::Return { value, .. } => self.expression(value),
+            StatementNode::FunctionCall(call) => {
+                self.access(&call.name);
+                call.arguments
+                    .iter()
+                    .for_each(|argument| self.expression(argument));
+            }
+            StatementNode::Conditional(conditional) => self.conditional(conditional),
+            StatementNode::WhileLoop {
+                condition, body, ..
+            } => {
+                let anchor = self.anchor(Anchor::Loop);
+                let span = self.span(anchor);
+                self.condition(condition, span);
+                self.expression(condition);
+                if body.is_empty() {
+                    self.warn(Lint::EmptyBody, span, "Empty `wl` body".into());
+                }
+                self.block(body);
+            }
+        }
+    }
+
+    fn conditional(&mut self, conditional: &Conditional) {
+        let mut next = Some(conditional);
+        while let Some(Conditional {
+            condition,
+            body,
+            chained,
+            ..
+        }) = next
+        {
+            let anchor = self.anchor(Anchor::Conditional);
+            let span = self.span(anchor);
+            let keyword = match anchor.map(|index| &self.tokens[index].token) {
+                Some(TokenType::Keyword(Keyword::ElseIf)) => "ef",
+                Some(TokenType::Keyword(Keyword::Else)) => "el",
+                _ => "if",
+            };
+
+            if keyword != "el" {
+                self.condition(condition, span);
+            }
+            self.expression(condition);
+            if body.is_empty() {
+                self.warn(Lint::EmptyBody, span, format!("Empty `{}` body", keyword));
+            }
+            self.block(body);
+            next = chained.as_deref();
+        }
+    }
+
+    fn condition(&mut self, condition: &ExpressionNode, span: Span) {
+        if is_constant(condition) {
+            self.warn(
+                Lint::ConstantCondition,
+                span,
+                "Condition is constant".into(),
+            );
+        }
+    }
+
+    fn expression(&mut self, expression: &ExpressionNode) {
+        match expression {
+            ExpressionNode::Primitive(_) => {}
+            ExpressionNode::VariableReference(name) => self.access(name),
+            ExpressionNode::FieldAccess { structure, .. } => self.expression(structure),
+            ExpressionNode::FunctionCall(call) => {
+                self.access(&call.name);
+                call.arguments
+                    .iter()
+                    .for_each(|argument| self.expression(argument));
+            }
+            ExpressionNode::Parenthesis(inner) => self.expression(inner),
+            ExpressionNode::Operation(operation) => {
+                self.expression(&operation.a);
+                self.expression(&operation.b);
+            }
+            ExpressionNode::Object { fields, .. } => {
+                fields.values().for_each(|value| self.expression(value))
+            }
+            ExpressionNode::Closure {
+                parameters, body, ..
+            } => {
+                let header = self.anchor(Anchor::Closure);
+                if body.is_empty() {
+                    self.warn(
+                        Lint::EmptyBody,
+                        self.span(header),
+                        "Closure has an empty body".into(),
+                    );
+                }
+                self.callable(header.unwrap_or(self.tokens.len()), parameters, body);
+            }
+        }
+    }
+
+    fn declare(&mut self, name: &str, span: Span, parameter: bool) {
+        if !name.starts_with('_') && (self.lookup(name).is_some() || self.globals.contains(name)) {
+            self.warn(
+                Lint::ShadowedName,
+                span,
+                format!("`{}` shadows an earlier declaration", name),
+            );
+        }
+
+        if let Some(scope) = self.scopes.last_mut() {
+            scope.push(Variable {
+                name: name.into(),
+                span,
+                accesses: 0,
+                parameter,
+            });
+        }
+    }
+
+    fn pop_scope(&mut self) {
+        for variable in self.scopes.pop().unwrap_or_default() {
+            if variable.accesses > 0 || variable.name.starts_with('_') {
+                continue;
+            }
+
+            if variable.parameter {
+                self.warn(
+                    Lint::UnusedParameter,
+                    variable.span,
+                    format!("Parameter `{}` is never used", variable.name),
+                );
+            } else {
+                self.warn(
+                    Lint::UnusedVariable,
+                    variable.span,
+                    format!("Variable `{}` is never used", variable.name),
+                );
+            }
+        }
+    }
+
+    fn lookup(&mut self, name: &str) -> Option<&mut Variable> {
+        self.scopes
+            .iter_mut()
+            .rev()
+            .flat_map(|scope| scope.iter_mut().rev())
+            .find(|variable| &*variable.name == name)
+    }
+
+    fn access(&mut self, name: &str) {
+        match self.lookup(name) {
+            Some(variable) => variable.accesses += 1,
+            None => self.use_function(name),
+        }
+    }
+
+    fn use_function(&mut self, name: &str) {
+        if self.current.as_deref() == Some(name) {
+            return;
+        }
+        if let Some(uses) = self.functions.get_mut(name) {
+            *uses += 1;
+        }
+    }
+
+    fn warn(&mut self, lint: Lint, span: Span, message: String) {
+        let level = self.config.level(lint);
+        if level != Level::Allow {
+            self.warnings.push(Warning {
+                lint,
+                level,
+                span,
+                message: message.into(),
+            });
+        }
+    }
+
+    fn anchor(&mut self, anchor: Anchor) -> Option<usize> {
+        let tokens = self.tokens;
+        let cursor = match anchor {
+            Anchor::Conditional => &mut self.conditionals,
+            Anchor::Loop => &mut self.loops,
+            Anchor::Closure => &mut self.closures,
+        };
+
+        let index = (*cursor..tokens.len()).find(|index| match &tokens[*index].token {
+            TokenType::Keyword(Keyword::If | Keyword::ElseIf | Keyword::Else) => {
+                anchor == Anchor::Conditional
+            }
+            TokenType::Keyword(Keyword::While) => anchor == Anchor::Loop,
+            TokenType::Keyword(Keyword::Function) => {
+                anchor == Anchor::Closure && is_closure(tokens, *index)
+            }
+            _ => false,
+        })?;
+
+        *cursor = index + 1;
+        Some(index)
+    }
+
+    fn span(&self, index: Option<usize>) -> Span {
+        index
+            .and_then(|index| self.tokens.get(index))
+            .map(|token| Span::new(token.start, token.end))
+            .unwrap_or_default()
+    }
+
+    fn parameter_spans(&self, header: usize) -> Vec<Span> {
+        let mut spans = Vec::new();
+        let mut depth = 0;
+        for index in header..self.tokens.len() {
+            match &self.tokens[index].token {
+                TokenType::OpeningParenthesis => depth += 1,
+                TokenType::ClosingParenthesis => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                TokenType::Identifier(_)
+                    if depth == 1
+                        && self.tokens.get(index + 1).map(|token| &token.token)
+                            == Some(&TokenType::Colon) =>
+                {
+                    spans.push(Span::new(self.tokens[index].start, self.tokens[index].end));
+                }
+                _ => {}
+            }
+        }
+        spans
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Anchor {
+    Conditional,
+    Loop,
+    Closure,
+}
+
+fn is_closure(tokens: &[Token], index: usize) -> bool {
+    let token = |offset: usize| tokens.get(index + offset).map(|token| &token.token);
+    if token(1) != Some(&TokenType::OpeningParenthesis) {
+        return false;
+    }
+
+    match (token(2), token(3)) {
+        (Some(TokenType::Identifier(_)), Some(TokenType::Colon)) => true,
+        (Some(TokenType::ClosingParenthesis), _) => {
+            index == 0 || tokens[index - 1].token != TokenType::Colon
+        }
+        _ => false,
+    }
+}
+
+fn is_constant(expression: &ExpressionNode) -> bool {
+    match expression {
+        ExpressionNode::Primitive(_) => true,
+        ExpressionNode::Parenthesis(inner) => is_constant(inner),
+        ExpressionNode::Operation(operation) => {
+            is_constant(&operation.a) && is_constant(&operation.b)
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::nodes::Span;
+
+    use crate::{lint, Config, Level, Lint};
+
+    fn lints(code: &str, config: &Config) -> Vec<(Lint, Level, Span)> {
+        lint(code, config)
+            .unwrap()
+            .into_iter()
+            .map(|warning| (warning.lint, warning.level, warning.span))
+            .collect()
+    }
+
+    #[test]
+    fn test_lint_program() {
+        let code = "fn helper(a: int, b: int): int {
+    vr c: int = 1;
+    vr a: int = b;
+    rt a;
+}
+
+fn unused(): int {
+    rt unused();
+}
+
+fn main(): int {
+    vr _ignored: int = 0;
+    if (true) {
+        print(helper(1, 2));
+    } ef (x > 1) {} el {}
+    wl (1 == 2) {}
+    vr f: fn(int): int = fn(x: int): int { rt 0; };
+    rt 0;
+}
+";
+
+        assert_eq!(
+            lints(code, &Config::default()),
+            [
+                (
+                    Lint::UnusedParameter,
+                    Level::Warn,
+                    Span::new((0, 10), (0, 10))
+                ),
+                (Lint::UnusedVariable, Level::Warn, Span::new((1, 7), (1, 7))),
+                (Lint::ShadowedName, Level::Warn, Span::new((2, 7), (2, 7))),
+                (Lint::UnusedFunction, Level::Warn, Span::new((6, 3), (6, 8))),
+                (
+                    Lint::ConstantCondition,
+                    Level::Warn,
+                    Span::new((12, 4), (12, 5))
+                ),
+                (Lint::EmptyBody, Level::Warn, Span::new((14, 6), (14, 7))),
+                (Lint::EmptyBody, Level::Warn, Span::new((14, 20), (14, 21))),
+                (
+                    Lint::ConstantCondition,
+                    Level::Warn,
+                    Span::new((15, 4), (15, 5))
+                ),
+                (Lint::EmptyBody, Level::Warn, Span::new((15, 4), (15, 5))),
+                (
+                    Lint::UnusedVariable,
+                    Level::Warn,
+                    Span::new((16, 7), (16, 7))
+                ),
+                (
+                    Lint::UnusedParameter,
+                    Level::Warn,
+                    Span::new((16, 28), (16, 28))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_levels() {
+        let code = "fn main(): int {
+    vr x: int = 1;
+    if (true) {
+        rt 1;
+    }
+    rt 0;
+}
+";
+
+        let mut config = Config::default();
+        config.set(Lint::UnusedVariable, Level::Allow);
+        config.set(Lint::ConstantCondition, Level::Deny);
+
+        assert_eq!(
+            lints(code, &config),
+            [(
+                Lint::ConstantCondition,
+                Level::Deny,
+                Span::new((2, 4), (2, 5))
+            )]
+        );
+    }
+}
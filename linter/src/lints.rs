@@ -0,0 +1,103 @@
+use std::{collections::HashMap, fmt::Display, str::FromStr};
+
+use colored::Colorize;
+use nilang_types::nodes::Span;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    UnusedVariable,
+    UnusedFunction,
+    UnusedParameter,
+    ShadowedName,
+    ConstantCondition,
+    EmptyBody,
+}
+
+impl Lint {
+    pub const ALL: [Lint; 6] = [
+        Lint::UnusedVariable,
+        Lint::UnusedFunction,
+        Lint::UnusedParameter,
+        Lint::ShadowedName,
+        Lint::ConstantCondition,
+        Lint::EmptyBody,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Lint::UnusedVariable => "unused-variable",
+            Lint::UnusedFunction => "unused-function",
+            Lint::UnusedParameter => "unused-parameter",
+            Lint::ShadowedName => "shadowed-name",
+            Lint::ConstantCondition => "constant-condition",
+            Lint::EmptyBody => "empty-body",
+        }
+    }
+}
+
+impl FromStr for Lint {
+    type Err = Box<str>;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Lint::ALL
+            .into_iter()
+            .find(|lint| lint.name() == name)
+            .ok_or_else(|| format!("Unknown lint `{}`", name).into())
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Level {
+    Allow,
+    #[default]
+    Warn,
+    Deny,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Config(HashMap<Lint, Level>);
+
+impl Config {
+    pub fn set(&mut self, lint: Lint, level: Level) {
+        self.0.insert(lint, level);
+    }
+
+    pub fn level(&self, lint: Lint) -> Level {
+        self.0.get(&lint).copied().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Warning {
+    pub lint: Lint,
+    pub level: Level,
+    pub span: Span,
+    pub message: Box<str>,
+}
+
+impl Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let message = format!(
+            "[{}:{}] {}: {} ({})",
+            self.span.start.0,
+            self.span.end.0,
+            if self.level == Level::Deny {
+                "Error"
+            } else {
+                "Warning"
+            },
+            self.message,
+            self.lint.name()
+        );
+
+        write!(
+            f,
+            "{}",
+            if self.level == Level::Deny {
+                message.as_str().red()
+            } else {
+                message.as_str().yellow()
+            }
+        )
+    }
+}
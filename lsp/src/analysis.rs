@@ -0,0 +1,888 @@
+use std::{fs::read_to_string, path::Path};
+
+use errors::NilangError;
+use nilang_transformer::Module;
+use nilang_types::{
+    nodes::{
+        expressions::{Conditional, ExpressionNode},
+        statements::{
+            FunctionDeclaration, GlobalDeclaration, ModulePath, Parameter, StatementNode,
+            StructureDeclaration,
+        },
+        Span, Type,
+    },
+    tokens::{Keyword, Token, TokenType},
+};
+
+pub type Position = (usize, usize);
+
+const KEYWORDS: [&str; 11] = [
+    "fn", "vr", "rt", "st", "if", "ef", "el", "wl", "cn", "pb", "use",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Keyword,
+    Function,
+    Structure,
+    Variable,
+    Field,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Definition {
+    pub name: Box<str>,
+    pub span: Span,
+    pub kind: Kind,
+    pub r#type: Type,
+    pub detail: String,
+}
+
+#[derive(Default)]
+pub struct Document {
+    text: String,
+    tokens: Vec<Token>,
+    functions: Vec<FunctionDeclaration>,
+    structures: Vec<StructureDeclaration>,
+    uses: Vec<ModulePath>,
+    globals: Vec<GlobalDeclaration>,
+    error: Option<NilangError>,
+}
+
+impl Document {
+    pub fn new(text: String) -> Self {
+        let mut document = Document::default();
+        document.update(text);
+        document
+    }
+
+    pub fn update(&mut self, text: String) {
+        self.tokens = nilang_lexer::lex(&text).map_while(Result::ok).collect();
+        match nilang_parser::parse(nilang_lexer::lex(&text)) {
+            Ok((functions, structures, uses, globals)) => {
+                self.functions = functions;
+                self.structures = structures;
+                self.uses = uses;
+                self.globals = globals;
+                self.error = None;
+            }
+            Err(err) => self.error = Some(err),
+        }
+        self.text = text;
+    }
+
+    pub fn column(&self, line: usize, character: usize) -> usize {
+        let mut units = 0;
+        let mut column = 0;
+        for char in self.text.lines().nth(line).unwrap_or_default().chars() {
+            if units >= character {
+                break;
+            }
+            units += char.len_utf16();
+            column += if char == '\t' { 4 } else { 1 };
+        }
+        column + character.saturating_sub(units)
+    }
+
+    pub fn character(&self, line: usize, column: usize) -> usize {
+        let mut units = 0;
+        let mut columns = 0;
+        for char in self.text.lines().nth(line).unwrap_or_default().chars() {
+            if columns >= column {
+                break;
+            }
+            units += char.len_utf16();
+            columns += if char == '\t' { 4 } else { 1 };
+        }
+        units + column.saturating_sub(columns)
+    }
+
+    pub fn diagnostics(&self, directory: Option<&Path>) -> Vec<(Span, String)> {
+        if let Some(NilangError { location, error }) = &self.error {
+            let span = if location.start().0 == usize::MAX {
+                let line = self.text.lines().count().saturating_sub(1);
+                let column = self.column(line, usize::MAX);
+                Span::new((line, column), (line, column))
+            } else {
+                Span::new(location.start(), location.end())
+            };
+            return vec![(span, error.to_string())];
+        }
+
+        let mut modules = vec![Module {
+            path: ModulePath::default(),
+            functions: self.functions.clone(),
+            structures: self.structures.clone(),
+            uses: self.uses.clone(),
+            globals: self.globals.clone(),
+        }];
+        let mut diagnostics = Vec::new();
+        let uses = self
+            .tokens
+            .iter()
+            .filter(|token| token.token == TokenType::Keyword(Keyword::Use));
+        for (path, token) in self.uses.iter().zip(uses) {
+            match directory.and_then(|directory| load_module(directory, path)) {
+                Some(module) => modules.push(module),
+                None => diagnostics.push((
+                    Span::new(token.start, token.end),
+                    format!("Module `{}` could not be loaded", path.join("::")),
+                )),
+            }
+        }
+
+        let mut loaded = 1;
+        while let Some(module) = modules.get(loaded) {
+            let missing = module
+                .uses
+                .iter()
+                .filter(|path| !modules.iter().any(|module| &module.path == *path))
+                .cloned()
+                .collect::<Vec<_>>();
+            modules.extend(
+                missing.iter().filter_map(|path| {
+                    directory.and_then(|directory| load_module(directory, path))
+                }),
+            );
+            loaded += 1;
+        }
+
+        match self.transform(&modules) {
+            Ok(errors) => diagnostics.extend(errors),
+            Err(err) => diagnostics.push((
+                err.span().unwrap_or_default(),
+                format!("Transformer: {}", err),
+            )),
+        }
+        diagnostics
+    }
+
+    fn transform(
+        &self,
+        modules: &[Module],
+    ) -> Result<Vec<(Span, String)>, errors::TransformerErrors> {
+        let context = nilang_transformer::module_context(&modules[0], modules)?;
+
+        let mut diagnostics = Vec::new();
+        for function in &self.functions {
+            let (instructions, _, mut closures) =
+                nilang_transformer::transform_function(&context, function);
+            let mut error = instructions.filter_map(Result::err).next();
+
+            while let (None, Some(closure)) = (&error, closures.pop()) {
+                let (instructions, _, new_closures) =
+                    nilang_transformer::transform_closure(&context, &closure);
+                error = instructions.filter_map(Result::err).next();
+                closures.extend(new_closures);
+            }
+
+            if let Some(err) = error {
+                diagnostics.push((
+                    err.span().unwrap_or(function.span),
+                    format!("Transformer: {}", err),
+                ));
+            }
+        }
+        Ok(diagnostics)
+    }
+
+    pub fn definitions(&self, position: Position) -> Vec<Definition> {
+        let Some(index) = self.identifier_at(position) else {
+            return Vec::new();
+        };
+        let TokenType::Identifier(name) = &self.tokens[index].token else {
+            return Vec::new();
+        };
+
+        if let Some(structure) = self.field_owner(index, position) {
+            return self
+                .fields(&structure)
+                .into_iter()
+                .filter(|field| &field.name == name)
+                .collect();
+        }
+
+        let start = self.tokens[index].start;
+        if let Some(parameter) = self
+            .parameters_in_scope(position, true)
+            .into_iter()
+            .find(|parameter| parameter.span.start == start)
+        {
+            return vec![parameter];
+        }
+
+        self.resolve(name, position)
+    }
+
+    pub fn hover(&self, position: Position) -> Option<(Span, String)> {
+        let index = self.identifier_at(position)?;
+        let Token { start, end, .. } = self.tokens[index];
+        let definition = self.definitions(position);
+        if definition.is_empty() {
+            return None;
+        }
+
+        Some((
+            Span::new(start, end),
+            definition
+                .iter()
+                .map(|definition| definition.detail.as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ))
+    }
+
+    pub fn completions(&self, position: Position) -> Vec<Definition> {
+        let before = self
+            .tokens
+            .iter()
+            .rposition(|token| (token.end.0, token.end.1 + 1) <= position);
+        let before = match before {
+            Some(index)
+                if matches!(self.tokens[index].token, TokenType::Identifier(_))
+                    && (self.tokens[index].end.0, self.tokens[index].end.1 + 1) == position =>
+            {
+                index.checked_sub(1)
+            }
+            before => before,
+        };
+
+        if let Some(dot) = before.filter(|dot| self.tokens[*dot].token == TokenType::Dot) {
+            return match self.type_before(dot, position) {
+                Some(Type::Object(structure)) => self.fields(&structure),
+                _ => Vec::new(),
+            };
+        }
+
+        let mut completions = KEYWORDS
+            .iter()
+            .map(|keyword| Definition {
+                name: (*keyword).into(),
+                span: Span::default(),
+                kind: Kind::Keyword,
+                r#type: Type::Void,
+                detail: String::new(),
+            })
+            .collect::<Vec<_>>();
+        let mut locals = self.locals(position);
+        locals.sort_by_key(|local| std::cmp::Reverse(local.span.start));
+        for definition in locals.into_iter().chain(self.top_level()) {
+            if !completions
+                .iter()
+                .any(|completion| completion.name == definition.name)
+            {
+                completions.push(definition);
+            }
+        }
+        completions
+    }
+
+    fn top_level(&self) -> Vec<Definition> {
+        let globals = self.globals.iter().map(|global| Definition {
+            name: global.name.clone(),
+            span: global.span,
+            kind: Kind::Variable,
+            r#type: global.r#type.clone(),
+            detail: format!(
+                "{} {}: {}",
+                if global.constant { "cn" } else { "vr" },
+                global.name,
+                global.r#type
+            ),
+        });
+        let functions = self.functions.iter().map(|function| Definition {
+            name: function.name.clone(),
+            span: function.span,
+            kind: Kind::Function,
+            r#type: Type::Function {
+                parameters: function
+                    .parameters
+                    .iter()
+                    .map(|(_, r#type)| r#type.clone())
+                    .collect(),
+                return_type: Box::new(function.return_type.clone()),
+            },
+            detail: format!(
+                "{}fn {}({}): {}",
+                if function.constant { "cn " } else { "" },
+                function.name,
+                function
+                    .parameters
+                    .iter()
+                    .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                function.return_type
+            ),
+        });
+        let structures = self.structures.iter().map(|structure| Definition {
+            name: structure.name.clone(),
+            span: structure.span,
+            kind: Kind::Structure,
+            r#type: Type::Object(structure.name.clone()),
+            detail: format!(
+                "st {} {{ {} }}",
+                structure.name,
+                self.fields(&structure.name)
+                    .iter()
+                    .map(|field| field.detail.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+
+        globals.chain(functions).chain(structures).collect()
+    }
+
+    fn resolve(&self, name: &str, position: Position) -> Vec<Definition> {
+        if let Some(local) = self
+            .locals(position)
+            .into_iter()
+            .filter(|local| &*local.name == name)
+            .max_by_key(|local| local.span.start)
+        {
+            return vec![local];
+        }
+
+        let top_level = self.top_level();
+        [Kind::Variable, Kind::Function, Kind::Structure]
+            .into_iter()
+            .map(|kind| {
+                top_level
+                    .iter()
+                    .filter(|definition| definition.kind == kind && &*definition.name == name)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .find(|definitions| !definitions.is_empty())
+            .unwrap_or_default()
+    }
+
+    fn enclosing_function(&self, position: Position) -> Option<&FunctionDeclaration> {
+        let starts = self
+            .functions
+            .iter()
+            .map(|function| function.span.start)
+            .chain(self.structures.iter().map(|structure| structure.span.start))
+            .chain(self.globals.iter().map(|global| global.span.start))
+            .collect::<Vec<_>>();
+
+        self.functions.iter().find(|function| {
+            function.span.start <= position
+                && !starts
+                    .iter()
+                    .any(|start| function.span.start < *start && *start <= position)
+        })
+    }
+
+    fn locals(&self, position: Position) -> Vec<Definition> {
+        let Some(function) = self.enclosing_function(position) else {
+            return Vec::new();
+        };
+
+        let mut declarations = Vec::new();
+        let mut closures = Vec::new();
+        walk_statements(&function.body, &mut declarations, &mut closures);
+
+        let variables = declarations
+            .into_iter()
+            .filter(|(_, _, span)| span.start <= position)
+            .filter(|(_, _, span)| {
+                self.token_index(span.start)
+                    .is_some_and(|index| self.is_visible(index, position))
+            })
+            .map(|(name, r#type, span)| Definition {
+                name: name.into(),
+                span,
+                kind: Kind::Variable,
+                r#type: r#type.clone(),
+                detail: format!("vr {}: {}", name, r#type),
+            });
+
+        self.parameters_in_scope(position, false)
+            .into_iter()
+            .chain(variables)
+            .collect()
+    }
+
+    fn parameters_in_scope(&self, position: Position, declared: bool) -> Vec<Definition> {
+        let Some(function) = self.enclosing_function(position) else {
+            return Vec::new();
+        };
+        let Some(header) = self.token_index(function.span.start) else {
+            return Vec::new();
+        };
+
+        let mut declarations = Vec::new();
+        let mut closures = Vec::new();
+        walk_statements(&function.body, &mut declarations, &mut closures);
+
+        let mut headers = vec![(header, &*function.parameters)];
+        for (index, token) in self.tokens.iter().enumerate().skip(header + 1) {
+            if token.start >= position {
+                break;
+            }
+            if token.token != TokenType::Keyword(Keyword::Function) {
+                continue;
+            }
+
+            let names = self.parameter_names(index);
+            if let Some(parameters) = closures.iter().find(|parameters| {
+                parameters.len() == names.len()
+                    && parameters
+                        .iter()
+                        .zip(&names)
+                        .all(|((name, _), (_, parameter))| name == parameter)
+            }) {
+                headers.push((index, parameters));
+            }
+        }
+
+        let mut definitions = Vec::new();
+        for (header, parameters) in headers {
+            let names = self.parameter_names(header);
+            let in_scope = self.body_start(header).is_some_and(|body| {
+                self.tokens[body].start < position && self.is_visible(body, position)
+            });
+            for (index, name) in names {
+                let Token { start, end, .. } = self.tokens[index];
+                let declaration = declared && Span::new(start, end).contains(position);
+                if !in_scope && !declaration {
+                    continue;
+                }
+                let Some((_, r#type)) = parameters.iter().find(|(parameter, _)| parameter == &name)
+                else {
+                    continue;
+                };
+
+                definitions.push(Definition {
+                    detail: format!("{}: {}", name, r#type),
+                    name,
+                    span: Span::new(start, end),
+                    kind: Kind::Variable,
+                    r#type: r#type.clone(),
+                });
+            }
+        }
+        definitions
+    }
+
+    fn parameter_names(&self, header: usize) -> Vec<(usize, Box<str>)> {
+        let mut names = Vec::new();
+        let mut depth = 0;
+        for index in header + 1..self.tokens.len() {
+            match &self.tokens[index].token {
+                TokenType::OpeningParenthesis => depth += 1,
+                TokenType::ClosingParenthesis => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                TokenType::Identifier(name)
+                    if depth == 1
+                        && self.tokens.get(index + 1).map(|token| &token.token)
+                            == Some(&TokenType::Colon) =>
+                {
+                    names.push((index, name.clone()));
+                }
+                _ if depth == 0 => break,
+                _ => {}
+            }
+        }
+        names
+    }
+
+    fn body_start(&self, header: usize) -> Option<usize> {
+        let mut depth = 0;
+        (header + 1..self.tokens.len()).find(|index| match self.tokens[*index].token {
+            TokenType::OpeningParenthesis => {
+                depth += 1;
+                false
+            }
+            TokenType::ClosingParenthesis => {
+                depth -= 1;
+                false
+            }
+            TokenType::OpeningBrace => depth == 0,
+            _ => false,
+        })
+    }
+
+    fn is_visible(&self, from: usize, position: Position) -> bool {
+        let mut depth = 0;
+        for token in &self.tokens[from + 1..] {
+            if token.start >= position {
+                break;
+            }
+            match token.token {
+                TokenType::OpeningBrace => depth += 1,
+                TokenType::ClosingBrace => depth -= 1,
+                _ => {}
+            }
+            if depth < 0 {
+                return false;
+            }
+        }
+        true
+    }
+
+    fn fields(&self, structure: &str) -> Vec<Definition> {
+        let Some(declaration) = self
+            .structures
+            .iter()
+            .find(|declaration| &*declaration.name == structure)
+        else {
+            return Vec::new();
+        };
+        let Some(header) = self.token_index(declaration.span.start) else {
+            return Vec::new();
+        };
+
+        let mut fields = Vec::new();
+        let mut depth = 0;
+        for index in header + 1..self.tokens.len() {
+            match &self.tokens[index].token {
+                TokenType::OpeningBrace => depth += 1,
+                TokenType::ClosingBrace => {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                }
+                TokenType::Identifier(name)
+                    if depth == 1
+                        && self.tokens.get(index + 1).map(|token| &token.token)
+                            == Some(&TokenType::Colon) =>
+                {
+                    if let Some(r#type) = declaration.fields.get(name) {
+                        let Token { start, end, .. } = self.tokens[index];
+                        fields.push(Definition {
+                            name: name.clone(),
+                            span: Span::new(start, end),
+                            kind: Kind::Field,
+                            r#type: r#type.clone(),
+                            detail: format!("{}: {}", name, r#type),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        fields
+    }
+
+    fn field_owner(&self, index: usize, position: Position) -> Option<Box<str>> {
+        if let Some(dot) = index
+            .checked_sub(1)
+            .filter(|dot| self.tokens[*dot].token == TokenType::Dot)
+        {
+            return match self.type_before(dot, position)? {
+                Type::Object(structure) => Some(structure),
+                _ => None,
+            };
+        }
+
+        if self.tokens.get(index + 1)?.token != TokenType::Colon {
+            return None;
+        }
+
+        let mut depth = 0;
+        for brace in (0..index).rev() {
+            match self.tokens[brace].token {
+                TokenType::ClosingBrace | TokenType::ClosingParenthesis => depth += 1,
+                TokenType::OpeningParenthesis if depth == 0 => return None,
+                TokenType::OpeningBrace if depth == 0 => {
+                    let TokenType::Identifier(structure) =
+                        &self.tokens[brace.checked_sub(1)?].token
+                    else {
+                        return None;
+                    };
+                    return self
+                        .structures
+                        .iter()
+                        .any(|declaration| &declaration.name == structure)
+                        .then(|| structure.clone());
+                }
+                TokenType::OpeningBrace | TokenType::OpeningParenthesis => depth -= 1,
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn type_before(&self, dot: usize, position: Position) -> Option<Type> {
+        let previous = dot.checked_sub(1)?;
+        match &self.tokens[previous].token {
+            TokenType::Identifier(name) => match previous.checked_sub(1) {
+                Some(dot) if self.tokens[dot].token == TokenType::Dot => {
+                    let Type::Object(structure) = self.type_before(dot, position)? else {
+                        return None;
+                    };
+                    self.structures
+                        .iter()
+                        .find(|declaration| declaration.name == structure)?
+                        .fields
+                        .get(name)
+                        .cloned()
+                }
+                _ => self
+                    .resolve(name, position)
+                    .into_iter()
+                    .next()
+                    .map(|definition| definition.r#type),
+            },
+            TokenType::ClosingParenthesis => {
+                let mut depth = 0;
+                let opening = (0..previous).rev().find(|index| {
+                    match self.tokens[*index].token {
+                        TokenType::ClosingParenthesis => depth += 1,
+                        TokenType::OpeningParenthesis if depth == 0 => return true,
+                        TokenType::OpeningParenthesis => depth -= 1,
+                        _ => {}
+                    }
+                    false
+                })?;
+                let TokenType::Identifier(name) = &self.tokens[opening.checked_sub(1)?].token
+                else {
+                    return None;
+                };
+                match self.resolve(name, position).into_iter().next()?.r#type {
+                    Type::Function { return_type, .. } => Some(*return_type),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn identifier_at(&self, position: Position) -> Option<usize> {
+        let identifiers = self
+            .tokens
+            .iter()
+            .enumerate()
+            .filter(|(_, token)| matches!(token.token, TokenType::Identifier(_)))
+            .filter(|(_, token)| token.start.0 == position.0 && token.start.1 <= position.1);
+
+        let mut touching = None;
+        for (index, token) in identifiers {
+            if position.1 <= token.end.1 {
+                return Some(index);
+            }
+            if position.1 == token.end.1 + 1 {
+                touching = Some(index);
+            }
+        }
+        touching
+    }
+
+    fn token_index(&self, position: Position) -> Option<usize> {
+        self.tokens
+            .binary_search_by_key(&position, |token| token.start)
+            .ok()
+    }
+}
+
+fn load_module(directory: &Path, path: &[Box<str>]) -> Option<Module> {
+    let file = path
+        .iter()
+        .fold(directory.to_path_buf(), |file, module| file.join(&**module))
+        .with_extension("ni");
+    let code = read_to_string(file).ok()?;
+    let (functions, structures, uses, globals) =
+        nilang_parser::parse(nilang_lexer::lex(&code)).ok()?;
+    Some(Module {
+        path: path.into(),
+        functions,
+        structures,
+        uses,
+        globals,
+    })
+}
+
+fn walk_statements<'a>(
+    body: &'a [StatementNode],
+    declarations: &mut Vec<(&'a str, &'a Type, Span)>,
+    closures: &mut Vec<&'a [Parameter]>,
+) {
+    for statement in body {
+        match statement {
+            StatementNode::VariableDeclaration {
+                name,
+                r#type,
+                value,
+                span,
+            } => {
+                declarations.push((name, r#type, *span));
+                walk_expression(value, declarations, closures);
+            }
+            StatementNode::VariableAssignment { value, .. }
+            | StatementNode::Return { value, .. } => walk_expression(value, declarations, closures),
+            StatementNode::FunctionCall(call) => {
+                for argument in &call.arguments {
+                    walk_expression(argument, declarations, closures);
+                }
+            }
+            StatementNode::Conditional(conditional) => {
+                walk_conditional(conditional, declarations, closures)
+            }
+            StatementNode::WhileLoop {
+                condition, body, ..
+            } => {
+                walk_expression(condition, declarations, closures);
+                walk_statements(body, declarations, closures);
+            }
+        }
+    }
+}
+
+fn walk_conditional<'a>(
+    Conditional {
+        condition,
+        body,
+        chained,
+        ..
+    }: &'a Conditional,
+    declarations: &mut Vec<(&'a str, &'a Type, Span)>,
+    closures: &mut Vec<&'a [Parameter]>,
+) {
+    walk_expression(condition, declarations, closures);
+    walk_statements(body, declarations, closures);
+    if let Some(chained) = chained {
+        walk_conditional(chained, declarations, closures);
+    }
+}
+
+fn walk_expression<'a>(
+    expression: &'a ExpressionNode,
+    declarations: &mut Vec<(&'a str, &'a Type, Span)>,
+    closures: &mut Vec<&'a [Parameter]>,
+) {
+    match expression {
+        ExpressionNode::Primitive(_) | ExpressionNode::VariableReference(_) => {}
+        ExpressionNode::FieldAccess { structure, .. } => {
+            walk_expression(structure, declarations, closures)
+        }
+        ExpressionNode::FunctionCall(call) => {
+            for argument in &call.arguments {
+                walk_expression(argument, declarations, closures);
+            }
+        }
+        ExpressionNode::Parenthesis(inner) => walk_expression(inner, declarations, closures),
+        ExpressionNode::Operation(operation) => {
+            walk_expression(&operation.a, declarations, closures);
+            walk_expression(&operation.b, declarations, closures);
+        }
+        ExpressionNode::Object { fields, .. } => {
+            for value in fields.values() {
+                walk_expression(value, declarations, closures);
+            }
+        }
+        ExpressionNode::Closure {
+            parameters, body, ..
+        } => {
+            closures.push(parameters);
+            walk_statements(body, declarations, closures);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::nodes::{Span, Type};
+
+    use super::{Document, Kind};
+
+    const CODE: &str = "st Point { x: int, y: int }
+
+fn length(p: Point): int {
+    vr sum: int = p.x + p.y;
+    rt sum;
+}
+
+fn main(): int {
+    vr point: Point = Point { x: 3, y: 4 };
+    if (true) {
+        vr inner: int = 1;
+    }
+    rt length(point);
+}
+";
+
+    #[test]
+    fn test_definitions() {
+        let document = Document::new(CODE.into());
+
+        let spans = |position| {
+            document
+                .definitions(position)
+                .into_iter()
+                .map(|definition| definition.span)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(spans((12, 7)), [Span::new((2, 3), (2, 8))]);
+        assert_eq!(spans((12, 15)), [Span::new((8, 7), (8, 11))]);
+        assert_eq!(spans((3, 18)), [Span::new((2, 10), (2, 10))]);
+        assert_eq!(spans((3, 20)), [Span::new((0, 11), (0, 11))]);
+        assert_eq!(spans((3, 26)), [Span::new((0, 19), (0, 19))]);
+        assert_eq!(spans((8, 36)), [Span::new((0, 19), (0, 19))]);
+        assert_eq!(spans((8, 16)), [Span::new((0, 3), (0, 7))]);
+        assert_eq!(spans((2, 10)), [Span::new((2, 10), (2, 10))]);
+    }
+
+    #[test]
+    fn test_hover_and_completions() {
+        let document = Document::new(CODE.into());
+
+        assert_eq!(
+            document.hover((12, 7)),
+            Some((
+                Span::new((12, 7), (12, 12)),
+                "fn length(p: Point): int".into()
+            ))
+        );
+        assert_eq!(
+            document.hover((0, 4)).map(|(_, detail)| detail),
+            Some("st Point { x: int, y: int }".into())
+        );
+
+        let names = |position| {
+            document
+                .completions(position)
+                .into_iter()
+                .map(|definition| definition.name)
+                .collect::<Vec<_>>()
+        };
+        let completions = names((12, 4));
+        assert!(completions.contains(&"point".into()));
+        assert!(completions.contains(&"length".into()));
+        assert!(!completions.contains(&"inner".into()));
+        assert!(!completions.contains(&"sum".into()));
+        assert!(names((10, 26)).contains(&"inner".into()));
+
+        let mut fields = Document::new(
+            "st P { x: int }\nfn main(): int { vr p: P = P { x: 1 }; rt p.x; }".into(),
+        );
+        fields.update("st P { x: int }\nfn main(): int { vr p: P = P { x: 1 }; rt p. }".into());
+        let completions = fields.completions((1, 44));
+        assert_eq!(completions.len(), 1);
+        assert_eq!(completions[0].kind, Kind::Field);
+        assert_eq!(completions[0].r#type, Type::Int);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let document = Document::new("fn main(): int {\n    rt missing;\n}\n".into());
+        let diagnostics = document.diagnostics(None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0, Span::new((1, 4), (1, 5)));
+
+        let document = Document::new("fn main(): int {\n    rt 1\n}\n".into());
+        let diagnostics = document.diagnostics(None);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].0.start, (2, 0));
+
+        assert!(Document::new(CODE.into()).diagnostics(None).is_empty());
+    }
+}
@@ -0,0 +1,266 @@
+use std::{fmt::Display, iter::Peekable, str::Chars};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Json {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(Box<str>),
+    Array(Vec<Json>),
+    Object(Vec<(Box<str>, Json)>),
+}
+
+static NULL: Json = Json::Null;
+
+impl Json {
+    pub fn object<const N: usize>(fields: [(&str, Json); N]) -> Self {
+        Json::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| (key.into(), value))
+                .collect(),
+        )
+    }
+
+    pub fn get(&self, key: &str) -> &Json {
+        match self {
+            Json::Object(fields) => fields
+                .iter()
+                .find(|(name, _)| &**name == key)
+                .map_or(&NULL, |(_, value)| value),
+            _ => &NULL,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Json::String(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    pub fn as_usize(&self) -> Option<usize> {
+        match self {
+            Json::Number(number) if *number >= 0. => Some(*number as usize),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> &[Json] {
+        match self {
+            Json::Array(items) => items,
+            _ => &[],
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.into())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value.into())
+    }
+}
+
+impl From<usize> for Json {
+    fn from(value: usize) -> Self {
+        Json::Number(value as f64)
+    }
+}
+
+impl From<bool> for Json {
+    fn from(value: bool) -> Self {
+        Json::Bool(value)
+    }
+}
+
+impl From<Vec<Json>> for Json {
+    fn from(value: Vec<Json>) -> Self {
+        Json::Array(value)
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Json::Null => write!(f, "null"),
+            Json::Bool(boolean) => write!(f, "{}", boolean),
+            Json::Number(number) => write!(f, "{}", number),
+            Json::String(text) => write_string(f, text),
+            Json::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+            Json::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut std::fmt::Formatter<'_>, text: &str) -> std::fmt::Result {
+    write!(f, "\"")?;
+    for char in text.chars() {
+        match char {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            char if (char as u32) < 0x20 => write!(f, "\\u{:04x}", char as u32)?,
+            char => write!(f, "{}", char)?,
+        }
+    }
+    write!(f, "\"")
+}
+
+pub fn parse(input: &str) -> Option<Json> {
+    let mut chars = input.chars().peekable();
+    let value = parse_value(&mut chars)?;
+    skip_whitespace(&mut chars);
+    chars.peek().is_none().then_some(value)
+}
+
+fn parse_value(chars: &mut Peekable<Chars>) -> Option<Json> {
+    skip_whitespace(chars);
+    match chars.peek()? {
+        'n' => parse_word(chars, "null", Json::Null),
+        't' => parse_word(chars, "true", Json::Bool(true)),
+        'f' => parse_word(chars, "false", Json::Bool(false)),
+        '"' => Some(Json::String(parse_string(chars)?.into())),
+        '[' => {
+            chars.next();
+            let mut items = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&']').is_some() {
+                return Some(Json::Array(items));
+            }
+            loop {
+                items.push(parse_value(chars)?);
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => {}
+                    ']' => return Some(Json::Array(items)),
+                    _ => return None,
+                }
+            }
+        }
+        '{' => {
+            chars.next();
+            let mut fields = Vec::new();
+            skip_whitespace(chars);
+            if chars.next_if_eq(&'}').is_some() {
+                return Some(Json::Object(fields));
+            }
+            loop {
+                skip_whitespace(chars);
+                let key = parse_string(chars)?;
+                skip_whitespace(chars);
+                chars.next_if_eq(&':')?;
+                fields.push((key.into(), parse_value(chars)?));
+                skip_whitespace(chars);
+                match chars.next()? {
+                    ',' => {}
+                    '}' => return Some(Json::Object(fields)),
+                    _ => return None,
+                }
+            }
+        }
+        _ => {
+            let mut number = String::new();
+            while let Some(char) =
+                chars.next_if(|char| char.is_ascii_digit() || "+-.eE".contains(*char))
+            {
+                number.push(char);
+            }
+            number.parse().ok().map(Json::Number)
+        }
+    }
+}
+
+fn parse_word(chars: &mut Peekable<Chars>, word: &str, value: Json) -> Option<Json> {
+    for expected in word.chars() {
+        chars.next_if_eq(&expected)?;
+    }
+    Some(value)
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    chars.next_if_eq(&'"')?;
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(text),
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                'r' => text.push('\r'),
+                't' => text.push('\t'),
+                'b' => text.push('\u{8}'),
+                'f' => text.push('\u{c}'),
+                'u' => {
+                    let high = parse_code_unit(chars)?;
+                    let code = if (0xd800..0xdc00).contains(&high) {
+                        chars.next_if_eq(&'\\')?;
+                        chars.next_if_eq(&'u')?;
+                        let low = parse_code_unit(chars)?;
+                        0x10000 + ((high - 0xd800) << 10) + (low.checked_sub(0xdc00)?)
+                    } else {
+                        high
+                    };
+                    text.push(char::from_u32(code)?);
+                }
+                char => text.push(char),
+            },
+            char => text.push(char),
+        }
+    }
+}
+
+fn parse_code_unit(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let digits = (0..4).map(|_| chars.next()).collect::<Option<String>>()?;
+    u32::from_str_radix(&digits, 16).ok()
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|char| char.is_whitespace()).is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, Json};
+
+    #[test]
+    fn test_json_round_trip() {
+        let text = r#"{"id":1,"method":"initialize","params":{"list":[true,false,null],"text":"a\"b\ncé"}}"#;
+        let json = parse(text).unwrap();
+
+        assert_eq!(json.get("id").as_usize(), Some(1));
+        assert_eq!(json.get("method").as_str(), Some("initialize"));
+        assert_eq!(
+            json.get("params").get("list"),
+            &Json::Array(vec![Json::Bool(true), Json::Bool(false), Json::Null])
+        );
+        assert_eq!(json.get("params").get("text").as_str(), Some("a\"b\ncé"));
+        assert_eq!(json.get("missing"), &Json::Null);
+        assert_eq!(parse(&json.to_string()), Some(json));
+        assert_eq!(parse("{\"a\":}"), None);
+    }
+}
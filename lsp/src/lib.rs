@@ -0,0 +1,259 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, Result, Write},
+    panic::{catch_unwind, AssertUnwindSafe},
+    path::Path,
+};
+
+use analysis::{Document, Kind, Position};
+use json::Json;
+use nilang_types::nodes::Span;
+use rpc::{read_message, write_message};
+
+mod analysis;
+pub mod json;
+pub mod rpc;
+
+#[derive(Default)]
+struct Server {
+    documents: HashMap<Box<str>, Document>,
+    shutdown: bool,
+}
+
+pub fn serve<R: BufRead, W: Write>(mut input: R, mut output: W) -> Result<bool> {
+    colored::control::set_override(false);
+
+    let mut server = Server::default();
+    while let Some(message) = read_message(&mut input)? {
+        if message.get("method").as_str() == Some("exit") {
+            return Ok(server.shutdown);
+        }
+
+        for response in server.handle(&message) {
+            write_message(&mut output, &response)?;
+        }
+    }
+
+    Ok(server.shutdown)
+}
+
+impl Server {
+    fn handle(&mut self, message: &Json) -> Vec<Json> {
+        let method = message.get("method").as_str().unwrap_or_default();
+        let params = message.get("params");
+        let id = message.get("id");
+
+        let result = catch_unwind(AssertUnwindSafe(|| self.dispatch(method, params)));
+
+        let mut responses = Vec::new();
+        let result = match result {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(err)) => Err(err),
+            Err(_) => Err((
+                -32603,
+                format!("Internal error while handling `{}`", method),
+            )),
+        };
+
+        if let ("textDocument/didOpen" | "textDocument/didChange", Some(uri)) =
+            (method, params.get("textDocument").get("uri").as_str())
+        {
+            responses.push(self.publish_diagnostics(uri));
+        }
+
+        if *id != Json::Null {
+            responses.push(match result {
+                Ok(result) => Json::object([
+                    ("jsonrpc", "2.0".into()),
+                    ("id", id.clone()),
+                    ("result", result),
+                ]),
+                Err((code, message)) => Json::object([
+                    ("jsonrpc", "2.0".into()),
+                    ("id", id.clone()),
+                    (
+                        "error",
+                        Json::object([
+                            ("code", Json::Number(code as f64)),
+                            ("message", message.into()),
+                        ]),
+                    ),
+                ]),
+            });
+        }
+
+        responses
+    }
+
+    fn dispatch(
+        &mut self,
+        method: &str,
+        params: &Json,
+    ) -> std::result::Result<Json, (i64, String)> {
+        let uri = params.get("textDocument").get("uri").as_str();
+
+        Ok(match method {
+            "initialize" => Json::object([(
+                "capabilities",
+                Json::object([
+                    ("textDocumentSync", 1.into()),
+                    ("definitionProvider", true.into()),
+                    ("hoverProvider", true.into()),
+                    (
+                        "completionProvider",
+                        Json::object([("triggerCharacters", vec![".".into()].into())]),
+                    ),
+                ]),
+            )]),
+            "shutdown" => {
+                self.shutdown = true;
+                Json::Null
+            }
+            "textDocument/didOpen" => {
+                let text = params.get("textDocument").get("text").as_str();
+                if let (Some(uri), Some(text)) = (uri, text) {
+                    self.documents
+                        .insert(uri.into(), Document::new(text.into()));
+                }
+                Json::Null
+            }
+            "textDocument/didChange" => {
+                let changes = params.get("contentChanges").as_array();
+                let text = changes
+                    .last()
+                    .and_then(|change| change.get("text").as_str());
+                if let (Some(document), Some(text)) =
+                    (uri.and_then(|uri| self.documents.get_mut(uri)), text)
+                {
+                    document.update(text.into());
+                }
+                Json::Null
+            }
+            "textDocument/didClose" => {
+                if let Some(uri) = uri {
+                    self.documents.remove(uri);
+                }
+                Json::Null
+            }
+            "textDocument/definition" => {
+                let Some((document, position)) = self.position(params) else {
+                    return Ok(Json::Null);
+                };
+                document
+                    .definitions(position)
+                    .iter()
+                    .map(|definition| {
+                        Json::object([
+                            ("uri", uri.unwrap_or_default().into()),
+                            ("range", range(document, definition.span)),
+                        ])
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            }
+            "textDocument/hover" => {
+                let Some((document, position)) = self.position(params) else {
+                    return Ok(Json::Null);
+                };
+                match document.hover(position) {
+                    Some((span, detail)) => Json::object([
+                        (
+                            "contents",
+                            Json::object([
+                                ("kind", "markdown".into()),
+                                ("value", format!("```nilang\n{}\n```", detail).into()),
+                            ]),
+                        ),
+                        ("range", range(document, span)),
+                    ]),
+                    None => Json::Null,
+                }
+            }
+            "textDocument/completion" => {
+                let Some((document, position)) = self.position(params) else {
+                    return Ok(Json::Null);
+                };
+                document
+                    .completions(position)
+                    .into_iter()
+                    .map(|definition| {
+                        Json::object([
+                            ("label", (&*definition.name).into()),
+                            (
+                                "kind",
+                                match definition.kind {
+                                    Kind::Keyword => 14,
+                                    Kind::Function => 3,
+                                    Kind::Structure => 22,
+                                    Kind::Variable => 6,
+                                    Kind::Field => 5,
+                                }
+                                .into(),
+                            ),
+                            ("detail", definition.detail.into()),
+                        ])
+                    })
+                    .collect::<Vec<_>>()
+                    .into()
+            }
+            "initialized" | "$/cancelRequest" | "$/setTrace" => Json::Null,
+            method => Err((-32601, format!("Method not found: `{}`", method)))?,
+        })
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Json {
+        let diagnostics = self
+            .documents
+            .get(uri)
+            .map(|document| {
+                let directory = uri
+                    .strip_prefix("file://")
+                    .and_then(|path| Path::new(path).parent());
+                catch_unwind(AssertUnwindSafe(|| document.diagnostics(directory)))
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|(span, message)| {
+                        Json::object([
+                            ("range", range(document, span)),
+                            ("severity", 1.into()),
+                            ("source", "nilang".into()),
+                            ("message", message.into()),
+                        ])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        Json::object([
+            ("jsonrpc", "2.0".into()),
+            ("method", "textDocument/publishDiagnostics".into()),
+            (
+                "params",
+                Json::object([("uri", uri.into()), ("diagnostics", diagnostics.into())]),
+            ),
+        ])
+    }
+
+    fn position(&self, params: &Json) -> Option<(&Document, Position)> {
+        let document = self
+            .documents
+            .get(params.get("textDocument").get("uri").as_str()?)?;
+        let line = params.get("position").get("line").as_usize()?;
+        let character = params.get("position").get("character").as_usize()?;
+        Some((document, (line, document.column(line, character))))
+    }
+}
+
+fn range(document: &Document, Span { start, end }: Span) -> Json {
+    let position = |(line, column): Position| {
+        Json::object([
+            ("line", line.into()),
+            ("character", document.character(line, column).into()),
+        ])
+    };
+
+    Json::object([
+        ("start", position(start)),
+        ("end", position((end.0, end.1 + 1))),
+    ])
+}
@@ -0,0 +1,15 @@
+use std::{
+    io::{stdin, stdout},
+    process::exit,
+};
+
+fn main() {
+    match nilang_lsp::serve(stdin().lock(), stdout().lock()) {
+        Ok(true) => {}
+        Ok(false) => exit(1),
+        Err(err) => {
+            eprintln!("{}", err);
+            exit(1);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::io::{BufRead, Error, ErrorKind, Result, Write};
+
+use crate::json::{self, Json};
+
+pub fn read_message<R: BufRead>(input: &mut R) -> Result<Option<Json>> {
+    let mut length = None;
+    loop {
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            if length.is_some() {
+                break;
+            }
+            continue;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            if name.eq_ignore_ascii_case("Content-Length") {
+                length = value.trim().parse::<usize>().ok();
+            }
+        }
+    }
+
+    let mut content = vec![0; length.unwrap_or_default()];
+    input.read_exact(&mut content)?;
+
+    let content =
+        String::from_utf8(content).map_err(|err| Error::new(ErrorKind::InvalidData, err))?;
+    Ok(Some(json::parse(&content).unwrap_or(Json::Null)))
+}
+
+pub fn write_message<W: Write>(output: &mut W, message: &Json) -> Result<()> {
+    let content = message.to_string();
+    write!(
+        output,
+        "Content-Length: {}\r\n\r\n{}",
+        content.len(),
+        content
+    )?;
+    output.flush()
+}
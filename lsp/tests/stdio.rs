@@ -0,0 +1,144 @@
+use std::{
+    io::{BufReader, Write},
+    process::{Command, Stdio},
+};
+
+use nilang_lsp::{
+    json::Json,
+    rpc::{read_message, write_message},
+};
+
+fn request(id: usize, method: &str, params: Json) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("id", id.into()),
+        ("method", method.into()),
+        ("params", params),
+    ])
+}
+
+fn notification(method: &str, params: Json) -> Json {
+    Json::object([
+        ("jsonrpc", "2.0".into()),
+        ("method", method.into()),
+        ("params", params),
+    ])
+}
+
+fn position(line: usize, character: usize) -> Json {
+    Json::object([
+        (
+            "textDocument",
+            Json::object([("uri", "file:///main.ni".into())]),
+        ),
+        (
+            "position",
+            Json::object([("line", line.into()), ("character", character.into())]),
+        ),
+    ])
+}
+
+#[test]
+fn test_stdio_session() {
+    let mut server = Command::new(env!("CARGO_BIN_EXE_nilang-lsp"))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut input = server.stdin.take().unwrap();
+    let mut output = BufReader::new(server.stdout.take().unwrap());
+
+    write_message(&mut input, &request(1, "initialize", Json::object([]))).unwrap();
+    let response = read_message(&mut output).unwrap().unwrap();
+    assert_eq!(
+        response
+            .get("result")
+            .get("capabilities")
+            .get("definitionProvider"),
+        &Json::Bool(true)
+    );
+
+    let code =
+        "fn square(x: int): int {\n    rt x * x;\n}\n\nfn main(): int {\n    rt square(y);\n}\n";
+    write_message(
+        &mut input,
+        &notification(
+            "textDocument/didOpen",
+            Json::object([(
+                "textDocument",
+                Json::object([
+                    ("uri", "file:///main.ni".into()),
+                    ("languageId", "nilang".into()),
+                    ("version", 1.into()),
+                    ("text", code.into()),
+                ]),
+            )]),
+        ),
+    )
+    .unwrap();
+    let diagnostics = read_message(&mut output).unwrap().unwrap();
+    assert_eq!(
+        diagnostics.get("method").as_str(),
+        Some("textDocument/publishDiagnostics")
+    );
+    let diagnostics = diagnostics.get("params").get("diagnostics").as_array();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0]
+            .get("range")
+            .get("start")
+            .get("line")
+            .as_usize(),
+        Some(5)
+    );
+
+    write_message(
+        &mut input,
+        &request(2, "textDocument/definition", position(5, 8)),
+    )
+    .unwrap();
+    let response = read_message(&mut output).unwrap().unwrap();
+    let location = &response.get("result").as_array()[0];
+    assert_eq!(location.get("uri").as_str(), Some("file:///main.ni"));
+    assert_eq!(
+        location.get("range").get("start"),
+        &Json::object([("line", 0.into()), ("character", 3.into())])
+    );
+
+    write_message(
+        &mut input,
+        &request(3, "textDocument/hover", position(1, 7)),
+    )
+    .unwrap();
+    let response = read_message(&mut output).unwrap().unwrap();
+    assert_eq!(
+        response.get("result").get("contents").get("value").as_str(),
+        Some("```nilang\nx: int\n```")
+    );
+
+    write_message(
+        &mut input,
+        &request(4, "textDocument/completion", position(1, 7)),
+    )
+    .unwrap();
+    let response = read_message(&mut output).unwrap().unwrap();
+    let labels = response
+        .get("result")
+        .as_array()
+        .iter()
+        .filter_map(|item| item.get("label").as_str())
+        .collect::<Vec<_>>();
+    for label in [
+        "fn", "vr", "rt", "st", "if", "ef", "el", "wl", "x", "square", "main",
+    ] {
+        assert!(labels.contains(&label), "missing completion `{}`", label);
+    }
+
+    write_message(&mut input, &request(5, "shutdown", Json::Null)).unwrap();
+    let response = read_message(&mut output).unwrap().unwrap();
+    assert_eq!(response.get("id").as_usize(), Some(5));
+    write_message(&mut input, &notification("exit", Json::Null)).unwrap();
+    input.flush().unwrap();
+
+    assert!(server.wait().unwrap().success());
+}
@@ -0,0 +1,283 @@
+use std::{collections::HashMap, mem::take};
+
+use nilang_types::instructions::Instruction;
+
+type Temporary = Box<str>;
+type Function = (Box<str>, Vec<Instruction>);
+
+pub fn inline_functions(functions: &mut [Function], threshold: usize) {
+    if threshold == 0 {
+        return;
+    }
+
+    let indices = functions
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (name.clone(), i))
+        .collect::<HashMap<_, _>>();
+    let calls = functions
+        .iter()
+        .map(|(_, instructions)| {
+            instructions
+                .iter()
+                .filter_map(|instruction| match instruction {
+                    Instruction::FunctionCall(name, _, _) => indices.get(name).copied(),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    let recursive = (0..functions.len())
+        .map(|function| reaches(&calls, function, function))
+        .collect::<Vec<_>>();
+
+    let mut inlined = 0;
+    for function in postorder(&calls) {
+        let body = take(&mut functions[function].1);
+        let mut instructions = Vec::with_capacity(body.len());
+
+        for instruction in body {
+            let callee = match &instruction {
+                Instruction::FunctionCall(name, _, _) => indices
+                    .get(name)
+                    .copied()
+                    .filter(|callee| !recursive[*callee])
+                    .filter(|callee| size(&functions[*callee].1) <= threshold),
+                _ => None,
+            };
+
+            match (callee, instruction) {
+                (Some(callee), Instruction::FunctionCall(_, arguments, result)) => {
+                    instructions.extend(substitute(
+                        &functions[callee].1,
+                        &arguments,
+                        result.as_ref(),
+                        &format!("inline_{}", inlined),
+                    ));
+                    inlined += 1;
+                }
+                (_, instruction) => instructions.push(instruction),
+            }
+        }
+
+        functions[function].1 = instructions;
+    }
+}
+
+fn size(instructions: &[Instruction]) -> usize {
+    instructions
+        .iter()
+        .filter(|instruction| {
+            !matches!(instruction, Instruction::Declare(_) | Instruction::Label(_))
+        })
+        .count()
+}
+
+fn reaches(calls: &[Vec<usize>], from: usize, to: usize) -> bool {
+    let mut visited = vec![false; calls.len()];
+    let mut stack = calls[from].clone();
+    while let Some(function) = stack.pop() {
+        if function == to {
+            return true;
+        }
+        if !visited[function] {
+            visited[function] = true;
+            stack.extend(&calls[function]);
+        }
+    }
+    false
+}
+
+fn postorder(calls: &[Vec<usize>]) -> Vec<usize> {
+    let mut order = Vec::new();
+    let mut visited = vec![false; calls.len()];
+    for root in 0..calls.len() {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root, 0)];
+        while let Some((function, child)) = stack.pop() {
+            match calls[function].get(child) {
+                Some(&callee) => {
+                    stack.push((function, child + 1));
+                    if !visited[callee] {
+                        visited[callee] = true;
+                        stack.push((callee, 0));
+                    }
+                }
+                None => order.push(function),
+            }
+        }
+    }
+    order
+}
+
+fn substitute(
+    body: &[Instruction],
+    arguments: &[Temporary],
+    result: Option<&Temporary>,
+    prefix: &str,
+) -> Vec<Instruction> {
+    let rename = |name: &str| -> Box<str> { format!("{}.{}", prefix, name).into() };
+    let exit = rename("return");
+
+    let mut instructions = Vec::new();
+    for instruction in body {
+        let mut instruction = instruction.clone();
+        match &mut instruction {
+            Instruction::TakeArgument(index, temporary) => {
+                instructions.push(Instruction::Declare(rename(temporary)));
+                instructions.push(Instruction::Copy(
+                    rename(temporary),
+                    arguments[*index].clone(),
+                ));
+                continue;
+            }
+            Instruction::ReturnVariable(temporary) => {
+                if let Some(result) = result {
+                    instructions.push(Instruction::Copy(result.clone(), rename(temporary)));
+                }
+                instructions.push(Instruction::Jump(exit.clone()));
+                continue;
+            }
+            Instruction::Label(label)
+            | Instruction::Jump(label)
+            | Instruction::ConditionalJump(_, label) => *label = rename(label),
+            Instruction::Declare(temporary) => *temporary = rename(temporary),
+            _ => (),
+        }
+
+        for temporary in instruction.uses_mut() {
+            *temporary = rename(temporary);
+        }
+        if let Some(temporary) = instruction.definition_mut() {
+            *temporary = rename(temporary);
+        }
+        instructions.push(instruction);
+    }
+    instructions.push(Instruction::Label(exit));
+
+    instructions
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_interpreter::IrInterpreter;
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use super::inline_functions;
+
+    fn program() -> Vec<(Box<str>, Vec<Instruction>)> {
+        [
+            (
+                "main",
+                "declare n
+                n = num 5
+                declare squared
+                squared = call square(n)
+                declare total
+                total = call countdown(squared)
+                call printi(total)
+                ret total",
+            ),
+            (
+                "square",
+                "x = arg 0
+                declare temp_0
+                temp_0 = mul x, x
+                ret temp_0",
+            ),
+            (
+                "countdown",
+                "n = arg 0
+                declare zero
+                zero = num 0
+                declare done
+                done = eq n, zero
+                jz done, label_0
+                ret zero
+                label_0:
+                declare one
+                one = num 1
+                declare next
+                next = sub n, one
+                declare rest
+                rest = call countdown(next)
+                declare total
+                total = add rest, n
+                ret total",
+            ),
+        ]
+        .into_iter()
+        .map(|(name, code)| (name.into(), parse_instructions(code).unwrap()))
+        .collect()
+    }
+
+    fn run(functions: Vec<(Box<str>, Vec<Instruction>)>) -> (Option<i64>, String) {
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        for (name, instructions) in functions {
+            interpreter.declare_function(&name, instructions);
+        }
+        let result = interpreter.call("main", &[]).unwrap();
+        (
+            result,
+            String::from_utf8(interpreter.output().clone()).unwrap(),
+        )
+    }
+
+    fn calls(instructions: &[Instruction]) -> Vec<&str> {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::FunctionCall(name, _, _) => Some(name.as_ref()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_inline_small_functions() {
+        let mut functions = program();
+        inline_functions(&mut functions, 8);
+
+        assert_eq!(calls(&functions[0].1), ["countdown", "printi"]);
+        assert_eq!(calls(&functions[2].1), ["countdown"]);
+        assert!(functions[0]
+            .1
+            .contains(&Instruction::Copy("inline_0.x".into(), "n".into())));
+        assert_eq!(run(functions), run(program()));
+    }
+
+    #[test]
+    fn test_respect_threshold() {
+        let mut functions = program();
+        inline_functions(&mut functions, 2);
+        assert_eq!(functions, program());
+
+        inline_functions(&mut functions, 0);
+        assert_eq!(functions, program());
+    }
+
+    #[test]
+    fn test_rename_labels() {
+        let mut functions = program();
+        functions[2].1 = parse_instructions(
+            "n = arg 0
+            jz n, label_0
+            declare temp_0
+            temp_0 = num 1
+            ret temp_0
+            label_0:
+            ret n",
+        )
+        .unwrap();
+        inline_functions(&mut functions, 8);
+
+        assert_eq!(calls(&functions[0].1), ["printi"]);
+        assert!(functions[0]
+            .1
+            .contains(&Instruction::Label("inline_1.label_0".into())));
+        assert_eq!(run(functions).0, Some(1));
+    }
+}
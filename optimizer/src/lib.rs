@@ -0,0 +1,56 @@
+mod inline;
+mod passes;
+mod ssa;
+
+use std::str::FromStr;
+
+use nilang_types::instructions::Instruction;
+
+pub use inline::inline_functions;
+pub use passes::{
+    CommonSubexpressionElimination, ConstantFolding, CopyPropagation, DeadCodeElimination, Pass,
+    PassManager,
+};
+pub use ssa::{Block, BlockId, Function, Phi, Terminator};
+
+pub const INLINE_THRESHOLD: usize = 16;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OptimizationLevel {
+    #[default]
+    O0,
+    O1,
+    O2,
+}
+
+impl OptimizationLevel {
+    pub fn inline_threshold(self) -> usize {
+        match self {
+            OptimizationLevel::O0 | OptimizationLevel::O1 => 0,
+            OptimizationLevel::O2 => INLINE_THRESHOLD,
+        }
+    }
+}
+
+impl FromStr for OptimizationLevel {
+    type Err = Box<str>;
+
+    fn from_str(level: &str) -> Result<Self, Self::Err> {
+        match level {
+            "0" => Ok(OptimizationLevel::O0),
+            "1" => Ok(OptimizationLevel::O1),
+            "2" => Ok(OptimizationLevel::O2),
+            _ => Err(format!("Unknown optimization level `{}`", level).into()),
+        }
+    }
+}
+
+pub fn optimize(instructions: Vec<Instruction>, level: OptimizationLevel) -> Vec<Instruction> {
+    if level == OptimizationLevel::O0 {
+        return instructions;
+    }
+
+    let mut function = Function::from_instructions(instructions);
+    PassManager::for_level(level).run(&mut function);
+    function.into_instructions()
+}
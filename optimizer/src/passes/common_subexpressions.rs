@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+use nilang_types::instructions::Instruction;
+
+use crate::ssa::{BlockId, Function};
+
+use super::Pass;
+
+pub struct CommonSubexpressionElimination;
+
+impl Pass for CommonSubexpressionElimination {
+    fn run(&self, function: &mut Function) -> bool {
+        let children = function.dominator_tree();
+        let mut available = Vec::new();
+        eliminate(function, 0, &children, &mut available)
+    }
+}
+
+fn eliminate(
+    function: &mut Function,
+    block: BlockId,
+    children: &[Vec<BlockId>],
+    available: &mut Vec<HashMap<Box<str>, Box<str>>>,
+) -> bool {
+    let mut changed = false;
+    available.push(HashMap::new());
+
+    for instruction in &mut function.blocks[block].instructions {
+        let Some(key) = key(instruction) else {
+            continue;
+        };
+        let result = instruction.definition().unwrap().clone();
+
+        match available.iter().rev().find_map(|scope| scope.get(&key)) {
+            Some(existing) => {
+                *instruction = Instruction::Copy(result, existing.clone());
+                changed = true;
+            }
+            None => {
+                available.last_mut().unwrap().insert(key, result);
+            }
+        }
+    }
+
+    for &child in &children[block] {
+        changed |= eliminate(function, child, children, available);
+    }
+    available.pop();
+
+    changed
+}
+
+fn key(instruction: &Instruction) -> Option<Box<str>> {
+    let mut instruction = instruction.clone();
+    match &mut instruction {
+        Instruction::LoadBoolean(result, _)
+        | Instruction::LoadNumber(result, _)
+        | Instruction::LoadChar(result, _)
+        | Instruction::LoadStringLocation(result, _)
+        | Instruction::LoadFunctionLocation(result, _)
+        | Instruction::SubtractVariables(result, _, _)
+        | Instruction::TestLess(result, _, _)
+        | Instruction::TestMore(result, _, _)
+        | Instruction::TestLessOrEqual(result, _, _)
+        | Instruction::TestMoreOrEqual(result, _, _) => *result = "_".into(),
+        Instruction::AddVariables(result, a, b)
+        | Instruction::MultiplyVariables(result, a, b)
+        | Instruction::TestEqual(result, a, b)
+        | Instruction::TestNotEqual(result, a, b) => {
+            *result = "_".into();
+            if a > b {
+                std::mem::swap(a, b);
+            }
+        }
+        _ => return None,
+    }
+    Some(instruction.to_string().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use crate::{
+        passes::{CommonSubexpressionElimination, Pass},
+        ssa::Function,
+    };
+
+    #[test]
+    fn test_common_subexpression_elimination() {
+        let mut function = Function::from_instructions(
+            parse_instructions(
+                "a = arg 0
+                b = arg 1
+                declare x
+                x = add a, b
+                jz a, skip
+                declare y
+                y = add b, a
+                skip:
+                declare z
+                z = sub a, b
+                declare w
+                w = sub b, a
+                ret x",
+            )
+            .unwrap(),
+        );
+
+        assert!(CommonSubexpressionElimination.run(&mut function));
+        let instructions = function.into_instructions();
+        assert!(instructions.contains(&Instruction::Copy("y".into(), "x".into())));
+        assert!(!instructions.iter().any(
+            |instruction| matches!(instruction, Instruction::Copy(result, _) if &**result == "w")
+        ));
+        assert!(!CommonSubexpressionElimination.run(&mut Function::from_instructions(instructions)));
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use nilang_types::instructions::Instruction;
+
+use crate::ssa::{BlockId, Function, Terminator};
+
+use super::Pass;
+
+type Constants = HashMap<Box<str>, i64>;
+
+const EXACT: i64 = 1 << f64::MANTISSA_DIGITS;
+
+pub struct ConstantFolding;
+
+impl Pass for ConstantFolding {
+    fn run(&self, function: &mut Function) -> bool {
+        let children = function.dominator_tree();
+        fold_block(function, 0, &children, &mut HashMap::new())
+    }
+}
+
+fn fold_block(
+    function: &mut Function,
+    block: BlockId,
+    children: &[Vec<BlockId>],
+    constants: &mut Constants,
+) -> bool {
+    let mut changed = false;
+    let mut known = Vec::new();
+
+    for phi in &function.blocks[block].phis {
+        let mut values = phi
+            .sources
+            .iter()
+            .map(|(_, source)| constants.get(source).copied());
+        if let Some(Some(value)) = values.next() {
+            if values.all(|other| other == Some(value)) {
+                known.push((phi.result.clone(), value));
+            }
+        }
+    }
+    for (temporary, value) in &known {
+        constants.insert(temporary.clone(), *value);
+    }
+
+    for instruction in &mut function.blocks[block].instructions {
+        if let Some(folded) = fold(instruction, constants) {
+            *instruction = folded;
+            changed = true;
+        }
+        if let Some(value) = constant(instruction, constants) {
+            let temporary = instruction.definition().unwrap().clone();
+            constants.insert(temporary.clone(), value);
+            known.push((temporary, value));
+        }
+    }
+
+    if let Terminator::Branch(check, zero, other) = &function.blocks[block].terminator {
+        if let Some(value) = constants.get(check) {
+            let (taken, dropped) = match value {
+                0 => (*zero, *other),
+                _ => (*other, *zero),
+            };
+            if taken != dropped {
+                for phi in &mut function.blocks[dropped].phis {
+                    phi.sources.retain(|(predecessor, _)| *predecessor != block);
+                }
+            }
+            function.blocks[block].terminator = Terminator::Jump(taken);
+            changed = true;
+        }
+    }
+
+    for &child in &children[block] {
+        changed |= fold_block(function, child, children, constants);
+    }
+
+    for (temporary, _) in known {
+        constants.remove(&temporary);
+    }
+
+    changed
+}
+
+fn constant(instruction: &Instruction, constants: &Constants) -> Option<i64> {
+    match instruction {
+        Instruction::LoadBoolean(_, boolean) => Some(*boolean as i64),
+        Instruction::LoadNumber(_, number) => Some(*number as i64),
+        Instruction::LoadChar(_, char) => Some(*char as i64),
+        Instruction::Copy(_, source) => constants.get(source).copied(),
+        _ => None,
+    }
+}
+
+fn fold(instruction: &Instruction, constants: &Constants) -> Option<Instruction> {
+    let value = |temporary: &str| constants.get(temporary).copied();
+    let number = |result: &str, value: i64| {
+        (-EXACT..=EXACT)
+            .contains(&value)
+            .then(|| Instruction::LoadNumber(result.into(), value as f64))
+    };
+    let boolean = |result: &str, value: bool| Some(Instruction::LoadBoolean(result.into(), value));
+    let copy = |result: &str, source: &str| Some(Instruction::Copy(result.into(), source.into()));
+
+    match instruction {
+        Instruction::AddVariables(result, a, b) => match (value(a), value(b)) {
+            (Some(a), Some(b)) => number(result, a.wrapping_add(b)),
+            (Some(0), None) => copy(result, b),
+            (None, Some(0)) => copy(result, a),
+            _ => None,
+        },
+        Instruction::SubtractVariables(result, a, b) => match (value(a), value(b)) {
+            (Some(a), Some(b)) => number(result, a.wrapping_sub(b)),
+            (None, Some(0)) => copy(result, a),
+            _ => None,
+        },
+        Instruction::MultiplyVariables(result, a, b) => match (value(a), value(b)) {
+            (Some(a), Some(b)) => number(result, a.wrapping_mul(b)),
+            (Some(0), None) | (None, Some(0)) => number(result, 0),
+            (Some(1), None) => copy(result, b),
+            (None, Some(1)) => copy(result, a),
+            _ => None,
+        },
+        Instruction::DivideVariables(result, a, b) => match (value(a), value(b)) {
+            (_, Some(0)) => None,
+            (Some(a), Some(b)) => number(result, a.wrapping_div(b)),
+            (None, Some(1)) => copy(result, a),
+            _ => None,
+        },
+        Instruction::ModuloVariables(result, a, b) => match (value(a), value(b)) {
+            (_, Some(0)) => None,
+            (Some(a), Some(b)) => number(result, a.wrapping_rem(b)),
+            _ => None,
+        },
+        Instruction::TestEqual(result, a, b) => boolean(result, value(a)? == value(b)?),
+        Instruction::TestNotEqual(result, a, b) => boolean(result, value(a)? != value(b)?),
+        Instruction::TestLess(result, a, b) => boolean(result, value(a)? < value(b)?),
+        Instruction::TestMore(result, a, b) => boolean(result, value(a)? > value(b)?),
+        Instruction::TestLessOrEqual(result, a, b) => boolean(result, value(a)? <= value(b)?),
+        Instruction::TestMoreOrEqual(result, a, b) => boolean(result, value(a)? >= value(b)?),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_interpreter::IrInterpreter;
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use crate::{
+        passes::{ConstantFolding, DeadCodeElimination, Pass, PassManager},
+        ssa::Function,
+        OptimizationLevel,
+    };
+
+    fn optimized(code: &str) -> Vec<Instruction> {
+        let mut function = Function::from_instructions(parse_instructions(code).unwrap());
+        assert!(ConstantFolding.run(&mut function));
+        DeadCodeElimination.run(&mut function);
+        function.into_instructions()
+    }
+
+    #[test]
+    fn test_fold_arithmetic() {
+        assert_eq!(
+            optimized(
+                "declare a
+                a = num 6
+                declare b
+                b = copy a
+                declare c
+                c = num 7
+                declare d
+                d = mul b, c
+                declare e
+                e = lt a, d
+                jz e, small
+                ret d
+                small:
+                ret a"
+            ),
+            parse_instructions("d = num 42\nret d").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_fold_identities() {
+        assert_eq!(
+            optimized(
+                "x = arg 0
+                declare zero
+                zero = num 0
+                declare one
+                one = num 1
+                declare a
+                a = add x, zero
+                declare b
+                b = mul one, a
+                declare c
+                c = mul b, zero
+                declare d
+                d = div x, zero
+                declare e
+                e = sub c, d
+                ret e"
+            ),
+            parse_instructions(
+                "x = arg 0
+                zero = num 0
+                c = num 0
+                d = div x, zero
+                e = sub c, d
+                ret e"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_remove_dead_branches() {
+        let mut function = Function::from_instructions(
+            parse_instructions(
+                "declare x
+                x = num 1
+                declare condition
+                condition = bool true
+                jz condition, else
+                x = num 2
+                jmp end
+                else:
+                x = num 3
+                end:
+                declare falsy
+                falsy = bool false
+                loop:
+                jz falsy, done
+                x = num 4
+                jmp loop
+                done:
+                ret x",
+            )
+            .unwrap(),
+        );
+        PassManager::for_level(OptimizationLevel::O1).run(&mut function);
+        let instructions = function.into_instructions();
+
+        assert!(!instructions
+            .iter()
+            .any(|instruction| matches!(instruction, Instruction::ConditionalJump(..))));
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        interpreter.declare_function("f", instructions);
+        assert_eq!(interpreter.call("f", &[]).unwrap(), Some(2));
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::{HashMap, HashSet};
+
+use nilang_types::instructions::Instruction;
+
+use crate::ssa::{BlockId, Function};
+
+use super::Pass;
+
+type Temporary = Box<str>;
+
+pub struct CopyPropagation;
+
+impl Pass for CopyPropagation {
+    fn run(&self, function: &mut Function) -> bool {
+        let defined = function
+            .blocks
+            .iter()
+            .flat_map(|block| {
+                block.phis.iter().map(|phi| &phi.result).chain(
+                    block
+                        .instructions
+                        .iter()
+                        .filter_map(Instruction::definition),
+                )
+            })
+            .cloned()
+            .collect();
+        let mut propagation = Propagation {
+            defined,
+            dominating: HashSet::new(),
+            copies: HashMap::new(),
+        };
+        propagation.propagate(function, 0, &function.dominator_tree())
+    }
+}
+
+struct Propagation {
+    defined: HashSet<Temporary>,
+    dominating: HashSet<Temporary>,
+    copies: HashMap<Temporary, Temporary>,
+}
+
+impl Propagation {
+    fn available(&self, temporary: &Temporary) -> bool {
+        self.dominating.contains(temporary) || !self.defined.contains(temporary)
+    }
+
+    fn resolve(&self, temporary: &Temporary) -> Option<Temporary> {
+        self.copies.get(temporary).cloned()
+    }
+
+    fn propagate(
+        &mut self,
+        function: &mut Function,
+        block: BlockId,
+        children: &[Vec<BlockId>],
+    ) -> bool {
+        let mut changed = false;
+        let mut copies = Vec::new();
+        let mut definitions = Vec::new();
+
+        for phi in &function.blocks[block].phis {
+            let mut sources = phi
+                .sources
+                .iter()
+                .map(|(_, source)| self.resolve(source).unwrap_or_else(|| source.clone()))
+                .filter(|source| *source != phi.result);
+            match sources.next() {
+                Some(source) if sources.all(|other| other == source) && self.available(&source) => {
+                    copies.push(phi.result.clone());
+                    self.copies.insert(phi.result.clone(), source);
+                }
+                _ => (),
+            }
+            definitions.push(phi.result.clone());
+        }
+        self.dominating.extend(definitions.iter().cloned());
+
+        for instruction in &mut function.blocks[block].instructions {
+            for temporary in instruction.uses_mut() {
+                if let Some(source) = self.resolve(temporary) {
+                    *temporary = source;
+                    changed = true;
+                }
+            }
+            if let Instruction::Copy(result, source) = instruction {
+                if self.available(source) {
+                    copies.push(result.clone());
+                    self.copies.insert(result.clone(), source.clone());
+                }
+            }
+            if let Some(temporary) = instruction.definition() {
+                definitions.push(temporary.clone());
+                self.dominating.insert(temporary.clone());
+            }
+        }
+
+        if let Some(temporary) = function.blocks[block].terminator.used_mut() {
+            if let Some(source) = self.resolve(temporary) {
+                *temporary = source;
+                changed = true;
+            }
+        }
+
+        for successor in function.blocks[block].terminator.successors() {
+            for phi in &mut function.blocks[successor].phis {
+                for (_, source) in phi
+                    .sources
+                    .iter_mut()
+                    .filter(|(predecessor, _)| *predecessor == block)
+                {
+                    if let Some(resolved) = self.resolve(source) {
+                        *source = resolved;
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        for &child in &children[block] {
+            changed |= self.propagate(function, child, children);
+        }
+
+        for temporary in copies {
+            self.copies.remove(&temporary);
+        }
+        for temporary in definitions {
+            self.dominating.remove(&temporary);
+        }
+
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use crate::{
+        passes::{CopyPropagation, DeadCodeElimination, Pass},
+        ssa::Function,
+    };
+
+    fn propagated(code: &str) -> Vec<Instruction> {
+        let mut function = Function::from_instructions(parse_instructions(code).unwrap());
+        CopyPropagation.run(&mut function);
+        DeadCodeElimination.run(&mut function);
+        function.into_instructions()
+    }
+
+    #[test]
+    fn test_propagate_copies() {
+        assert_eq!(
+            propagated(
+                "x = arg 0
+                declare temp_0
+                temp_0 = copy x
+                declare temp_1
+                temp_1 = copy temp_0
+                declare temp_2
+                temp_2 = add temp_1, temp_0
+                ret temp_2"
+            ),
+            parse_instructions(
+                "x = arg 0
+                temp_2 = add x, x
+                ret temp_2"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_propagate_through_phis() {
+        assert_eq!(
+            propagated(
+                "x = arg 0
+                declare y
+                y = copy x
+                jz x, skip
+                y = copy x
+                skip:
+                ret y"
+            ),
+            parse_instructions(
+                "x = arg 0
+                jz x, skip
+                skip:
+                ret x"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_keep_copies_of_later_definitions() {
+        let code = "declare n
+            n = num 3
+            declare one
+            one = num 1
+            loop:
+            declare previous
+            previous = copy step
+            jz n, end
+            declare step
+            step = num 5
+            n = sub n, one
+            jmp loop
+            end:
+            ret previous";
+
+        assert!(!CopyPropagation.run(&mut Function::from_instructions(
+            parse_instructions(code).unwrap()
+        )));
+    }
+}
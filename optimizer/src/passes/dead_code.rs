@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use nilang_types::instructions::Instruction;
+
+use crate::ssa::Function;
+
+use super::Pass;
+
+pub struct DeadCodeElimination;
+
+impl Pass for DeadCodeElimination {
+    fn run(&self, function: &mut Function) -> bool {
+        let mut changed = function.remove_unreachable();
+
+        loop {
+            let used = function
+                .used_temporaries()
+                .into_iter()
+                .map(Box::from)
+                .collect::<HashSet<Box<str>>>();
+            let mut removed = false;
+
+            for block in &mut function.blocks {
+                let phis = block.phis.len();
+                block.phis.retain(|phi| used.contains(&phi.result));
+                removed |= phis != block.phis.len();
+
+                for instruction in &mut block.instructions {
+                    if let Instruction::FunctionCall(_, _, result @ Some(_))
+                    | Instruction::IndirectFunctionCall(_, _, result @ Some(_)) = instruction
+                    {
+                        if !used.contains(result.as_ref().unwrap()) {
+                            *result = None;
+                            removed = true;
+                        }
+                    }
+                }
+
+                let instructions = block.instructions.len();
+                block.instructions.retain(|instruction| {
+                    !is_pure(instruction)
+                        || instruction
+                            .definition()
+                            .is_some_and(|temporary| used.contains(temporary))
+                });
+                removed |= instructions != block.instructions.len();
+            }
+
+            if !removed {
+                return changed;
+            }
+            changed = true;
+        }
+    }
+}
+
+fn is_pure(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::TakeArgument(..)
+            | Instruction::LoadBoolean(..)
+            | Instruction::LoadNumber(..)
+            | Instruction::LoadChar(..)
+            | Instruction::LoadStringLocation(..)
+            | Instruction::LoadFunctionLocation(..)
+            | Instruction::LoadGlobal(..)
+            | Instruction::LoadFromAddress(..)
+            | Instruction::Copy(..)
+            | Instruction::AddVariables(..)
+            | Instruction::SubtractVariables(..)
+            | Instruction::MultiplyVariables(..)
+            | Instruction::TestEqual(..)
+            | Instruction::TestNotEqual(..)
+            | Instruction::TestLess(..)
+            | Instruction::TestMore(..)
+            | Instruction::TestLessOrEqual(..)
+            | Instruction::TestMoreOrEqual(..)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use crate::{
+        passes::{DeadCodeElimination, Pass},
+        ssa::Function,
+    };
+
+    #[test]
+    fn test_dead_code_elimination() {
+        let mut function = Function::from_instructions(
+            parse_instructions(
+                "x = arg 0
+                declare unused
+                unused = num 5
+                declare twice
+                twice = add unused, x
+                declare quotient
+                quotient = div x, x
+                declare ignored
+                ignored = call f(x)
+                ret x",
+            )
+            .unwrap(),
+        );
+
+        assert!(DeadCodeElimination.run(&mut function));
+        assert_eq!(
+            function.into_instructions(),
+            parse_instructions(
+                "x = arg 0
+                declare quotient
+                quotient = div x, x
+                call f(x)
+                ret x"
+            )
+            .unwrap()
+            .into_iter()
+            .filter(|instruction| !matches!(instruction, Instruction::Declare(_)))
+            .collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,53 @@
+mod common_subexpressions;
+mod constant_folding;
+mod copy_propagation;
+mod dead_code;
+
+pub use common_subexpressions::CommonSubexpressionElimination;
+pub use constant_folding::ConstantFolding;
+pub use copy_propagation::CopyPropagation;
+pub use dead_code::DeadCodeElimination;
+
+use crate::{ssa::Function, OptimizationLevel};
+
+const MAX_ROUNDS: usize = 16;
+
+pub trait Pass {
+    fn run(&self, function: &mut Function) -> bool;
+}
+
+#[derive(Default)]
+pub struct PassManager(Vec<Box<dyn Pass>>);
+
+impl PassManager {
+    pub fn for_level(level: OptimizationLevel) -> Self {
+        let mut manager = PassManager::default();
+        if level >= OptimizationLevel::O1 {
+            manager.add(ConstantFolding).add(CopyPropagation);
+        }
+        if level >= OptimizationLevel::O2 {
+            manager.add(CommonSubexpressionElimination);
+        }
+        if level >= OptimizationLevel::O1 {
+            manager.add(DeadCodeElimination);
+        }
+        manager
+    }
+
+    pub fn add(&mut self, pass: impl Pass + 'static) -> &mut Self {
+        self.0.push(Box::new(pass));
+        self
+    }
+
+    pub fn run(&self, function: &mut Function) {
+        for _ in 0..MAX_ROUNDS {
+            let mut changed = false;
+            for pass in &self.0 {
+                changed |= pass.run(function);
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+}
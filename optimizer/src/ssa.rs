@@ -0,0 +1,683 @@
+use std::{
+    collections::{HashMap, HashSet},
+    mem::take,
+};
+
+use nilang_types::instructions::Instruction;
+
+type Temporary = Box<str>;
+type Label = Box<str>;
+pub type BlockId = usize;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Terminator {
+    Jump(BlockId),
+    Branch(Temporary, BlockId, BlockId),
+    Return(Temporary),
+    Exit,
+}
+
+impl Terminator {
+    pub fn successors(&self) -> Vec<BlockId> {
+        match self {
+            Terminator::Jump(target) => vec![*target],
+            Terminator::Branch(_, zero, other) if zero == other => vec![*zero],
+            Terminator::Branch(_, zero, other) => vec![*zero, *other],
+            Terminator::Return(_) | Terminator::Exit => Vec::new(),
+        }
+    }
+
+    pub fn used(&self) -> Option<&str> {
+        match self {
+            Terminator::Branch(check, _, _) | Terminator::Return(check) => Some(check),
+            Terminator::Jump(_) | Terminator::Exit => None,
+        }
+    }
+
+    pub fn used_mut(&mut self) -> Option<&mut Temporary> {
+        match self {
+            Terminator::Branch(check, _, _) | Terminator::Return(check) => Some(check),
+            Terminator::Jump(_) | Terminator::Exit => None,
+        }
+    }
+
+    fn targets_mut(&mut self) -> Vec<&mut BlockId> {
+        match self {
+            Terminator::Jump(target) => vec![target],
+            Terminator::Branch(_, zero, other) => vec![zero, other],
+            Terminator::Return(_) | Terminator::Exit => Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Phi {
+    pub result: Temporary,
+    pub sources: Vec<(BlockId, Temporary)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Block {
+    pub label: Option<Label>,
+    pub phis: Vec<Phi>,
+    pub instructions: Vec<Instruction>,
+    pub terminator: Terminator,
+}
+
+impl Block {
+    fn new(label: Option<Label>, instructions: Vec<Instruction>, terminator: Terminator) -> Self {
+        Block {
+            label,
+            phis: Vec::new(),
+            instructions,
+            terminator,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Function {
+    pub blocks: Vec<Block>,
+}
+
+impl Function {
+    pub fn from_instructions(instructions: Vec<Instruction>) -> Self {
+        let mut function = Function {
+            blocks: basic_blocks(instructions),
+        };
+        function.remove_unreachable();
+        function.construct_ssa();
+        function
+    }
+
+    pub fn into_instructions(mut self) -> Vec<Instruction> {
+        self.split_critical_edges();
+        self.eliminate_phis();
+
+        let count = self.blocks.len();
+        let mut targeted = vec![false; count];
+        let mut exits = false;
+        for (i, block) in self.blocks.iter().enumerate() {
+            match &block.terminator {
+                Terminator::Jump(target) if *target != i + 1 => targeted[*target] = true,
+                Terminator::Branch(_, zero, other) => {
+                    targeted[*zero] = true;
+                    targeted[*other] |= *other != i + 1;
+                }
+                Terminator::Exit => exits |= i + 1 != count,
+                _ => (),
+            }
+        }
+        let label = |i: BlockId| -> Label {
+            self.blocks[i]
+                .label
+                .clone()
+                .unwrap_or_else(|| format!("block_{}", i).into())
+        };
+
+        let mut instructions = self
+            .undefined()
+            .into_iter()
+            .map(Instruction::Declare)
+            .collect::<Vec<_>>();
+        for (i, block) in self.blocks.iter().enumerate() {
+            if targeted[i] {
+                instructions.push(Instruction::Label(label(i)));
+            }
+            instructions.extend(block.instructions.iter().cloned());
+            match &block.terminator {
+                Terminator::Jump(target) if *target == i + 1 => (),
+                Terminator::Jump(target) => instructions.push(Instruction::Jump(label(*target))),
+                Terminator::Branch(check, zero, other) => {
+                    instructions.push(Instruction::ConditionalJump(check.clone(), label(*zero)));
+                    if *other != i + 1 {
+                        instructions.push(Instruction::Jump(label(*other)));
+                    }
+                }
+                Terminator::Return(temporary) => {
+                    instructions.push(Instruction::ReturnVariable(temporary.clone()))
+                }
+                Terminator::Exit if i + 1 == count => (),
+                Terminator::Exit => instructions.push(Instruction::Jump("exit".into())),
+            }
+        }
+        if exits {
+            instructions.push(Instruction::Label("exit".into()));
+        }
+
+        instructions
+    }
+
+    pub fn predecessors(&self) -> Vec<Vec<BlockId>> {
+        let mut predecessors = vec![Vec::new(); self.blocks.len()];
+        for (i, block) in self.blocks.iter().enumerate() {
+            for successor in block.terminator.successors() {
+                predecessors[successor].push(i);
+            }
+        }
+        predecessors
+    }
+
+    pub fn used_temporaries(&self) -> HashSet<&str> {
+        self.blocks
+            .iter()
+            .flat_map(|block| {
+                block
+                    .phis
+                    .iter()
+                    .flat_map(|phi| phi.sources.iter().map(|(_, source)| source.as_ref()))
+                    .chain(
+                        block
+                            .instructions
+                            .iter()
+                            .flat_map(|instruction| instruction.uses())
+                            .map(|temporary| temporary.as_ref()),
+                    )
+                    .chain(block.terminator.used())
+            })
+            .collect()
+    }
+
+    pub fn remove_unreachable(&mut self) -> bool {
+        let mut reachable = vec![false; self.blocks.len()];
+        let mut stack = vec![0];
+        while let Some(block) = stack.pop() {
+            if !reachable[block] {
+                reachable[block] = true;
+                stack.extend(self.blocks[block].terminator.successors());
+            }
+        }
+        if reachable.iter().all(|reachable| *reachable) {
+            return false;
+        }
+
+        let mut ids = vec![None; self.blocks.len()];
+        for (id, block) in (0..self.blocks.len())
+            .filter(|block| reachable[*block])
+            .enumerate()
+        {
+            ids[block] = Some(id);
+        }
+
+        self.blocks = take(&mut self.blocks)
+            .into_iter()
+            .zip(reachable)
+            .filter(|(_, reachable)| *reachable)
+            .map(|(mut block, _)| {
+                for target in block.terminator.targets_mut() {
+                    *target = ids[*target].unwrap();
+                }
+                for phi in &mut block.phis {
+                    phi.sources = take(&mut phi.sources)
+                        .into_iter()
+                        .filter_map(|(predecessor, source)| Some((ids[predecessor]?, source)))
+                        .collect();
+                }
+                block
+            })
+            .collect();
+        true
+    }
+
+    pub fn dominators(&self) -> Vec<BlockId> {
+        let order = self.reverse_postorder();
+        let mut position = vec![usize::MAX; self.blocks.len()];
+        for (i, block) in order.iter().enumerate() {
+            position[*block] = i;
+        }
+        let predecessors = self.predecessors();
+
+        let mut dominators = vec![None; self.blocks.len()];
+        dominators[0] = Some(0);
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &block in &order[1..] {
+                let mut dominator = None;
+                for &predecessor in &predecessors[block] {
+                    if dominators[predecessor].is_none() {
+                        continue;
+                    }
+                    dominator = Some(match dominator {
+                        None => predecessor,
+                        Some(other) => intersect(&dominators, &position, predecessor, other),
+                    });
+                }
+                if dominator != dominators[block] {
+                    dominators[block] = dominator;
+                    changed = true;
+                }
+            }
+        }
+
+        dominators
+            .into_iter()
+            .map(|dominator| dominator.unwrap_or(0))
+            .collect()
+    }
+
+    pub fn dominator_tree(&self) -> Vec<Vec<BlockId>> {
+        let mut children = vec![Vec::new(); self.blocks.len()];
+        for (block, dominator) in self.dominators().into_iter().enumerate().skip(1) {
+            children[dominator].push(block);
+        }
+        children
+    }
+
+    fn reverse_postorder(&self) -> Vec<BlockId> {
+        let mut order = Vec::new();
+        let mut visited = vec![false; self.blocks.len()];
+        visited[0] = true;
+        let mut stack = vec![(0, 0)];
+        while let Some((block, child)) = stack.pop() {
+            match self.blocks[block].terminator.successors().get(child) {
+                Some(&successor) => {
+                    stack.push((block, child + 1));
+                    if !visited[successor] {
+                        visited[successor] = true;
+                        stack.push((successor, 0));
+                    }
+                }
+                None => order.push(block),
+            }
+        }
+        order.reverse();
+        order
+    }
+
+    fn dominance_frontiers(&self, dominators: &[BlockId]) -> Vec<HashSet<BlockId>> {
+        let mut frontiers = vec![HashSet::new(); self.blocks.len()];
+        for (block, predecessors) in self.predecessors().into_iter().enumerate() {
+            if predecessors.len() < 2 {
+                continue;
+            }
+            for mut runner in predecessors {
+                while runner != dominators[block] {
+                    frontiers[runner].insert(block);
+                    if runner == dominators[runner] {
+                        break;
+                    }
+                    runner = dominators[runner];
+                }
+            }
+        }
+        frontiers
+    }
+
+    fn construct_ssa(&mut self) {
+        let dominators = self.dominators();
+        let frontiers = self.dominance_frontiers(&dominators);
+
+        let mut definitions = HashMap::<Temporary, Vec<BlockId>>::new();
+        for (i, block) in self.blocks.iter().enumerate() {
+            for temporary in block
+                .instructions
+                .iter()
+                .filter_map(Instruction::definition)
+            {
+                definitions.entry(temporary.clone()).or_default().push(i);
+            }
+        }
+        let mut redefined = definitions
+            .iter()
+            .filter(|(_, blocks)| blocks.len() > 1)
+            .map(|(temporary, _)| temporary.clone())
+            .collect::<Vec<_>>();
+        redefined.sort();
+
+        for temporary in &redefined {
+            let mut work = definitions[temporary].clone();
+            let mut placed = HashSet::new();
+            while let Some(block) = work.pop() {
+                let mut frontier = frontiers[block].iter().copied().collect::<Vec<_>>();
+                frontier.sort();
+                for block in frontier {
+                    if placed.insert(block) {
+                        self.blocks[block].phis.push(Phi {
+                            result: temporary.clone(),
+                            sources: Vec::new(),
+                        });
+                        work.push(block);
+                    }
+                }
+            }
+        }
+
+        let mut renamer = Renamer {
+            originals: self
+                .blocks
+                .iter()
+                .map(|block| block.phis.iter().map(|phi| phi.result.clone()).collect())
+                .collect(),
+            redefined: redefined.into_iter().collect(),
+            versions: HashMap::new(),
+            stacks: HashMap::new(),
+        };
+        renamer.rename(self, 0, &self.dominator_tree());
+    }
+
+    fn split_critical_edges(&mut self) {
+        for (block, predecessors) in self.predecessors().into_iter().enumerate() {
+            if self.blocks[block].phis.is_empty() || predecessors.len() < 2 {
+                continue;
+            }
+            for predecessor in predecessors {
+                if self.blocks[predecessor].terminator.successors().len() < 2 {
+                    continue;
+                }
+
+                let split = self.blocks.len();
+                self.blocks
+                    .push(Block::new(None, Vec::new(), Terminator::Jump(block)));
+                for target in self.blocks[predecessor].terminator.targets_mut() {
+                    if *target == block {
+                        *target = split;
+                    }
+                }
+                for phi in &mut self.blocks[block].phis {
+                    for (source, _) in &mut phi.sources {
+                        if *source == predecessor {
+                            *source = split;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn eliminate_phis(&mut self) {
+        for block in 0..self.blocks.len() {
+            let mut copies = HashMap::<BlockId, Vec<(Temporary, Temporary)>>::new();
+            for phi in take(&mut self.blocks[block].phis) {
+                for (predecessor, source) in phi.sources {
+                    copies
+                        .entry(predecessor)
+                        .or_default()
+                        .push((phi.result.clone(), source));
+                }
+            }
+            for (predecessor, copies) in copies {
+                self.blocks[predecessor]
+                    .instructions
+                    .extend(sequentialize(copies));
+            }
+        }
+    }
+
+    fn undefined(&self) -> Vec<Temporary> {
+        let defined = self
+            .blocks
+            .iter()
+            .flat_map(|block| &block.instructions)
+            .filter_map(Instruction::definition)
+            .map(|temporary| temporary.as_ref())
+            .collect::<HashSet<_>>();
+        let mut undefined = self
+            .used_temporaries()
+            .into_iter()
+            .filter(|temporary| !defined.contains(temporary))
+            .map(Box::from)
+            .collect::<Vec<_>>();
+        undefined.sort();
+        undefined
+    }
+}
+
+struct Renamer {
+    originals: Vec<Vec<Temporary>>,
+    redefined: HashSet<Temporary>,
+    versions: HashMap<Temporary, usize>,
+    stacks: HashMap<Temporary, Vec<Temporary>>,
+}
+
+impl Renamer {
+    fn fresh(&mut self, temporary: &Temporary) -> Temporary {
+        let version = self.versions.entry(temporary.clone()).or_default();
+        *version += 1;
+        let name: Temporary = format!("{}.{}", temporary, version).into();
+        self.stacks
+            .entry(temporary.clone())
+            .or_default()
+            .push(name.clone());
+        name
+    }
+
+    fn current(&self, temporary: &Temporary) -> Temporary {
+        self.stacks
+            .get(temporary)
+            .and_then(|stack| stack.last())
+            .unwrap_or(temporary)
+            .clone()
+    }
+
+    fn rename(&mut self, function: &mut Function, block: BlockId, children: &[Vec<BlockId>]) {
+        let mut pushed = self.originals[block].clone();
+        for (phi, original) in function.blocks[block]
+            .phis
+            .iter_mut()
+            .zip(&self.originals[block].clone())
+        {
+            phi.result = self.fresh(original);
+        }
+
+        for instruction in &mut function.blocks[block].instructions {
+            for temporary in instruction.uses_mut() {
+                if self.redefined.contains(temporary) {
+                    *temporary = self.current(temporary);
+                }
+            }
+            if let Some(temporary) = instruction.definition_mut() {
+                if self.redefined.contains(temporary) {
+                    pushed.push(temporary.clone());
+                    *temporary = self.fresh(temporary);
+                }
+            }
+        }
+        if let Some(temporary) = function.blocks[block].terminator.used_mut() {
+            if self.redefined.contains(temporary) {
+                *temporary = self.current(temporary);
+            }
+        }
+
+        for successor in function.blocks[block].terminator.successors() {
+            for (phi, original) in function.blocks[successor]
+                .phis
+                .iter_mut()
+                .zip(&self.originals[successor])
+            {
+                phi.sources.push((block, self.current(original)));
+            }
+        }
+
+        for &child in &children[block] {
+            self.rename(function, child, children);
+        }
+
+        for temporary in pushed {
+            self.stacks.get_mut(&temporary).unwrap().pop();
+        }
+    }
+}
+
+fn basic_blocks(instructions: Vec<Instruction>) -> Vec<Block> {
+    let mut blocks = vec![(None, Vec::new(), None)];
+    for instruction in instructions {
+        match instruction {
+            Instruction::Label(label) => blocks.push((Some(label), Vec::new(), None)),
+            Instruction::Declare(_) => (),
+            Instruction::Jump(_)
+            | Instruction::ConditionalJump(_, _)
+            | Instruction::ReturnVariable(_) => {
+                blocks.last_mut().unwrap().2 = Some(instruction);
+                blocks.push((None, Vec::new(), None));
+            }
+            instruction => blocks.last_mut().unwrap().1.push(instruction),
+        }
+    }
+
+    let labels = blocks
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (label, _, _))| Some((label.clone()?, i)))
+        .collect::<HashMap<Label, BlockId>>();
+
+    let exit = blocks.len();
+    blocks
+        .into_iter()
+        .enumerate()
+        .map(|(i, (label, instructions, terminator))| {
+            let terminator = match terminator {
+                Some(Instruction::Jump(target)) => Terminator::Jump(labels[&target]),
+                Some(Instruction::ConditionalJump(check, target)) => {
+                    Terminator::Branch(check, labels[&target], i + 1)
+                }
+                Some(Instruction::ReturnVariable(temporary)) => Terminator::Return(temporary),
+                _ => Terminator::Jump(i + 1),
+            };
+            Block::new(label, instructions, terminator)
+        })
+        .chain([Block::new(None, Vec::new(), Terminator::Exit)])
+        .take(exit + 1)
+        .collect()
+}
+
+fn intersect(
+    dominators: &[Option<BlockId>],
+    position: &[usize],
+    mut a: BlockId,
+    mut b: BlockId,
+) -> BlockId {
+    while a != b {
+        while position[a] > position[b] {
+            a = dominators[a].unwrap();
+        }
+        while position[b] > position[a] {
+            b = dominators[b].unwrap();
+        }
+    }
+    a
+}
+
+fn sequentialize(copies: Vec<(Temporary, Temporary)>) -> Vec<Instruction> {
+    let copies = copies
+        .into_iter()
+        .filter(|(to, from)| to != from)
+        .collect::<Vec<_>>();
+    let overlapping = copies
+        .iter()
+        .any(|(_, from)| copies.iter().any(|(to, _)| to == from));
+
+    if !overlapping {
+        return copies
+            .into_iter()
+            .map(|(to, from)| Instruction::Copy(to, from))
+            .collect();
+    }
+
+    let staged = |to: &Temporary| -> Temporary { format!("{}.phi", to).into() };
+    copies
+        .iter()
+        .map(|(to, from)| Instruction::Copy(staged(to), from.clone()))
+        .chain(
+            copies
+                .iter()
+                .map(|(to, _)| Instruction::Copy(to.clone(), staged(to))),
+        )
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_interpreter::IrInterpreter;
+    use nilang_types::instructions::{parse_instructions, Instruction};
+
+    use super::{Function, Phi, Terminator};
+
+    const LOOP: &str = "n = arg 0
+        declare total
+        total = num 0
+        declare one
+        one = num 1
+        loop:
+        declare done
+        done = copy n
+        jz done, end
+        total = add total, n
+        n = sub n, one
+        jmp loop
+        end:
+        ret total";
+
+    fn run(instructions: Vec<Instruction>, argument: i64) -> Option<i64> {
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        interpreter.declare_function("f", instructions);
+        interpreter.call("f", &[argument]).unwrap()
+    }
+
+    #[test]
+    fn test_construct_ssa() {
+        let function = Function::from_instructions(parse_instructions(LOOP).unwrap());
+
+        let header = function
+            .blocks
+            .iter()
+            .find(|block| block.label.as_deref() == Some("loop"))
+            .unwrap();
+        assert_eq!(
+            header.phis,
+            [
+                Phi {
+                    result: "n.2".into(),
+                    sources: vec![(0, "n.1".into()), (2, "n.3".into())],
+                },
+                Phi {
+                    result: "total.2".into(),
+                    sources: vec![(0, "total.1".into()), (2, "total.3".into())],
+                },
+            ]
+        );
+        assert_eq!(header.terminator, Terminator::Branch("done".into(), 3, 2));
+        assert_eq!(function.dominators(), [0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let instructions = parse_instructions(LOOP).unwrap();
+        let lowered = Function::from_instructions(instructions.clone()).into_instructions();
+
+        for argument in 0..5 {
+            assert_eq!(
+                run(lowered.clone(), argument),
+                run(instructions.clone(), argument)
+            );
+        }
+    }
+
+    #[test]
+    fn test_round_trip_swap() {
+        let instructions = parse_instructions(
+            "declare a
+            a = num 1
+            declare b
+            b = num 2
+            declare n
+            n = num 3
+            declare one
+            one = num 1
+            loop:
+            jz n, end
+            declare t
+            t = copy a
+            a = copy b
+            b = copy t
+            n = sub n, one
+            jmp loop
+            end:
+            ret a",
+        )
+        .unwrap();
+        let lowered = Function::from_instructions(instructions.clone()).into_instructions();
+
+        assert_eq!(run(lowered, 0), Some(2));
+    }
+}
@@ -1,70 +1,336 @@
-use std::usize;
-
 use errors::{CodeLocation, NilangError, ParserErrors};
 use nilang_types::{
-    nodes::statements::{FunctionDeclaration, StructureDeclaration},
+    nodes::{
+        expressions::ExpressionNode,
+        statements::{
+            FunctionDeclaration, GlobalDeclaration, ModulePath, StatementNode, StructureDeclaration,
+        },
+    },
     tokens::{Keyword, Token, TokenType},
 };
 
-use crate::{assuming_iterator::PeekableAssumingIterator, multi_peekable::MultiPeekable};
+use crate::{
+    assuming_iterator::{AssumingIterator, PeekableAssumingIterator},
+    multi_peekable::MultiPeekable,
+};
 
 mod assuming_iterator;
 mod multi_peekable;
 mod parsers;
 
+type Program = (
+    Vec<FunctionDeclaration>,
+    Vec<StructureDeclaration>,
+    Vec<ModulePath>,
+    Vec<GlobalDeclaration>,
+);
+
 pub fn parse<I: Iterator<Item = Result<Token, NilangError>>>(
     tokens: I,
-) -> Result<(Vec<FunctionDeclaration>, Vec<StructureDeclaration>), NilangError> {
+) -> Result<Program, NilangError> {
     let mut tokens = MultiPeekable::new(tokens);
 
     let mut structures = Vec::new();
     let mut functions = Vec::new();
+    let mut uses = Vec::new();
+    let mut globals = Vec::new();
     while tokens.peek().is_some() {
-        if let TokenType::Keyword(value) = &tokens.peek_valid()?.token {
-            match value {
-                Keyword::Function => {
-                    let function = parsers::function_definition_parser::parse_function_definition(
-                        &mut tokens,
-                    )?;
-                    functions.push(function);
-                }
-                Keyword::Structure => {
-                    let structure = parsers::structure_parser::parse_structure(&mut tokens)?;
-                    structures.push(structure);
-                }
-                Keyword::Return
-                | Keyword::Variable
-                | Keyword::If
-                | Keyword::ElseIf
-                | Keyword::Else
-                | Keyword::While => {
-                    return Err(NilangError {
-                        location: CodeLocation::at(usize::MAX, usize::MAX),
-                        error: ParserErrors::ExpectedTokens(
-                            [
-                                TokenType::Keyword(Keyword::Structure),
-                                TokenType::Keyword(Keyword::Function),
-                            ]
-                            .to_vec(),
-                        )
-                        .into(),
-                    });
+        let public = if let TokenType::Keyword(Keyword::Public) = tokens.peek_valid()?.token {
+            tokens.assume_keyword(Keyword::Public)?;
+            true
+        } else {
+            false
+        };
+
+        let Token { token, start, .. } = tokens.peek_valid()?.clone();
+        match token {
+            TokenType::Keyword(Keyword::Function) => {
+                let mut function =
+                    parsers::function_definition_parser::parse_function_definition(&mut tokens)?;
+                function.public = public;
+                functions.push(function);
+            }
+            TokenType::Keyword(Keyword::Structure) => {
+                let mut structure = parsers::structure_parser::parse_structure(&mut tokens)?;
+                structure.public = public;
+                structures.push(structure);
+            }
+            TokenType::Keyword(Keyword::Constant)
+                if tokens.peek_nth_valid(1)?.token == TokenType::Keyword(Keyword::Function) =>
+            {
+                tokens.assume_keyword(Keyword::Constant)?;
+                let mut function =
+                    parsers::function_definition_parser::parse_function_definition(&mut tokens)?;
+                function.public = public;
+                function.constant = true;
+                functions.push(function);
+            }
+            TokenType::Keyword(Keyword::Constant | Keyword::Variable) => {
+                let mut global = parsers::global_parser::parse_global(&mut tokens)?;
+                global.public = public;
+                globals.push(global);
+            }
+            TokenType::Keyword(Keyword::Use) if !public => {
+                uses.push(parsers::use_parser::parse_use(&mut tokens)?);
+            }
+            _ => {
+                let mut expected = vec![
+                    TokenType::Keyword(Keyword::Structure),
+                    TokenType::Keyword(Keyword::Function),
+                    TokenType::Keyword(Keyword::Constant),
+                    TokenType::Keyword(Keyword::Variable),
+                ];
+                if !public {
+                    expected.push(TokenType::Keyword(Keyword::Use));
+                    expected.push(TokenType::Keyword(Keyword::Public));
                 }
+
+                return Err(NilangError {
+                    location: CodeLocation::at(start.0, start.1),
+                    error: ParserErrors::ExpectedTokens(expected).into(),
+                });
             }
-        } else {
-            return Err(NilangError {
-                location: CodeLocation::at(usize::MAX, usize::MAX),
-                error: ParserErrors::ExpectedTokens(
-                    [
-                        TokenType::Keyword(Keyword::Structure),
-                        TokenType::Keyword(Keyword::Function),
-                    ]
-                    .to_vec(),
+        }
+    }
+
+    Ok((functions, structures, uses, globals))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Function(FunctionDeclaration),
+    Structure(StructureDeclaration),
+    Global(GlobalDeclaration),
+    Statement(StatementNode),
+    Expression(ExpressionNode),
+}
+
+pub fn parse_input<I: Iterator<Item = Result<Token, NilangError>>>(
+    tokens: I,
+) -> Result<Vec<Input>, NilangError> {
+    let mut tokens = MultiPeekable::new(tokens);
+
+    let mut inputs = Vec::new();
+    while tokens.peek().is_some() {
+        let token = tokens.peek_valid()?.token.clone();
+        let next = match tokens.peek_nth(1) {
+            Some(Ok(Token { token, .. })) => Some(token.clone()),
+            _ => None,
+        };
+
+        inputs.push(match (token, next) {
+            (TokenType::Keyword(Keyword::Function), Some(TokenType::Identifier(_))) => {
+                Input::Function(
+                    parsers::function_definition_parser::parse_function_definition(&mut tokens)?,
                 )
-                .into(),
-            });
+            }
+            (
+                TokenType::Keyword(Keyword::Constant),
+                Some(TokenType::Keyword(Keyword::Function)),
+            ) => {
+                tokens.assume_keyword(Keyword::Constant)?;
+                let mut function =
+                    parsers::function_definition_parser::parse_function_definition(&mut tokens)?;
+                function.constant = true;
+                Input::Function(function)
+            }
+            (TokenType::Keyword(Keyword::Constant), _) => {
+                Input::Global(parsers::global_parser::parse_global(&mut tokens)?)
+            }
+            (TokenType::Keyword(Keyword::Structure), _) => {
+                Input::Structure(parsers::structure_parser::parse_structure(&mut tokens)?)
+            }
+            (
+                TokenType::Keyword(
+                    Keyword::Variable | Keyword::Return | Keyword::If | Keyword::While,
+                ),
+                _,
+            )
+            | (TokenType::Identifier(_), Some(TokenType::Equals)) => {
+                Input::Statement(parsers::parse_statement(&mut tokens)?)
+            }
+            _ => {
+                let expression = parsers::parse_expression(&mut tokens)?;
+                if let Some(Ok(Token {
+                    token: TokenType::Semicolon,
+                    ..
+                })) = tokens.peek()
+                {
+                    tokens.assume(TokenType::Semicolon)?;
+                }
+                Input::Expression(expression)
+            }
+        });
+    }
+
+    Ok(inputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::{
+        nodes::{
+            expressions::{ExpressionNode, Primitive},
+            statements::StatementNode,
+            Span, Type,
+        },
+        tokens::{Keyword, Token, TokenType},
+    };
+
+    use crate::{parse, parse_input, Input};
+
+    fn token(token: TokenType) -> Token {
+        Token {
+            token,
+            start: (0, 0),
+            end: (0, 0),
         }
     }
 
-    Ok((functions, structures))
+    #[test]
+    fn test_parse_visibility_and_uses() {
+        let (functions, structures, uses, _) = parse(
+            [
+                TokenType::Keyword(Keyword::Use),
+                TokenType::Identifier("math".into()),
+                TokenType::Semicolon,
+                TokenType::Keyword(Keyword::Public),
+                TokenType::Keyword(Keyword::Structure),
+                TokenType::Identifier("Point".into()),
+                TokenType::OpeningBrace,
+                TokenType::Identifier("x".into()),
+                TokenType::Colon,
+                TokenType::Identifier("int".into()),
+                TokenType::ClosingBrace,
+                TokenType::Keyword(Keyword::Function),
+                TokenType::Identifier("main".into()),
+                TokenType::OpeningParenthesis,
+                TokenType::ClosingParenthesis,
+                TokenType::Colon,
+                TokenType::Identifier("int".into()),
+                TokenType::OpeningBrace,
+                TokenType::ClosingBrace,
+            ]
+            .into_iter()
+            .map(|t| Ok(token(t))),
+        )
+        .unwrap();
+
+        assert_eq!(uses, [["math".into()].into()]);
+        assert!(structures[0].public);
+        assert!(!functions[0].public);
+
+        assert!(parse(
+            [
+                TokenType::Keyword(Keyword::Public),
+                TokenType::Keyword(Keyword::Use),
+                TokenType::Identifier("math".into()),
+                TokenType::Semicolon,
+            ]
+            .into_iter()
+            .map(|t| Ok(token(t)))
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_constant_function() {
+        let (functions, _, _, globals) = parse(
+            [
+                TokenType::Keyword(Keyword::Public),
+                TokenType::Keyword(Keyword::Constant),
+                TokenType::Keyword(Keyword::Function),
+                TokenType::Identifier("square".into()),
+                TokenType::OpeningParenthesis,
+                TokenType::ClosingParenthesis,
+                TokenType::Colon,
+                TokenType::Identifier("int".into()),
+                TokenType::OpeningBrace,
+                TokenType::ClosingBrace,
+            ]
+            .into_iter()
+            .map(|t| Ok(token(t))),
+        )
+        .unwrap();
+
+        assert!(globals.is_empty());
+        assert!(functions[0].public);
+        assert!(functions[0].constant);
+    }
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(
+            parse_input(
+                [
+                    TokenType::Keyword(Keyword::Variable),
+                    TokenType::Identifier("x".into()),
+                    TokenType::Colon,
+                    TokenType::Identifier("int".into()),
+                    TokenType::Equals,
+                    TokenType::Literal("1".into()),
+                    TokenType::Semicolon,
+                    TokenType::Identifier("x".into()),
+                    TokenType::Semicolon,
+                ]
+                .into_iter()
+                .map(|t| Ok(token(t))),
+            )
+            .unwrap(),
+            [
+                Input::Statement(StatementNode::VariableDeclaration {
+                    name: "x".into(),
+                    r#type: Type::Int,
+                    value: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                    span: Span::default(),
+                }),
+                Input::Expression(ExpressionNode::VariableReference("x".into())),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_print_round_trip() {
+        let code = "pb st Point {
+    x: int,
+    y: int,
+}
+
+cn ORIGIN: int = (1 + 2) * 3;
+
+pb cn fn square(x: int): int {
+    rt x * x;
+}
+
+fn main(): int {
+    vr p: Point = Point { x: 1, y: 'a' };
+    vr f: fn(int): bool = fn(n: int): bool {
+        rt n >= (2 - p.x);
+    };
+    if (f(p.x)) {
+        print(square(2));
+    } ef (false) {} el {
+        p = Point { x: 2, y: 3 };
+    }
+    wl (true) {
+        rt 0;
+    }
+    rt 1;
+}";
+
+        let (functions, structures, _, globals) = parse(nilang_lexer::lex(code)).unwrap();
+        let printed = structures
+            .iter()
+            .map(ToString::to_string)
+            .chain(globals.iter().map(ToString::to_string))
+            .chain(functions.iter().map(ToString::to_string))
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        assert_eq!(printed, code);
+
+        assert_eq!(
+            parse(nilang_lexer::lex(&printed)).unwrap(),
+            (functions, structures, Vec::new(), globals)
+        );
+    }
 }
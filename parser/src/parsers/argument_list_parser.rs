@@ -1,7 +1,7 @@
 use errors::{CodeLocation, NilangError, ParserErrors};
 use nilang_types::{
     nodes::expressions::ExpressionNode,
-    tokens::{Token, TokenType},
+    tokens::{Keyword, Token, TokenType},
 };
 
 use crate::assuming_iterator::PeekableAssumingIterator;
@@ -19,7 +19,10 @@ pub fn parse_argument_list<I: PeekableAssumingIterator>(
         match tokens.peek_valid()? {
             Token {
                 token:
-                    TokenType::Literal(_) | TokenType::Identifier(_) | TokenType::OpeningParenthesis,
+                    TokenType::Literal(_)
+                    | TokenType::Identifier(_)
+                    | TokenType::OpeningParenthesis
+                    | TokenType::Keyword(Keyword::Function),
                 ..
             } => {
                 arguments.push(parse_expression(tokens)?);
@@ -56,6 +59,7 @@ pub fn parse_argument_list<I: PeekableAssumingIterator>(
                     TokenType::Identifier("".into()),
                     TokenType::Literal("".into()),
                     TokenType::OpeningParenthesis,
+                    TokenType::Keyword(Keyword::Function),
                     TokenType::ClosingParenthesis,
                 ]))
                 .into(),
@@ -0,0 +1,135 @@
+use errors::NilangError;
+use nilang_types::{nodes::expressions::ExpressionNode, tokens::Keyword};
+
+use crate::{assuming_iterator::PeekableAssumingIterator, parsers::scope_parser::parse_scope};
+
+use super::{
+    parameter_list_parser::parse_parameter_list, type_annotation_parser::parse_type_annotation,
+};
+
+pub fn parse_closure<I: PeekableAssumingIterator>(
+    tokens: &mut I,
+) -> Result<ExpressionNode, NilangError> {
+    tokens.assume_keyword(Keyword::Function)?;
+
+    let parameters = parse_parameter_list(tokens)?;
+    let return_type = parse_type_annotation(tokens)?;
+    let body = parse_scope(tokens)?;
+
+    Ok(ExpressionNode::Closure {
+        parameters,
+        return_type,
+        body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::{
+        nodes::{
+            expressions::{ExpressionNode, FunctionCall},
+            statements::StatementNode,
+            Span, Type,
+        },
+        tokens::{Keyword, Token, TokenType},
+    };
+
+    use crate::{multi_peekable::MultiPeekable, parsers::closure_parser::parse_closure};
+
+    #[test]
+    fn test_parse_closure() {
+        assert_eq!(
+            parse_closure(&mut MultiPeekable::new(
+                [
+                    Ok(Token {
+                        token: TokenType::Keyword(Keyword::Function),
+                        start: (0, 0),
+                        end: (0, 1),
+                    }),
+                    Ok(Token {
+                        token: TokenType::OpeningParenthesis,
+                        start: (0, 2),
+                        end: (0, 2),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("x".into()),
+                        start: (0, 3),
+                        end: (0, 3),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 4),
+                        end: (0, 4),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("int".into()),
+                        start: (0, 6),
+                        end: (0, 8),
+                    }),
+                    Ok(Token {
+                        token: TokenType::ClosingParenthesis,
+                        start: (0, 9),
+                        end: (0, 9),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 10),
+                        end: (0, 10),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("void".into()),
+                        start: (0, 12),
+                        end: (0, 15),
+                    }),
+                    Ok(Token {
+                        token: TokenType::OpeningBrace,
+                        start: (0, 17),
+                        end: (0, 17),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("print".into()),
+                        start: (0, 19),
+                        end: (0, 23),
+                    }),
+                    Ok(Token {
+                        token: TokenType::OpeningParenthesis,
+                        start: (0, 24),
+                        end: (0, 24),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("x".into()),
+                        start: (0, 25),
+                        end: (0, 25),
+                    }),
+                    Ok(Token {
+                        token: TokenType::ClosingParenthesis,
+                        start: (0, 26),
+                        end: (0, 26),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Semicolon,
+                        start: (0, 27),
+                        end: (0, 27),
+                    }),
+                    Ok(Token {
+                        token: TokenType::ClosingBrace,
+                        start: (0, 29),
+                        end: (0, 29),
+                    }),
+                ]
+                .into_iter()
+            ))
+            .unwrap(),
+            ExpressionNode::Closure {
+                parameters: [("x".into(), Type::Int)].into(),
+                return_type: Type::Void,
+                body: [StatementNode::FunctionCall(FunctionCall {
+                    name: "print".into(),
+                    arguments: [ExpressionNode::VariableReference("x".into())].into(),
+                    span: Span::new((0, 19), (0, 23))
+                })]
+                .into(),
+            }
+        );
+    }
+}
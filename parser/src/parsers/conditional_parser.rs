@@ -1,6 +1,9 @@
 use errors::{CodeLocation, NilangError, ParserErrors};
 use nilang_types::{
-    nodes::expressions::{Conditional, ExpressionNode, Primitive},
+    nodes::{
+        expressions::{Conditional, ExpressionNode, Primitive},
+        Span,
+    },
     tokens::{Keyword, Token, TokenType},
 };
 
@@ -39,37 +42,39 @@ pub fn parse_conditional<I: PeekableAssumingIterator>(
 }
 
 pub fn parse_if<I: PeekableAssumingIterator>(tokens: &mut I) -> Result<Conditional, NilangError> {
-    tokens.assume_keyword(Keyword::If)?;
+    let (start, end) = tokens.assume_keyword(Keyword::If)?;
 
     let condition = parse_expression(tokens)?;
     let body = parse_scope(tokens)?;
-    let chained = parse_conditional(tokens).ok().map(Box::new);
+    let chained = parse_chained(tokens)?;
 
     Ok(Conditional {
         condition,
         body,
         chained,
+        span: Span::new(start, end),
     })
 }
 
 pub fn parse_else_if<I: PeekableAssumingIterator>(
     tokens: &mut I,
 ) -> Result<Conditional, NilangError> {
-    tokens.assume_keyword(Keyword::ElseIf)?;
+    let (start, end) = tokens.assume_keyword(Keyword::ElseIf)?;
 
     let condition = parse_expression(tokens)?;
     let body = parse_scope(tokens)?;
-    let chained = parse_conditional(tokens).ok().map(Box::new);
+    let chained = parse_chained(tokens)?;
 
     Ok(Conditional {
         condition,
         body,
         chained,
+        span: Span::new(start, end),
     })
 }
 
 pub fn parse_else<I: PeekableAssumingIterator>(tokens: &mut I) -> Result<Conditional, NilangError> {
-    tokens.assume_keyword(Keyword::Else)?;
+    let (start, end) = tokens.assume_keyword(Keyword::Else)?;
 
     let body = parse_scope(tokens)?;
 
@@ -77,13 +82,31 @@ pub fn parse_else<I: PeekableAssumingIterator>(tokens: &mut I) -> Result<Conditi
         condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
         body,
         chained: None,
+        span: Span::new(start, end),
     })
 }
 
+fn parse_chained<I: PeekableAssumingIterator>(
+    tokens: &mut I,
+) -> Result<Option<Box<Conditional>>, NilangError> {
+    let chained = matches!(
+        tokens.peek_valid().map(|token| &token.token),
+        Ok(TokenType::Keyword(Keyword::ElseIf | Keyword::Else))
+    );
+    if !chained {
+        return Ok(None);
+    }
+
+    parse_conditional(tokens).map(|conditional| Some(Box::new(conditional)))
+}
+
 #[cfg(test)]
 mod tests {
     use nilang_types::{
-        nodes::expressions::{Conditional, ExpressionNode, Primitive},
+        nodes::{
+            expressions::{Conditional, ExpressionNode, Primitive},
+            Span,
+        },
         tokens::{Keyword, Token, TokenType},
     };
 
@@ -121,7 +144,8 @@ mod tests {
             Conditional {
                 condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
                 body: Box::new([]),
-                chained: None
+                chained: None,
+                span: Span::new((0, 0), (0, 1))
             }
         );
     }
@@ -176,8 +200,10 @@ mod tests {
                 chained: Some(Box::new(Conditional {
                     condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
                     body: Box::new([]),
-                    chained: None
-                }))
+                    chained: None,
+                    span: Span::new((0, 6), (0, 7))
+                })),
+                span: Span::new((0, 0), (0, 1))
             }
         );
     }
@@ -237,8 +263,10 @@ mod tests {
                 chained: Some(Box::new(Conditional {
                     condition: ExpressionNode::Primitive(Primitive::Boolean(false)),
                     body: Box::new([]),
-                    chained: None
-                }))
+                    chained: None,
+                    span: Span::new((0, 7), (0, 8))
+                })),
+                span: Span::new((0, 0), (0, 1))
             }
         );
     }
@@ -316,9 +344,49 @@ mod tests {
                     chained: Some(Box::new(Conditional {
                         condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
                         body: Box::new([]),
-                        chained: None
-                    }))
-                }))
+                        chained: None,
+                        span: Span::new((0, 16), (0, 16))
+                    })),
+                    span: Span::new((0, 7), (0, 8))
+                })),
+                span: Span::new((0, 0), (0, 1))
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_conditional_followed_by_conditional() {
+        let mut tokens = MultiPeekable::new(
+            [
+                (TokenType::Keyword(Keyword::If), (0, 0), (0, 1)),
+                (TokenType::Literal("true".into()), (0, 3), (0, 6)),
+                (TokenType::OpeningBrace, (0, 8), (0, 8)),
+                (TokenType::ClosingBrace, (0, 9), (0, 9)),
+                (TokenType::Keyword(Keyword::If), (1, 0), (1, 1)),
+                (TokenType::Literal("false".into()), (1, 3), (1, 7)),
+                (TokenType::OpeningBrace, (1, 9), (1, 9)),
+                (TokenType::ClosingBrace, (1, 10), (1, 10)),
+            ]
+            .into_iter()
+            .map(|(token, start, end)| Ok(Token { token, start, end })),
+        );
+
+        assert_eq!(
+            parse_conditional(&mut tokens).unwrap(),
+            Conditional {
+                condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
+                body: Box::new([]),
+                chained: None,
+                span: Span::new((0, 0), (0, 1))
+            }
+        );
+        assert_eq!(
+            parse_conditional(&mut tokens).unwrap(),
+            Conditional {
+                condition: ExpressionNode::Primitive(Primitive::Boolean(false)),
+                body: Box::new([]),
+                chained: None,
+                span: Span::new((1, 0), (1, 1))
             }
         );
     }
@@ -3,6 +3,7 @@ use nilang_types::{
     nodes::{
         expressions::{ExpressionNode, FunctionCall},
         statements::StatementNode,
+        Span,
     },
     tokens::TokenType,
 };
@@ -31,10 +32,14 @@ pub fn parse_function_call_expression<I: PeekableAssumingIterator>(
 fn parse_function_call_only<I: PeekableAssumingIterator>(
     tokens: &mut I,
 ) -> Result<FunctionCall, NilangError> {
-    let (_, _, name) = tokens.assume_identifier()?;
+    let (start, end, name) = tokens.assume_identifier()?;
     let arguments = parse_argument_list(tokens)?;
 
-    Ok(FunctionCall { name, arguments })
+    Ok(FunctionCall {
+        name,
+        arguments,
+        span: Span::new(start, end),
+    })
 }
 
 fn expand_function_call_if_dot_follows<I: PeekableAssumingIterator>(
@@ -58,7 +63,10 @@ fn expand_function_call_if_dot_follows<I: PeekableAssumingIterator>(
 #[cfg(test)]
 mod tests {
     use nilang_types::{
-        nodes::expressions::{ExpressionNode, FunctionCall},
+        nodes::{
+            expressions::{ExpressionNode, FunctionCall},
+            Span,
+        },
         tokens::{Token, TokenType},
     };
 
@@ -98,7 +106,8 @@ mod tests {
             .unwrap(),
             ExpressionNode::FunctionCall(FunctionCall {
                 name: "x".into(),
-                arguments: [].into()
+                arguments: [].into(),
+                span: Span::default()
             })
         );
 
@@ -142,7 +151,8 @@ mod tests {
             ExpressionNode::FieldAccess {
                 structure: Box::new(ExpressionNode::FunctionCall(FunctionCall {
                     name: "x".into(),
-                    arguments: [].into()
+                    arguments: [].into(),
+                    span: Span::default()
                 })),
                 field: "test".into()
             }
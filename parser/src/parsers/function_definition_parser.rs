@@ -1,5 +1,8 @@
 use errors::NilangError;
-use nilang_types::{nodes::statements::FunctionDeclaration, tokens::Keyword};
+use nilang_types::{
+    nodes::{statements::FunctionDeclaration, Span},
+    tokens::Keyword,
+};
 
 use crate::{assuming_iterator::PeekableAssumingIterator, parsers::scope_parser::parse_scope};
 
@@ -12,16 +15,19 @@ pub fn parse_function_definition<I: PeekableAssumingIterator>(
 ) -> Result<FunctionDeclaration, NilangError> {
     tokens.assume_keyword(Keyword::Function)?;
 
-    let (_, _, name) = tokens.assume_identifier()?;
+    let (start, end, name) = tokens.assume_identifier()?;
     let parameters = parse_parameter_list(tokens)?;
     let return_type = parse_type_annotation(tokens)?;
     let body = parse_scope(tokens)?;
 
     Ok(FunctionDeclaration {
+        public: false,
+        constant: false,
         name,
         parameters,
         return_type,
         body,
+        span: Span::new(start, end),
     })
 }
 
@@ -31,7 +37,7 @@ mod tests {
         nodes::{
             expressions::{ExpressionNode, Primitive},
             statements::{FunctionDeclaration, StatementNode},
-            Type,
+            Span, Type,
         },
         tokens::{Keyword, Token, TokenType},
     };
@@ -105,12 +111,16 @@ mod tests {
             ),)
             .unwrap(),
             FunctionDeclaration {
+                public: false,
+                constant: false,
                 name: "main".into(),
                 parameters: [].into(),
                 return_type: Type::Int,
-                body: Box::new([StatementNode::Return(Box::new(ExpressionNode::Primitive(
-                    Primitive::Number(6.)
-                )))]),
+                body: Box::new([StatementNode::Return {
+                    value: Box::new(ExpressionNode::Primitive(Primitive::Number(6.))),
+                    span: Span::new((0, 14), (0, 15))
+                }]),
+                span: Span::new((0, 3), (0, 6)),
             }
         );
     }
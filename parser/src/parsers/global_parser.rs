@@ -0,0 +1,122 @@
+use errors::{CodeLocation, NilangError, ParserErrors};
+use nilang_types::{
+    nodes::{statements::GlobalDeclaration, Span},
+    tokens::{Keyword, Token, TokenType},
+};
+
+use crate::assuming_iterator::PeekableAssumingIterator;
+
+use super::{parse_expression, type_annotation_parser::parse_type_annotation};
+
+pub fn parse_global<I: PeekableAssumingIterator>(
+    tokens: &mut I,
+) -> Result<GlobalDeclaration, NilangError> {
+    let constant = match tokens.assume_next()? {
+        Token {
+            token: TokenType::Keyword(Keyword::Constant),
+            ..
+        } => true,
+        Token {
+            token: TokenType::Keyword(Keyword::Variable),
+            ..
+        } => false,
+        Token { start, end, .. } => {
+            return Err(NilangError {
+                location: CodeLocation::range(start.0, start.1, end.0, end.1),
+                error: ParserErrors::ExpectedTokens(vec![
+                    TokenType::Keyword(Keyword::Constant),
+                    TokenType::Keyword(Keyword::Variable),
+                ])
+                .into(),
+            })
+        }
+    };
+
+    let (start, end, name) = tokens.assume_identifier()?;
+
+    let r#type = parse_type_annotation(tokens)?;
+
+    tokens.assume(TokenType::Equals)?;
+
+    let value = parse_expression(tokens)?;
+
+    tokens.assume(TokenType::Semicolon)?;
+
+    Ok(GlobalDeclaration {
+        public: false,
+        constant,
+        name,
+        r#type,
+        value: Box::new(value),
+        span: Span::new(start, end),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::{
+        nodes::{
+            expressions::{ExpressionNode, Primitive},
+            statements::GlobalDeclaration,
+            Span, Type,
+        },
+        tokens::{Keyword, Token, TokenType},
+    };
+
+    use crate::{multi_peekable::MultiPeekable, parsers::global_parser::parse_global};
+
+    #[test]
+    fn test_parse_constant() {
+        assert_eq!(
+            parse_global(&mut MultiPeekable::new(
+                [
+                    Ok(Token {
+                        token: TokenType::Keyword(Keyword::Constant),
+                        start: (0, 0),
+                        end: (0, 1),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("LIMIT".into()),
+                        start: (0, 3),
+                        end: (0, 7),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 8),
+                        end: (0, 8),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("int".into()),
+                        start: (0, 10),
+                        end: (0, 12),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Equals,
+                        start: (0, 14),
+                        end: (0, 14),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Literal("10".into()),
+                        start: (0, 16),
+                        end: (0, 17),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Semicolon,
+                        start: (0, 18),
+                        end: (0, 18),
+                    }),
+                ]
+                .into_iter()
+            ))
+            .unwrap(),
+            GlobalDeclaration {
+                public: false,
+                constant: true,
+                name: "LIMIT".into(),
+                r#type: Type::Int,
+                value: Box::new(ExpressionNode::Primitive(Primitive::Number(10.))),
+                span: Span::new((0, 3), (0, 7)),
+            }
+        );
+    }
+}
@@ -14,26 +14,29 @@ use variable_declaration_parser::parse_variable_declaration;
 use crate::{
     assuming_iterator::PeekableAssumingIterator,
     parsers::{
-        conditional_parser::parse_conditional,
+        closure_parser::parse_closure, conditional_parser::parse_conditional,
         variable_assignment_parser::parse_variable_assignment, while_loop_parser::parse_while_loop,
     },
 };
 
 mod argument_list_parser;
+mod closure_parser;
 mod conditional_parser;
 mod field_access_parser;
 mod function_call_parser;
 pub mod function_definition_parser;
+pub mod global_parser;
 mod identifier_parser;
 mod literal_parser;
 mod object_parser;
-mod operation;
+pub mod operation;
 mod parameter_list_parser;
 mod parenthesis_parser;
 mod return_parser;
 mod scope_parser;
 pub mod structure_parser;
 mod type_annotation_parser;
+pub mod use_parser;
 mod variable_assignment_parser;
 mod variable_declaration_parser;
 mod while_loop_parser;
@@ -49,7 +52,13 @@ pub fn parse_statement<I: PeekableAssumingIterator>(
             Keyword::Return => parse_return(tokens)?,
             Keyword::If => StatementNode::Conditional(parse_conditional(tokens)?),
             Keyword::While => parse_while_loop(tokens)?,
-            Keyword::ElseIf | Keyword::Else | Keyword::Function | Keyword::Structure => {
+            Keyword::ElseIf
+            | Keyword::Else
+            | Keyword::Function
+            | Keyword::Structure
+            | Keyword::Use
+            | Keyword::Public
+            | Keyword::Constant => {
                 return Err(NilangError {
                     location: CodeLocation::at(peek_valid.start.0, peek_valid.start.1),
                     error: ParserErrors::UnexpectedToken(peek_valid.token.clone()).into(),
@@ -80,7 +89,8 @@ pub fn parse_statement<I: PeekableAssumingIterator>(
         | TokenType::Semicolon
         | TokenType::Colon
         | TokenType::Comma
-        | TokenType::Dot => Err(NilangError {
+        | TokenType::Dot
+        | TokenType::Comment(_) => Err(NilangError {
             location: CodeLocation::at(peek_valid.start.0, peek_valid.start.1),
             error: ParserErrors::UnexpectedToken(peek_valid.token.clone()).into(),
         })?,
@@ -103,6 +113,7 @@ pub fn parse_single_expression<I: PeekableAssumingIterator>(
         TokenType::Literal(_) => parse_literal::<_>(tokens)?,
         TokenType::OpeningParenthesis => parse_parenthesis(tokens)?,
         TokenType::Identifier(_) => parse_identifier(tokens)?,
+        TokenType::Keyword(Keyword::Function) => parse_closure(tokens)?,
         TokenType::Operator(_)
         | TokenType::ClosingParenthesis
         | TokenType::ClosingBrace
@@ -112,7 +123,8 @@ pub fn parse_single_expression<I: PeekableAssumingIterator>(
         | TokenType::Semicolon
         | TokenType::Colon
         | TokenType::Comma
-        | TokenType::Dot => Err(NilangError {
+        | TokenType::Dot
+        | TokenType::Comment(_) => Err(NilangError {
             location: CodeLocation::at(peek_valid.start.0, peek_valid.start.1),
             error: ParserErrors::UnexpectedToken(peek_valid.token.clone()).into(),
         })?,
@@ -1,32 +1,71 @@
-use nilang_types::nodes::expressions::{ExpressionNode, Operation, Operator};
+use nilang_types::nodes::{
+    expressions::{ExpressionNode, Operation, Operator},
+    Span,
+};
 
 use super::operation_extender::extend_operation;
 
 pub(super) fn combine_expressions(
     preceding: ExpressionNode,
     operator: Operator,
+    span: Span,
     following: ExpressionNode,
 ) -> Result<ExpressionNode, ()> {
     Ok(match (preceding, following) {
-        (ExpressionNode::Object { .. }, _) | (_, ExpressionNode::Object { .. }) => Err(())?,
+        (ExpressionNode::Object { .. }, _)
+        | (_, ExpressionNode::Object { .. })
+        | (ExpressionNode::Closure { .. }, _)
+        | (_, ExpressionNode::Closure { .. }) => Err(())?,
 
-        (_, ExpressionNode::Operation(_)) => {
-            unreachable!("expressions are always evaluated left-to-right")
+        (a, ExpressionNode::Operation(following)) => {
+            let (first, rest) = flatten_operation(following);
+            rest.into_iter().try_fold(
+                combine_expressions(a, operator, span, first)?,
+                |a, (operator, span, b)| combine_expressions(a, operator, span, b),
+            )?
         }
         (ExpressionNode::Operation(a), b) => {
-            ExpressionNode::Operation(extend_operation(a, operator, b))
+            ExpressionNode::Operation(extend_operation(a, operator, span, b))
         }
 
         (a, b) => ExpressionNode::Operation(Operation {
             operator,
             a: Box::new(a),
             b: Box::new(b),
+            span,
         }),
     })
 }
 
+fn flatten_operation(
+    Operation {
+        operator,
+        a,
+        b,
+        span,
+    }: Operation,
+) -> (ExpressionNode, Vec<(Operator, Span, ExpressionNode)>) {
+    let (first, mut rest) = match *a {
+        ExpressionNode::Operation(a) => flatten_operation(a),
+        a => (a, Vec::new()),
+    };
+
+    match *b {
+        ExpressionNode::Operation(b) => {
+            let (b, mut following) = flatten_operation(b);
+            rest.push((operator, span, b));
+            rest.append(&mut following);
+        }
+        b => rest.push((operator, span, b)),
+    }
+
+    (first, rest)
+}
+
 #[cfg(test)]
 mod tests {
+    use nilang_types::nodes::expressions::{Arithmetic, Primitive};
+
     use super::*;
 
     #[test]
@@ -38,6 +77,7 @@ mod tests {
                     fields: Default::default()
                 },
                 Default::default(),
+                Span::default(),
                 ExpressionNode::Object {
                     r#type: Default::default(),
                     fields: Default::default()
@@ -50,12 +90,46 @@ mod tests {
             combine_expressions(
                 ExpressionNode::Primitive(Default::default()),
                 Default::default(),
+                Span::default(),
                 ExpressionNode::Parenthesis(Default::default())
             ),
             Ok(ExpressionNode::Operation(Operation {
                 operator: Default::default(),
                 a: Box::new(ExpressionNode::Primitive(Default::default())),
-                b: Box::new(ExpressionNode::Parenthesis(Default::default()))
+                b: Box::new(ExpressionNode::Parenthesis(Default::default())),
+                span: Span::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_combine_with_following_operation() {
+        let number = |n| Box::new(ExpressionNode::Primitive(Primitive::Number(n)));
+        let multiply = Span::new((0, 2), (0, 2));
+        let add = Span::new((0, 6), (0, 6));
+
+        assert_eq!(
+            combine_expressions(
+                ExpressionNode::Primitive(Primitive::Number(2.)),
+                Operator::Arithmetic(Arithmetic::Multiply),
+                multiply,
+                ExpressionNode::Operation(Operation {
+                    operator: Operator::Arithmetic(Arithmetic::Add),
+                    a: number(3.),
+                    b: number(1.),
+                    span: add,
+                })
+            ),
+            Ok(ExpressionNode::Operation(Operation {
+                operator: Operator::Arithmetic(Arithmetic::Add),
+                a: Box::new(ExpressionNode::Operation(Operation {
+                    operator: Operator::Arithmetic(Arithmetic::Multiply),
+                    a: number(2.),
+                    b: number(3.),
+                    span: multiply,
+                })),
+                b: number(1.),
+                span: add,
             }))
         );
     }
@@ -1,6 +1,6 @@
 use errors::{CodeLocation, NilangError, ParserErrors};
 use nilang_types::{
-    nodes::expressions::ExpressionNode,
+    nodes::{expressions::ExpressionNode, Span},
     tokens::{Token, TokenType},
 };
 
@@ -23,7 +23,10 @@ pub fn lookup_operation_recursive<I: PeekableAssumingIterator>(
         let following = super::parse_single_expression(tokens)?;
 
         let expression = expression_combinator::combine_expressions(
-            preceeding, operator, following,
+            preceeding,
+            operator,
+            Span::new(start, end),
+            following,
         )
         .map_err(|_| NilangError {
             location: CodeLocation::range(start.0, start.1, end.0, end.1),
@@ -1,10 +1,14 @@
-use nilang_types::nodes::expressions::{ExpressionNode, Operation, Operator};
+use nilang_types::nodes::{
+    expressions::{ExpressionNode, Operation, Operator},
+    Span,
+};
 
 use super::precendence::is_preceeding;
 
 pub(super) fn extend_operation(
     preceding: Operation,
     operator: Operator,
+    span: Span,
     following: ExpressionNode,
 ) -> Operation {
     if !is_preceeding(preceding.operator, operator) {
@@ -15,13 +19,16 @@ pub(super) fn extend_operation(
                 operator,
                 a: preceding.b,
                 b: Box::new(following),
+                span,
             })),
+            span: preceding.span,
         }
     } else {
         Operation {
             operator,
             a: Box::new(ExpressionNode::Operation(preceding)),
             b: Box::new(following),
+            span,
         }
     }
 }
@@ -34,14 +41,19 @@ mod tests {
 
     #[test]
     fn test_extend_operation() {
+        let first = Span::new((0, 2), (0, 2));
+        let second = Span::new((0, 6), (0, 6));
+
         assert_eq!(
             extend_operation(
                 Operation {
                     operator: Operator::Arithmetic(Arithmetic::Multiply),
                     a: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
-                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.)))
+                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: first
                 },
                 Operator::Arithmetic(Arithmetic::Add),
+                second,
                 ExpressionNode::Primitive(Primitive::Number(3.))
             ),
             Operation {
@@ -50,8 +62,10 @@ mod tests {
                     operator: Operator::Arithmetic(Arithmetic::Multiply),
                     a: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
                     b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: first
                 })),
                 b: Box::new(ExpressionNode::Primitive(Primitive::Number(3.))),
+                span: second
             }
         );
 
@@ -60,9 +74,11 @@ mod tests {
                 Operation {
                     operator: Operator::Arithmetic(Arithmetic::Add),
                     a: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
-                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.)))
+                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: first
                 },
                 Operator::Arithmetic(Arithmetic::Multiply),
+                second,
                 ExpressionNode::Primitive(Primitive::Number(3.))
             ),
             Operation {
@@ -72,7 +88,9 @@ mod tests {
                     operator: Operator::Arithmetic(Arithmetic::Multiply),
                     a: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
                     b: Box::new(ExpressionNode::Primitive(Primitive::Number(3.))),
+                    span: second
                 })),
+                span: first
             }
         );
     }
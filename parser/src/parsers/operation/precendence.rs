@@ -1,23 +1,13 @@
-use nilang_types::nodes::expressions::{Arithmetic, Operator};
+use nilang_types::nodes::expressions::Operator;
 
 pub(super) fn is_preceeding(a: Operator, b: Operator) -> bool {
-    precendence_score(a) > precendence_score(b)
-}
-
-fn precendence_score(operator: Operator) -> u8 {
-    match operator {
-        Operator::Arithmetic(operator) => match operator {
-            Arithmetic::Add | Arithmetic::Subtract => 0,
-            Arithmetic::Multiply | Arithmetic::Divide | Arithmetic::Modulo => 1,
-        },
-        Operator::Boolean(_) => 2,
-    }
+    a.precedence() > b.precedence()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use nilang_types::nodes::expressions::{Boolean, Operator};
+    use nilang_types::nodes::expressions::{Arithmetic, Boolean, Operator};
 
     #[test]
     fn test_is_preceeding() {
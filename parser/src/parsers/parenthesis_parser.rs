@@ -19,7 +19,10 @@ pub fn parse_parenthesis<I: PeekableAssumingIterator>(
 #[cfg(test)]
 mod tests {
     use nilang_types::{
-        nodes::expressions::{Arithmetic, ExpressionNode, Operation, Operator, Primitive},
+        nodes::{
+            expressions::{Arithmetic, ExpressionNode, Operation, Operator, Primitive},
+            Span,
+        },
         tokens::{Token, TokenType},
     };
 
@@ -68,6 +71,7 @@ mod tests {
                 operator: Operator::Arithmetic(Arithmetic::Add),
                 a: Box::new(ExpressionNode::Primitive(Primitive::Number(6.))),
                 b: Box::new(ExpressionNode::Primitive(Primitive::Number(9.))),
+                span: Span::new((0, 2), (0, 2))
             })))
         );
 
@@ -136,8 +140,10 @@ mod tests {
                         operator: Operator::Arithmetic(Arithmetic::Add),
                         a: Box::new(ExpressionNode::Primitive(Primitive::Number(9.))),
                         b: Box::new(ExpressionNode::Primitive(Primitive::Number(5.))),
+                        span: Span::new((0, 5), (0, 5))
                     })
                 ))),
+                span: Span::new((0, 2), (0, 2))
             })))
         );
 
@@ -205,9 +211,11 @@ mod tests {
                         operator: Operator::Arithmetic(Arithmetic::Add),
                         a: Box::new(ExpressionNode::Primitive(Primitive::Number(4.))),
                         b: Box::new(ExpressionNode::Primitive(Primitive::Number(9.))),
+                        span: Span::new((0, 3), (0, 3))
                     })
                 ))),
                 b: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                span: Span::new((0, 6), (0, 6))
             })))
         );
 
@@ -297,15 +305,19 @@ mod tests {
                             operator: Operator::Arithmetic(Arithmetic::Add),
                             a: Box::new(ExpressionNode::Primitive(Primitive::Number(4.))),
                             b: Box::new(ExpressionNode::Primitive(Primitive::Number(9.))),
+                            span: Span::new((0, 3), (0, 3))
                         })
                     ))),
                     b: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                    span: Span::new((0, 6), (0, 6))
                 })),
                 b: Box::new(ExpressionNode::Operation(Operation {
                     operator: Operator::Arithmetic(Arithmetic::Multiply),
                     a: Box::new(ExpressionNode::Primitive(Primitive::Number(6.))),
                     b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: Span::new((0, 10), (0, 10))
                 })),
+                span: Span::new((0, 8), (0, 8))
             })))
         );
     }
@@ -1,6 +1,6 @@
 use errors::NilangError;
 use nilang_types::{
-    nodes::statements::StatementNode,
+    nodes::{statements::StatementNode, Span},
     tokens::{Keyword, TokenType},
 };
 
@@ -11,13 +11,16 @@ use super::parse_expression;
 pub fn parse_return<I: PeekableAssumingIterator>(
     tokens: &mut I,
 ) -> Result<StatementNode, NilangError> {
-    tokens.assume_keyword(Keyword::Return)?;
+    let (start, end) = tokens.assume_keyword(Keyword::Return)?;
 
     let value = parse_expression(tokens)?;
 
     tokens.assume(TokenType::Semicolon)?;
 
-    Ok(StatementNode::Return(Box::new(value)))
+    Ok(StatementNode::Return {
+        value: Box::new(value),
+        span: Span::new(start, end),
+    })
 }
 
 #[cfg(test)]
@@ -26,6 +29,7 @@ mod tests {
         nodes::{
             expressions::{ExpressionNode, Primitive},
             statements::StatementNode,
+            Span,
         },
         tokens::{Keyword, Token, TokenType},
     };
@@ -56,7 +60,10 @@ mod tests {
                 .into_iter()
             ),)
             .unwrap(),
-            StatementNode::Return(Box::new(ExpressionNode::Primitive(Primitive::Number(6.))))
+            StatementNode::Return {
+                value: Box::new(ExpressionNode::Primitive(Primitive::Number(6.))),
+                span: Span::new((0, 0), (0, 1))
+            }
         );
     }
 }
@@ -21,7 +21,7 @@ pub fn parse_scope<I: PeekableAssumingIterator>(
 #[cfg(test)]
 mod tests {
     use nilang_types::{
-        nodes::{expressions::FunctionCall, statements::StatementNode},
+        nodes::{expressions::FunctionCall, statements::StatementNode, Span},
         tokens::{Token, TokenType},
     };
 
@@ -91,7 +91,8 @@ mod tests {
             .unwrap(),
             [StatementNode::FunctionCall(FunctionCall {
                 name: "test".into(),
-                arguments: [].into()
+                arguments: [].into(),
+                span: Span::new((0, 1), (0, 4))
             })]
             .into()
         );
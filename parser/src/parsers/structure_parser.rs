@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use errors::{CodeLocation, NilangError, ParserErrors};
 use nilang_types::{
-    nodes::statements::StructureDeclaration,
+    nodes::{statements::StructureDeclaration, Span},
     tokens::{Keyword, Token, TokenType},
 };
 
@@ -15,7 +15,7 @@ pub fn parse_structure<I: PeekableAssumingIterator>(
 ) -> Result<StructureDeclaration, NilangError> {
     tokens.assume_keyword(Keyword::Structure)?;
 
-    let (_, _, name) = tokens.assume_identifier()?;
+    let (start, end, name) = tokens.assume_identifier()?;
 
     tokens.assume(TokenType::OpeningBrace)?;
 
@@ -64,13 +64,18 @@ pub fn parse_structure<I: PeekableAssumingIterator>(
 
     tokens.assume(TokenType::ClosingBrace)?;
 
-    Ok(StructureDeclaration { name, fields })
+    Ok(StructureDeclaration {
+        public: false,
+        name,
+        fields,
+        span: Span::new(start, end),
+    })
 }
 
 #[cfg(test)]
 mod test {
     use nilang_types::{
-        nodes::{statements::StructureDeclaration, Type},
+        nodes::{statements::StructureDeclaration, Span, Type},
         tokens::{Keyword, Token, TokenType},
     };
 
@@ -141,12 +146,14 @@ mod test {
             ))
             .unwrap(),
             StructureDeclaration {
+                public: false,
                 name: "Test".into(),
                 fields: [
                     ("test_field".into(), Type::Int),
                     ("test_field2".into(), Type::Int),
                 ]
                 .into(),
+                span: Span::new((0, 3), (0, 6)),
             }
         );
 
@@ -198,8 +205,10 @@ mod test {
             ))
             .unwrap(),
             StructureDeclaration {
+                public: false,
                 name: "Test".into(),
                 fields: [("test_field".into(), Type::Int)].into(),
+                span: Span::new((0, 3), (0, 6)),
             },
         );
     }
@@ -1,5 +1,8 @@
-use errors::NilangError;
-use nilang_types::{nodes::Type, tokens::TokenType};
+use errors::{CodeLocation, NilangError, ParserErrors};
+use nilang_types::{
+    nodes::Type,
+    tokens::{Keyword, Token, TokenType},
+};
 
 use crate::assuming_iterator::PeekableAssumingIterator;
 
@@ -8,11 +11,62 @@ pub fn parse_type_annotation<I: PeekableAssumingIterator>(
 ) -> Result<Type, NilangError> {
     tokens.assume(TokenType::Colon)?;
 
+    parse_type_reference(tokens)
+}
+
+pub fn parse_type_reference<I: PeekableAssumingIterator>(
+    tokens: &mut I,
+) -> Result<Type, NilangError> {
+    if let TokenType::Keyword(Keyword::Function) = tokens.peek_valid()?.token {
+        return parse_function_type(tokens);
+    }
+
     let (_, _, r#type) = tokens.assume_identifier()?;
 
     Ok(parse_type(&r#type))
 }
 
+fn parse_function_type<I: PeekableAssumingIterator>(tokens: &mut I) -> Result<Type, NilangError> {
+    tokens.assume_keyword(Keyword::Function)?;
+    tokens.assume(TokenType::OpeningParenthesis)?;
+
+    let mut parameters = Vec::new();
+
+    if let TokenType::ClosingParenthesis = tokens.peek_valid()?.token {
+        tokens.assume(TokenType::ClosingParenthesis)?;
+    } else {
+        loop {
+            parameters.push(parse_type_reference(tokens)?);
+
+            match tokens.assume_next()? {
+                Token {
+                    token: TokenType::ClosingParenthesis,
+                    ..
+                } => break,
+                Token {
+                    token: TokenType::Comma,
+                    ..
+                } => {}
+                Token { start, .. } => Err(NilangError {
+                    location: CodeLocation::at(start.0, start.1),
+                    error: ParserErrors::ExpectedTokens(Vec::from([
+                        TokenType::Comma,
+                        TokenType::ClosingParenthesis,
+                    ]))
+                    .into(),
+                })?,
+            }
+        }
+    }
+
+    let return_type = parse_type_annotation(tokens)?;
+
+    Ok(Type::Function {
+        parameters: parameters.into(),
+        return_type: Box::new(return_type),
+    })
+}
+
 pub fn parse_type(r#type: &str) -> Type {
     match r#type.to_string().as_str() {
         "void" => Type::Void,
@@ -28,7 +82,7 @@ pub fn parse_type(r#type: &str) -> Type {
 mod test {
     use nilang_types::{
         nodes::Type,
-        tokens::{Token, TokenType},
+        tokens::{Keyword, Token, TokenType},
     };
 
     use crate::{
@@ -57,4 +111,65 @@ mod test {
             Type::Int,
         );
     }
+
+    #[test]
+    fn test_parse_function_type() {
+        assert_eq!(
+            parse_type_annotation(&mut MultiPeekable::new(
+                [
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 5),
+                        end: (0, 5),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Keyword(Keyword::Function),
+                        start: (0, 7),
+                        end: (0, 8),
+                    }),
+                    Ok(Token {
+                        token: TokenType::OpeningParenthesis,
+                        start: (0, 9),
+                        end: (0, 9),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("int".into()),
+                        start: (0, 10),
+                        end: (0, 12),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Comma,
+                        start: (0, 13),
+                        end: (0, 13),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("char".into()),
+                        start: (0, 15),
+                        end: (0, 18),
+                    }),
+                    Ok(Token {
+                        token: TokenType::ClosingParenthesis,
+                        start: (0, 19),
+                        end: (0, 19),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 20),
+                        end: (0, 20),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("bool".into()),
+                        start: (0, 22),
+                        end: (0, 25),
+                    }),
+                ]
+                .into_iter()
+            ))
+            .unwrap(),
+            Type::Function {
+                parameters: [Type::Int, Type::Char].into(),
+                return_type: Box::new(Type::Bool),
+            },
+        );
+    }
 }
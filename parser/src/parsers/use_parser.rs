@@ -0,0 +1,82 @@
+use errors::NilangError;
+use nilang_types::{
+    nodes::statements::ModulePath,
+    tokens::{Keyword, Token, TokenType},
+};
+
+use crate::assuming_iterator::PeekableAssumingIterator;
+
+pub fn parse_use<I: PeekableAssumingIterator>(tokens: &mut I) -> Result<ModulePath, NilangError> {
+    tokens.assume_keyword(Keyword::Use)?;
+
+    let mut path = Vec::new();
+    loop {
+        let (_, _, module) = tokens.assume_identifier()?;
+        path.push(module);
+
+        if let Token {
+            token: TokenType::Semicolon,
+            ..
+        } = tokens.peek_valid()?
+        {
+            break;
+        }
+
+        tokens.assume(TokenType::Colon)?;
+        tokens.assume(TokenType::Colon)?;
+    }
+
+    tokens.assume(TokenType::Semicolon)?;
+
+    Ok(path.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use nilang_types::tokens::{Keyword, Token, TokenType};
+
+    use crate::{multi_peekable::MultiPeekable, parsers::use_parser::parse_use};
+
+    #[test]
+    fn test_parse_use() {
+        assert_eq!(
+            parse_use(&mut MultiPeekable::new(
+                [
+                    Ok(Token {
+                        token: TokenType::Keyword(Keyword::Use),
+                        start: (0, 0),
+                        end: (0, 2),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("math".into()),
+                        start: (0, 4),
+                        end: (0, 7),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 8),
+                        end: (0, 8),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Colon,
+                        start: (0, 9),
+                        end: (0, 9),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Identifier("vector".into()),
+                        start: (0, 10),
+                        end: (0, 15),
+                    }),
+                    Ok(Token {
+                        token: TokenType::Semicolon,
+                        start: (0, 16),
+                        end: (0, 16),
+                    }),
+                ]
+                .into_iter()
+            ))
+            .unwrap(),
+            ["math".into(), "vector".into()].into()
+        );
+    }
+}
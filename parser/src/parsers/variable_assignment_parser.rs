@@ -1,5 +1,8 @@
 use errors::NilangError;
-use nilang_types::{nodes::statements::StatementNode, tokens::TokenType};
+use nilang_types::{
+    nodes::{statements::StatementNode, Span},
+    tokens::TokenType,
+};
 
 use crate::assuming_iterator::PeekableAssumingIterator;
 
@@ -8,7 +11,7 @@ use super::parse_expression;
 pub fn parse_variable_assignment<I: PeekableAssumingIterator>(
     tokens: &mut I,
 ) -> Result<StatementNode, NilangError> {
-    let (_, _, name) = tokens.assume_identifier()?;
+    let (start, end, name) = tokens.assume_identifier()?;
 
     tokens.assume(TokenType::Equals)?;
 
@@ -19,6 +22,7 @@ pub fn parse_variable_assignment<I: PeekableAssumingIterator>(
     Ok(StatementNode::VariableAssignment {
         name,
         value: value.into(),
+        span: Span::new(start, end),
     })
 }
 
@@ -28,6 +32,7 @@ mod tests {
         nodes::{
             expressions::{ExpressionNode, Primitive},
             statements::StatementNode,
+            Span,
         },
         tokens::{Token, TokenType},
     };
@@ -68,7 +73,8 @@ mod tests {
             .unwrap(),
             StatementNode::VariableAssignment {
                 name: "x".into(),
-                value: Box::new(ExpressionNode::Primitive(Primitive::Boolean(true)))
+                value: Box::new(ExpressionNode::Primitive(Primitive::Boolean(true))),
+                span: Span::default()
             }
         );
     }
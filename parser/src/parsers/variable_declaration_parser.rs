@@ -1,6 +1,6 @@
 use errors::NilangError;
 use nilang_types::{
-    nodes::statements::StatementNode,
+    nodes::{statements::StatementNode, Span},
     tokens::{Keyword, TokenType},
 };
 
@@ -13,7 +13,7 @@ pub fn parse_variable_declaration<I: PeekableAssumingIterator>(
 ) -> Result<StatementNode, NilangError> {
     tokens.assume_keyword(Keyword::Variable)?;
 
-    let (_, _, name) = tokens.assume_identifier()?;
+    let (start, end, name) = tokens.assume_identifier()?;
 
     let r#type = parse_type_annotation(tokens)?;
 
@@ -27,6 +27,7 @@ pub fn parse_variable_declaration<I: PeekableAssumingIterator>(
         name,
         r#type,
         value: Box::new(value),
+        span: Span::new(start, end),
     })
 }
 
@@ -36,7 +37,7 @@ mod tests {
         nodes::{
             expressions::{ExpressionNode, Primitive},
             statements::StatementNode,
-            Type,
+            Span, Type,
         },
         tokens::{Keyword, Token, TokenType},
     };
@@ -93,7 +94,8 @@ mod tests {
             StatementNode::VariableDeclaration {
                 name: "test".into(),
                 r#type: Type::Int,
-                value: Box::new(ExpressionNode::Primitive(Primitive::Number(9.)))
+                value: Box::new(ExpressionNode::Primitive(Primitive::Number(9.))),
+                span: Span::new((0, 1), (0, 4)),
             }
         );
 
@@ -142,7 +144,8 @@ mod tests {
             StatementNode::VariableDeclaration {
                 name: "test".into(),
                 r#type: Type::Int,
-                value: Box::new(ExpressionNode::VariableReference("test2".into()))
+                value: Box::new(ExpressionNode::VariableReference("test2".into())),
+                span: Span::new((0, 1), (0, 4)),
             }
         );
     }
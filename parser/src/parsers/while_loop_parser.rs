@@ -1,5 +1,8 @@
 use errors::NilangError;
-use nilang_types::{nodes::statements::StatementNode, tokens::Keyword};
+use nilang_types::{
+    nodes::{statements::StatementNode, Span},
+    tokens::Keyword,
+};
 
 use crate::assuming_iterator::PeekableAssumingIterator;
 
@@ -8,12 +11,16 @@ use super::{parse_expression, scope_parser::parse_scope};
 pub fn parse_while_loop<I: PeekableAssumingIterator>(
     tokens: &mut I,
 ) -> Result<StatementNode, NilangError> {
-    tokens.assume_keyword(Keyword::While)?;
+    let (start, end) = tokens.assume_keyword(Keyword::While)?;
 
     let condition = parse_expression(tokens)?;
     let body = parse_scope(tokens)?;
 
-    Ok(StatementNode::WhileLoop { condition, body })
+    Ok(StatementNode::WhileLoop {
+        condition,
+        body,
+        span: Span::new(start, end),
+    })
 }
 
 #[cfg(test)]
@@ -22,6 +29,7 @@ mod tests {
         nodes::{
             expressions::{ExpressionNode, Primitive},
             statements::StatementNode,
+            Span,
         },
         tokens::{Keyword, Token, TokenType},
     };
@@ -60,6 +68,7 @@ mod tests {
             StatementNode::WhileLoop {
                 condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
                 body: Box::new([]),
+                span: Span::new((0, 0), (0, 1))
             }
         );
     }
@@ -0,0 +1,97 @@
+use std::{
+    env::var,
+    panic::{catch_unwind, set_hook, take_hook},
+};
+
+const FRAGMENTS: [&str; 38] = [
+    "fn ", "vr ", "rt ", "st ", "if ", "ef ", "el ", "wl ", "use ", "pb ", "cn ", "main", "x",
+    ": ", "int", "bool", "(", ")", "{", "}", ",", ".", ";", "=", "+", "-", "*", "/", "%", "==",
+    "<=", ">", "1", "'a'", "\"s\"", "// c\n", "\n", "::",
+];
+
+const PROGRAM: &str = "use math;
+
+pb st Point {
+    x: int,
+    y: char,
+}
+
+cn ORIGIN: int = (1 + 2) * 3;
+
+fn main(): int {
+    vr p: Point = Point { x: 1, y: 'a' };
+    vr f: fn(int): bool = fn(n: int): bool {
+        rt n >= (2 - p.x);
+    };
+    if (f(p.x)) {
+        print(math::square(2));
+    } ef (false) {} el {
+        p = Point { x: 2, y: 3 };
+    }
+    wl (true) {
+        rt 0;
+    }
+    rt \"done\";
+}
+";
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+fn input(random: &mut Random) -> Vec<u8> {
+    let length = random.below(64);
+    match random.below(3) {
+        0 => (0..length).map(|_| random.next() as u8).collect(),
+        1 => (0..length)
+            .flat_map(|_| FRAGMENTS[random.below(FRAGMENTS.len())].bytes())
+            .collect(),
+        _ => {
+            let mut bytes = PROGRAM.as_bytes().to_vec();
+            for _ in 0..=random.below(4) {
+                let start = random.below(bytes.len());
+                let end = (start + random.below(8)).min(bytes.len());
+                let fragment = FRAGMENTS[random.below(FRAGMENTS.len())];
+                bytes.splice(start..end, fragment.bytes());
+            }
+            bytes
+        }
+    }
+}
+
+fn exercise(bytes: &[u8]) {
+    let code = String::from_utf8_lossy(bytes);
+    let _ = nilang_lexer::lex_with_trivia(&code).count();
+    let _ = nilang_parser::parse(nilang_lexer::lex(&code));
+    let _ = nilang_parser::parse_input(nilang_lexer::lex(&code));
+}
+
+#[test]
+fn test_random_bytes_never_panic() {
+    let seed = var("NILANG_FUZZ_SEED").map_or(0, |seed| seed.parse().unwrap());
+    let cases = var("NILANG_FUZZ_CASES").map_or(2000, |cases| cases.parse().unwrap());
+
+    let hook = take_hook();
+    set_hook(Box::new(|_| {}));
+    let mut random = Random(seed);
+    let failures = (0..cases)
+        .map(|_| input(&mut random))
+        .filter(|bytes| catch_unwind(|| exercise(bytes)).is_err())
+        .map(|bytes| format!("{:?}", String::from_utf8_lossy(&bytes)))
+        .collect::<Vec<_>>();
+    set_hook(hook);
+
+    assert!(failures.is_empty(), "panicked on:\n{}", failures.join("\n"));
+}
@@ -1,58 +1,272 @@
-#![feature(iterator_try_collect)]
-
 use std::{
     cell::RefCell,
+    env::args,
     fs::{read_to_string, write},
+    io::stdout,
+    path::Path,
+    process::exit,
 };
 
 use errors::TransformerErrors;
+use modules::{load_modules, Module};
 use nilang_generator::options::{AtAndTFlavour, SystemVAmd64Abi, X86Registers};
-use nilang_transformer::{FunctionsRef, StructuresRef};
+use nilang_interpreter::{Interpreter, IrInterpreter};
+use nilang_linter::{Level, Lint};
+use nilang_optimizer::OptimizationLevel;
+use nilang_types::instructions::{format_program, Data, Functions};
+
+mod modules;
+mod repl;
+
+type Optimization = (OptimizationLevel, usize);
 
 fn main() {
-    let code = read_to_string("test.ni").unwrap();
+    let mut level = OptimizationLevel::default();
+    let mut inline_threshold = None;
+    let arguments = args()
+        .skip(1)
+        .filter(|argument| {
+            if let Some(value) = argument.strip_prefix("-O") {
+                level = value.parse().unwrap_or_else(|err| panic!("{}", err));
+            } else if let Some(value) = argument.strip_prefix("--inline-threshold=") {
+                inline_threshold = Some(value.parse().unwrap_or_else(|_| {
+                    panic!("Invalid inline threshold `{}`", value);
+                }));
+            } else {
+                return true;
+            }
+            false
+        })
+        .collect::<Vec<_>>();
+    let optimization = (level, inline_threshold.unwrap_or(level.inline_threshold()));
+
+    match arguments.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["repl"] => repl::repl(),
+        ["--emit=ast", root] | [root, "--emit=ast"] => emit_ast(&load_modules(Path::new(root))),
+        ["--emit=ir", root] | [root, "--emit=ir"] => {
+            emit_ir(&load_modules(Path::new(root)), optimization)
+        }
+        ["run", root] => interpret(&load_modules(Path::new(root))),
+        ["run", "--ir", root] => interpret_ir(&load_modules(Path::new(root)), optimization),
+        ["fmt", "--check", ref files @ ..] if !files.is_empty() => {
+            if files.iter().filter(|file| !check(file)).count() > 0 {
+                exit(1);
+            }
+        }
+        ["lint", ref arguments @ ..] if !arguments.is_empty() => {
+            if !lint(arguments) {
+                exit(1);
+            }
+        }
+        ["fmt", ref files @ ..] if !files.is_empty() => files.iter().for_each(|file| format(file)),
+        [root] => build(root, optimization),
+        [] => build("test.ni", optimization),
+        _ => panic!("Usage: nilang [-O0|-O1|-O2] [--inline-threshold=<size>] [run [--ir]] <file> | nilang --emit=ast|ir <file> | nilang fmt [--check] <files> | nilang lint [-A|-W|-D <lint>] <files> | nilang repl"),
+    }
+}
+
+fn build(root: &str, optimization: Optimization) {
+    let modules = load_modules(Path::new(root));
+    let compiled = compile(&modules, optimization);
+    write(Path::new(root).with_extension("asm"), compiled.as_ref()).unwrap();
+}
+
+fn emit_ast(modules: &[Module]) {
+    for module in modules {
+        let items = module
+            .uses
+            .iter()
+            .map(|path| format!("use {};", path.join("::")))
+            .chain(module.structures.iter().map(ToString::to_string))
+            .chain(module.globals.iter().map(ToString::to_string))
+            .chain(module.functions.iter().map(ToString::to_string))
+            .collect::<Vec<_>>();
+
+        if !module.path.is_empty() {
+            println!("// {}", module.path.join("::"));
+        }
+        println!("{}\n", items.join("\n\n"));
+    }
+}
+
+fn emit_ir(modules: &[Module], optimization: Optimization) {
+    let (data, functions) = match lower(modules, optimization) {
+        Ok(lowered) => lowered,
+        Err(err) => panic!("{}", err),
+    };
+    print!("{}", format_program(&data, &functions));
+}
+
+fn formatted(file: &str) -> (String, String) {
+    let code = read_to_string(file).unwrap();
+    match nilang_formatter::format(&code) {
+        Ok(formatted) => (code, formatted),
+        Err(err) => panic!("{}: {}", file, err),
+    }
+}
+
+fn format(file: &str) {
+    let (code, formatted) = formatted(file);
+    if code != formatted {
+        write(file, formatted).unwrap();
+    }
+}
+
+fn check(file: &str) -> bool {
+    let (code, formatted) = formatted(file);
+    if code != formatted {
+        println!("{}", file);
+    }
+    code == formatted
+}
+
+fn lint(arguments: &[&str]) -> bool {
+    let mut config = nilang_linter::Config::default();
+    let mut files = Vec::new();
+    let mut arguments = arguments.iter();
+    while let Some(argument) = arguments.next() {
+        let level = match *argument {
+            "-A" => Level::Allow,
+            "-W" => Level::Warn,
+            "-D" => Level::Deny,
+            file => {
+                files.push(file);
+                continue;
+            }
+        };
+
+        let lint = arguments.next().map(|lint| lint.parse::<Lint>());
+        match lint {
+            Some(Ok(lint)) => config.set(lint, level),
+            Some(Err(err)) => panic!("{}", err),
+            None => panic!("Missing lint name after `{}`", argument),
+        }
+    }
+
+    let mut passed = true;
+    for file in files {
+        let code = read_to_string(file).unwrap();
+        let warnings = match nilang_linter::lint(&code, &config) {
+            Ok(warnings) => warnings,
+            Err(err) => panic!("{}: {}", file, err),
+        };
 
-    let compiled = compile(&code);
-    write("test.asm", compiled.as_ref()).unwrap();
+        for warning in warnings {
+            println!("{}: {}", file, warning);
+            passed &= warning.level != Level::Deny;
+        }
+    }
+    passed
 }
 
-fn compile(code: &str) -> Box<str> {
-    let lexed = nilang_lexer::lex(code);
+fn interpret(modules: &[Module]) {
+    let mut interpreter = Interpreter::new(stdout());
+    for module in modules {
+        let prefix = module.prefix();
+        let imports = module
+            .imports(modules)
+            .iter()
+            .map(|module| module.prefix())
+            .collect::<Vec<_>>();
+        interpreter.declare_module(&prefix, &imports);
 
-    let (functions, structures) = match nilang_parser::parse(lexed) {
-        Ok(parsed) => parsed,
-        Err(err) => {
-            panic!("{}", err.format_error(code));
+        for function in &module.functions {
+            interpreter.declare_module_function(&prefix, function);
+        }
+        for structure in &module.structures {
+            interpreter.declare_structure(structure);
+        }
+        for global in &module.globals {
+            interpreter.declare_module_global(&prefix, global);
         }
+    }
+
+    match interpreter.run() {
+        Ok(code) => exit(code as i32),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+fn interpret_ir(modules: &[Module], optimization: Optimization) {
+    let (data, functions) = match lower(modules, optimization) {
+        Ok(lowered) => lowered,
+        Err(err) => panic!("{}", err),
     };
 
-    let context = create_transformer_context(&functions, &structures).unwrap();
+    let mut interpreter = IrInterpreter::new(stdout());
+    interpreter.declare_data(&data);
+    for (name, instructions) in functions {
+        interpreter.declare_function(&name, instructions);
+    }
 
+    match interpreter.call("main", &[]) {
+        Ok(code) => exit(code.unwrap_or_default() as i32),
+        Err(err) => panic!("{}", err),
+    }
+}
+
+type Lowered = (Data, Functions);
+
+fn lower(
+    modules: &[Module],
+    (level, inline_threshold): Optimization,
+) -> Result<Lowered, TransformerErrors> {
     let data = RefCell::new(Vec::new());
-    let transformed = functions.iter().map(|function| {
-        let (function_instructions, mut new_data) =
-            nilang_transformer::transform_function(&context, function);
-        data.borrow_mut().append(&mut new_data);
-        (
-            function.name.clone(),
-            function_instructions.map(|result| match result {
-                Ok(instruction) => instruction,
-                Err(err) => {
-                    panic!("{}", err);
-                }
-            }),
-        )
-    });
+    let mut transformed = Vec::<(Box<str>, Vec<_>)>::new();
+
+    for module in modules {
+        let context = nilang_transformer::module_context(module, modules)?;
+
+        data.borrow_mut().extend(context.2.data().iter().cloned());
+
+        let mut closures = Vec::new();
+        for function in &module.functions {
+            let (function_instructions, mut new_data, mut new_closures) =
+                nilang_transformer::transform_function(&context, function);
+            data.borrow_mut().append(&mut new_data);
+            closures.append(&mut new_closures);
+            let symbol = context.0.get_symbol(&function.name, &function.parameters)?;
+            transformed.push((
+                symbol.into(),
+                function_instructions.collect::<Result<_, _>>()?,
+            ));
+        }
+
+        while let Some(closure) = closures.pop() {
+            let (closure_instructions, mut new_data, mut new_closures) =
+                nilang_transformer::transform_closure(&context, &closure);
+            data.borrow_mut().append(&mut new_data);
+            transformed.push((
+                closure.symbol.clone(),
+                closure_instructions.collect::<Result<_, _>>()?,
+            ));
+            closures.append(&mut new_closures);
+        }
+    }
 
-    let generated = transformed.map(|(name, instructions)| {
+    nilang_optimizer::inline_functions(&mut transformed, inline_threshold);
+    let optimized = transformed
+        .into_iter()
+        .map(|(name, instructions)| (name, nilang_optimizer::optimize(instructions, level)))
+        .collect();
+
+    Ok((data.take(), optimized))
+}
+
+fn compile(modules: &[Module], optimization: Optimization) -> Box<str> {
+    let (data, transformed) = match lower(modules, optimization) {
+        Ok(lowered) => lowered,
+        Err(err) => panic!("{}", err),
+    };
+
+    let generated = transformed.into_iter().map(|(name, instructions)| {
         nilang_generator::generate_function::<X86Registers, SystemVAmd64Abi, AtAndTFlavour>(
             name,
             &data
-                .borrow()
                 .iter()
                 .map(|(name, _)| name.clone())
                 .collect::<Vec<_>>(),
-            instructions,
+            instructions.into_iter(),
         )
         .map(|result| match result {
             Ok(instruction) => instruction,
@@ -69,14 +283,7 @@ fn compile(code: &str) -> Box<str> {
         .join("\n");
 
     let code = ".data\n".to_owned()
-        + &nilang_generator::generate_data::<AtAndTFlavour>(&data.borrow()).collect::<String>()
+        + &nilang_generator::generate_data::<AtAndTFlavour>(&data).collect::<String>()
         + &code;
     code.into()
 }
-
-fn create_transformer_context(
-    functions: &[nilang_types::nodes::statements::FunctionDeclaration],
-    structures: &[nilang_types::nodes::statements::StructureDeclaration],
-) -> Result<(FunctionsRef, StructuresRef), TransformerErrors> {
-    structures.try_into().map(|s| (functions.into(), s))
-}
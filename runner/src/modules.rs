@@ -0,0 +1,63 @@
+use std::{
+    collections::HashSet,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+};
+
+pub use nilang_transformer::Module;
+use nilang_types::nodes::statements::ModulePath;
+
+pub fn load_modules(root: &Path) -> Vec<Module> {
+    let directory = root.parent().unwrap_or(Path::new(""));
+
+    let mut modules = Vec::new();
+    let mut loaded = HashSet::new();
+    let mut pending = vec![ModulePath::default()];
+    while let Some(path) = pending.pop() {
+        if !loaded.insert(path.clone()) {
+            continue;
+        }
+
+        let file = if path.is_empty() {
+            root.to_path_buf()
+        } else {
+            module_file(directory, &path)
+        };
+        let code = match read_to_string(&file) {
+            Ok(code) => code,
+            Err(err) => {
+                panic!(
+                    "Module `{}` could not be read from `{}`: {}",
+                    path.join("::"),
+                    file.display(),
+                    err
+                );
+            }
+        };
+
+        let (functions, structures, uses, globals) =
+            match nilang_parser::parse(nilang_lexer::lex(&code)) {
+                Ok(parsed) => parsed,
+                Err(err) => {
+                    panic!("{}\n{}", file.display(), err.format_error(&code));
+                }
+            };
+
+        pending.extend(uses.iter().cloned());
+        modules.push(Module {
+            path,
+            functions,
+            structures,
+            uses,
+            globals,
+        });
+    }
+
+    modules
+}
+
+fn module_file(directory: &Path, path: &[Box<str>]) -> PathBuf {
+    path.iter()
+        .fold(directory.to_path_buf(), |file, module| file.join(&**module))
+        .with_extension("ni")
+}
@@ -0,0 +1,78 @@
+use std::io::{stdin, stdout, Write};
+
+use errors::{CodeLocation, InterpreterErrors, NilangError, NilangErrorKind, ParserErrors};
+use nilang_interpreter::{Interpreter, Scope, Value};
+use nilang_parser::Input;
+
+pub fn repl() {
+    let mut interpreter = Interpreter::new(stdout());
+    let mut scope = Scope::new();
+    let mut buffer = String::new();
+
+    loop {
+        print!("{}", if buffer.is_empty() { "> " } else { ". " });
+        stdout().flush().unwrap();
+
+        let mut line = String::new();
+        if stdin().read_line(&mut line).unwrap() == 0 {
+            break;
+        }
+        buffer.push_str(&line);
+
+        let inputs = match parse(&buffer) {
+            Err(NilangError {
+                error: NilangErrorKind::ParserError(ParserErrors::EndOfInput),
+                ..
+            }) => continue,
+            Err(err) => {
+                eprintln!("{}", err.format_error(&buffer));
+                buffer.clear();
+                continue;
+            }
+            Ok(inputs) => inputs,
+        };
+        buffer.clear();
+
+        for input in inputs {
+            if let Err(err) = evaluate(&mut interpreter, &mut scope, input) {
+                eprintln!("{}", err);
+                break;
+            }
+        }
+    }
+}
+
+fn evaluate<W: Write>(
+    interpreter: &mut Interpreter<W>,
+    scope: &mut Scope,
+    input: Input,
+) -> Result<(), InterpreterErrors> {
+    match input {
+        Input::Function(function) => interpreter.declare_function(&function),
+        Input::Structure(structure) => interpreter.declare_structure(&structure),
+        Input::Global(global) => interpreter.declare_global(&global),
+        Input::Statement(statement) => {
+            interpreter.execute(&[statement], scope)?;
+        }
+        Input::Expression(expression) => match interpreter.evaluate(&expression, scope)? {
+            Value::Void => {}
+            value => println!("{}", value),
+        },
+    }
+
+    Ok(())
+}
+
+fn parse(code: &str) -> Result<Vec<Input>, NilangError> {
+    match nilang_parser::parse_input(nilang_lexer::lex(code)) {
+        Err(NilangError {
+            error: NilangErrorKind::ParserError(ParserErrors::EndOfInput),
+            ..
+        }) => nilang_parser::parse_input(nilang_lexer::lex(&format!("{};", code.trim_end())))
+            .map_err(|_| NilangError {
+                location: CodeLocation::at(usize::MAX, usize::MAX),
+                error: ParserErrors::EndOfInput.into(),
+            }),
+        result => result,
+    }
+}
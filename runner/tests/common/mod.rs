@@ -0,0 +1,109 @@
+#![allow(dead_code)]
+
+use std::{
+    io::{Read, Write},
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+    thread::{sleep, spawn},
+    time::{Duration, Instant},
+};
+
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+pub fn run(command: &mut Command) -> Output {
+    command.env("NO_COLOR", "1").output().unwrap()
+}
+
+pub fn execute(command: &mut Command) -> Option<(String, String)> {
+    let mut child = command
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    let mut output = child.stdout.take().unwrap();
+    let reader = spawn(move || {
+        let mut stdout = String::new();
+        output.read_to_string(&mut stdout).map(|_| stdout)
+    });
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait().unwrap() {
+            break status;
+        }
+        if started.elapsed() > TIMEOUT {
+            child.kill().unwrap();
+            child.wait().unwrap();
+            return None;
+        }
+        sleep(Duration::from_millis(10));
+    };
+
+    let stdout = reader.join().unwrap().unwrap_or_default();
+    let status = status
+        .code()
+        .map_or_else(|| "signal".to_owned(), |code| code.to_string());
+    Some((stdout, status))
+}
+
+pub fn interact(command: &mut Command, input: &str) -> (String, String) {
+    let mut child = command
+        .env("NO_COLOR", "1")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        String::from_utf8_lossy(&output.stdout).into_owned(),
+        String::from_utf8_lossy(&output.stderr).into_owned(),
+    )
+}
+
+pub fn available(tool: &str) -> bool {
+    Command::new(tool).arg("--version").output().is_ok()
+}
+
+pub fn nilang() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_nilang-runner"))
+}
+
+pub fn link(root: &Path) -> Result<PathBuf, String> {
+    let object = root.with_extension("o");
+    let binary = root.with_extension("bin");
+    let assembled = run(Command::new("as")
+        .arg(root.with_extension("asm"))
+        .arg("-o")
+        .arg(&object));
+    if !assembled.status.success() {
+        return Err(format!(
+            "assembling failed:\n{}",
+            String::from_utf8_lossy(&assembled.stderr)
+        ));
+    }
+
+    let linked = run(Command::new("gcc")
+        .arg(&object)
+        .arg("-o")
+        .arg(&binary)
+        .args(["-nostartfiles", "-no-pie"]));
+    if !linked.status.success() {
+        return Err(format!(
+            "linking failed:\n{}",
+            String::from_utf8_lossy(&linked.stderr)
+        ));
+    }
+
+    Ok(binary)
+}
@@ -0,0 +1,383 @@
+mod common;
+
+use std::{
+    env::{temp_dir, var},
+    fs::{create_dir_all, remove_dir_all, write},
+    path::Path,
+    process::Command,
+};
+
+use common::{available, execute, link, nilang, run};
+use nilang_types::nodes::{
+    expressions::{
+        Arithmetic, Conditional, ExpressionNode, FunctionCall, Operation, Operator, Primitive,
+    },
+    statements::{FunctionDeclaration, StatementNode},
+    Span, Type,
+};
+
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+}
+
+struct Generator {
+    random: Random,
+    functions: Vec<(Box<str>, usize)>,
+    scopes: Vec<Vec<(Box<str>, Type)>>,
+    names: usize,
+}
+
+impl Generator {
+    fn new(seed: u64) -> Self {
+        Generator {
+            random: Random(seed),
+            functions: Vec::new(),
+            scopes: Vec::new(),
+            names: 0,
+        }
+    }
+
+    fn program(&mut self) -> String {
+        let mut functions = Vec::new();
+        for index in 0..self.random.below(4) {
+            let parameters = (0..self.random.below(4))
+                .map(|parameter| (format!("a{}", parameter).into(), Type::Int))
+                .collect::<Box<[_]>>();
+            let name: Box<str> = format!("f{}", index).into();
+            functions.push(self.function(name.clone(), parameters.clone()));
+            self.functions.push((name, parameters.len()));
+        }
+        functions.push(self.function("main".into(), Box::new([])));
+
+        functions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+
+    fn function(
+        &mut self,
+        name: Box<str>,
+        parameters: Box<[(Box<str>, Type)]>,
+    ) -> FunctionDeclaration {
+        self.scopes.push(parameters.to_vec());
+        let mut body = self.statements(0);
+        body.push(StatementNode::Return {
+            value: Box::new(self.int(0)),
+            span: Span::default(),
+        });
+        self.scopes.pop();
+
+        FunctionDeclaration {
+            public: false,
+            constant: false,
+            name,
+            parameters,
+            return_type: Type::Int,
+            body: body.into(),
+            span: Span::default(),
+        }
+    }
+
+    fn name(&mut self, prefix: &str) -> Box<str> {
+        self.names += 1;
+        format!("{}{}", prefix, self.names).into()
+    }
+
+    fn variables(&self, r#type: &Type) -> Vec<Box<str>> {
+        self.scopes
+            .iter()
+            .flatten()
+            .filter(|(_, variable)| variable == r#type)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    fn declare(&mut self, r#type: Type, value: ExpressionNode) -> StatementNode {
+        let name = self.name(if r#type == Type::Int { "v" } else { "b" });
+        self.scopes
+            .last_mut()
+            .unwrap()
+            .push((name.clone(), r#type.clone()));
+        StatementNode::VariableDeclaration {
+            name,
+            r#type,
+            value: Box::new(value),
+            span: Span::default(),
+        }
+    }
+
+    fn block(&mut self, depth: usize) -> Box<[StatementNode]> {
+        self.scopes.push(Vec::new());
+        let body = self.statements(depth + 1);
+        self.scopes.pop();
+        body.into()
+    }
+
+    fn statements(&mut self, depth: usize) -> Vec<StatementNode> {
+        (0..self.random.below(6 - depth * 2) + 1)
+            .flat_map(|_| self.statement(depth))
+            .collect()
+    }
+
+    fn statement(&mut self, depth: usize) -> Vec<StatementNode> {
+        match self.random.below(if depth < 2 { 7 } else { 5 }) {
+            0 | 1 => {
+                let value = self.int(0);
+                vec![self.declare(Type::Int, value)]
+            }
+            2 => {
+                let value = self.boolean();
+                vec![self.declare(Type::Bool, value)]
+            }
+            3 => {
+                let value = self.int(0);
+                match self.variables(&Type::Int).last() {
+                    Some(name) if !name.starts_with('a') => {
+                        vec![StatementNode::VariableAssignment {
+                            name: name.clone(),
+                            value: Box::new(value),
+                            span: Span::default(),
+                        }]
+                    }
+                    _ => vec![],
+                }
+            }
+            4 => vec![StatementNode::FunctionCall(FunctionCall {
+                name: "print".into(),
+                arguments: Box::new([self.int(0)]),
+                span: Span::default(),
+            })],
+            5 => vec![StatementNode::Conditional(self.conditional(depth))],
+            _ => {
+                let flag = self.declare(
+                    Type::Bool,
+                    ExpressionNode::Primitive(Primitive::Boolean(true)),
+                );
+                let StatementNode::VariableDeclaration { name, .. } = &flag else {
+                    unreachable!()
+                };
+                let name = name.clone();
+                let mut body = self.block(depth).into_vec();
+                body.push(StatementNode::VariableAssignment {
+                    name: name.clone(),
+                    value: Box::new(ExpressionNode::Primitive(Primitive::Boolean(false))),
+                    span: Span::default(),
+                });
+                vec![
+                    flag,
+                    StatementNode::WhileLoop {
+                        condition: ExpressionNode::Parenthesis(Box::new(
+                            ExpressionNode::VariableReference(name),
+                        )),
+                        body: body.into(),
+                        span: Span::default(),
+                    },
+                ]
+            }
+        }
+    }
+
+    fn conditional(&mut self, depth: usize) -> Conditional {
+        let condition = ExpressionNode::Parenthesis(Box::new(self.boolean()));
+        let body = self.block(depth);
+        let chained = match self.random.below(3) {
+            0 => None,
+            1 => Some(Box::new(self.conditional(depth))),
+            _ => Some(Box::new(Conditional {
+                condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
+                body: self.block(depth),
+                chained: None,
+                span: Span::default(),
+            })),
+        };
+        Conditional {
+            condition,
+            body,
+            chained,
+            span: Span::default(),
+        }
+    }
+
+    fn boolean(&mut self) -> ExpressionNode {
+        let variables = self.variables(&Type::Bool);
+        if !variables.is_empty() && self.random.chance(50) {
+            ExpressionNode::VariableReference(variables[self.random.below(variables.len())].clone())
+        } else {
+            ExpressionNode::Primitive(Primitive::Boolean(self.random.chance(50)))
+        }
+    }
+
+    fn int(&mut self, depth: usize) -> ExpressionNode {
+        let variables = self.variables(&Type::Int);
+        match self.random.below(if depth < 3 { 5 } else { 2 }) {
+            0 => ExpressionNode::Primitive(Primitive::Number(self.random.below(100) as f64)),
+            1 if !variables.is_empty() => ExpressionNode::VariableReference(
+                variables[self.random.below(variables.len())].clone(),
+            ),
+            1 => ExpressionNode::Primitive(Primitive::Number(self.random.below(10) as f64)),
+            2 if !self.functions.is_empty() => {
+                let (name, parameters) =
+                    self.functions[self.random.below(self.functions.len())].clone();
+                ExpressionNode::FunctionCall(FunctionCall {
+                    name,
+                    arguments: (0..parameters).map(|_| self.int(depth + 1)).collect(),
+                    span: Span::default(),
+                })
+            }
+            _ => {
+                let operator = [
+                    Arithmetic::Add,
+                    Arithmetic::Subtract,
+                    Arithmetic::Multiply,
+                    Arithmetic::Divide,
+                    Arithmetic::Modulo,
+                ][self.random.below(5)];
+                let b = if let Arithmetic::Divide | Arithmetic::Modulo = operator {
+                    ExpressionNode::Primitive(Primitive::Number((self.random.below(9) + 1) as f64))
+                } else {
+                    self.int(depth + 1)
+                };
+                ExpressionNode::Operation(Operation {
+                    operator: Operator::Arithmetic(operator),
+                    a: Box::new(self.int(depth + 1)),
+                    b: Box::new(b),
+                    span: Span::default(),
+                })
+            }
+        }
+    }
+}
+
+type Outcome = Result<(String, String), String>;
+type Backend = (&'static str, fn(&Path) -> Outcome);
+
+fn interpreted(root: &Path) -> Outcome {
+    execute(nilang().arg("run").arg(root)).ok_or_else(|| "timed out".to_owned())
+}
+
+fn lowered(root: &Path) -> Outcome {
+    execute(nilang().args(["run", "--ir"]).arg(root)).ok_or_else(|| "timed out".to_owned())
+}
+
+fn optimized(root: &Path) -> Outcome {
+    execute(nilang().args(["-O2", "run", "--ir"]).arg(root)).ok_or_else(|| "timed out".to_owned())
+}
+
+fn compiled(root: &Path) -> Outcome {
+    build(nilang().arg(root), root)
+}
+
+fn compiled_optimized(root: &Path) -> Outcome {
+    build(nilang().arg("-O2").arg(root), root)
+}
+
+fn build(command: &mut Command, root: &Path) -> Outcome {
+    let compiled = run(command);
+    if !compiled.status.success() {
+        return Err(format!(
+            "compilation failed:\n{}",
+            String::from_utf8_lossy(&compiled.stderr)
+        ));
+    }
+    let binary = link(root)?;
+    execute(&mut Command::new(binary)).ok_or_else(|| "timed out".to_owned())
+}
+
+fn check(seed: u64, directory: &Path, backends: &[Backend]) -> Result<(), String> {
+    let program = Generator::new(seed).program();
+    let root = directory.join(format!("case_{}.ni", seed));
+    write(&root, &program).unwrap();
+
+    let outcomes = backends
+        .iter()
+        .map(|(name, backend)| (name, backend(&root)))
+        .collect::<Vec<_>>();
+    if outcomes
+        .iter()
+        .all(|(_, outcome)| *outcome == outcomes[0].1)
+    {
+        return Ok(());
+    }
+
+    let outcomes = outcomes
+        .iter()
+        .map(|(name, outcome)| format!("{}: {:?}", name, outcome))
+        .collect::<Vec<_>>();
+    Err(format!(
+        "seed {}:\n{}\n{}",
+        seed,
+        outcomes.join("\n"),
+        program
+    ))
+}
+
+fn differential(backends: &[Backend]) {
+    let seed = var("NILANG_FUZZ_SEED").map_or(0, |seed| seed.parse().unwrap());
+    let cases = var("NILANG_FUZZ_CASES").map_or(32, |cases| cases.parse().unwrap());
+
+    let directory = temp_dir().join(format!("nilang-differential-{}", std::process::id()));
+    create_dir_all(&directory).unwrap();
+
+    let failures = (seed..seed + cases)
+        .filter_map(|seed| check(seed, &directory, backends).err())
+        .collect::<Vec<_>>();
+    remove_dir_all(&directory).unwrap();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn test_interpreters_agree() {
+    differential(&[("interpreter", interpreted), ("IR interpreter", lowered)]);
+}
+
+#[test]
+fn test_optimizations_preserve_behaviour() {
+    differential(&[("IR interpreter", lowered), ("optimized", optimized)]);
+}
+
+#[test]
+fn test_compiled_agrees() {
+    if !available("as") || !available("gcc") {
+        eprintln!("Skipping differential testing: `as` and `gcc` are required");
+        return;
+    }
+
+    differential(&[
+        ("interpreter", interpreted),
+        ("binary", compiled),
+        ("binary -O2", compiled_optimized),
+    ]);
+}
+
+#[test]
+fn test_generated_programs_reparse() {
+    for seed in 0..256 {
+        let program = Generator::new(seed).program();
+        let (functions, ..) = nilang_parser::parse(nilang_lexer::lex(&program)).unwrap();
+        let reparsed = functions
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        assert_eq!(reparsed, program, "seed {}", seed);
+    }
+}
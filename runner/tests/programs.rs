@@ -0,0 +1,167 @@
+mod common;
+
+use std::{
+    env::{temp_dir, var_os},
+    fs::{copy, create_dir_all, read_dir, read_to_string, remove_dir_all, write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use common::{available, execute, link, nilang, run};
+use nilang_types::instructions::{format_program, parse_program};
+
+fn expected(program: &Path, extension: &str) -> Option<String> {
+    read_to_string(program.with_extension(extension)).ok()
+}
+
+fn check(program: &Path, directory: &Path, flags: &[&str]) -> Result<(), String> {
+    let stem = program.file_stem().unwrap().to_str().unwrap();
+    let name = &[&[stem], flags].concat().join(" ");
+    let root = directory.join(program.file_name().unwrap());
+    let update = var_os("UPDATE_EXPECT").is_some() && flags.is_empty();
+
+    let compiled = run(nilang().args(flags).arg(&root));
+    let stderr = String::from_utf8_lossy(&compiled.stderr);
+
+    if let Some(diagnostic) = expected(program, "stderr") {
+        if compiled.status.success() {
+            return Err(format!("{}: expected compilation to fail", name));
+        }
+        if !stderr.contains(diagnostic.trim()) {
+            return Err(format!(
+                "{}: expected diagnostic `{}`, got:\n{}",
+                name,
+                diagnostic.trim(),
+                stderr
+            ));
+        }
+        return Ok(());
+    }
+
+    if !compiled.status.success() {
+        return Err(format!("{}: compilation failed:\n{}", name, stderr));
+    }
+
+    let binary = link(&root).map_err(|err| format!("{}: {}", name, err))?;
+
+    let (stdout, status) =
+        execute(&mut Command::new(binary)).ok_or_else(|| format!("{}: timed out", name))?;
+
+    if update {
+        write(program.with_extension("stdout"), &stdout).unwrap();
+        write(program.with_extension("exit"), format!("{}\n", status)).unwrap();
+        return Ok(());
+    }
+
+    let expected_stdout = expected(program, "stdout").unwrap_or_default();
+    let expected_status = expected(program, "exit").unwrap_or_else(|| "0".to_owned());
+    if stdout != expected_stdout || status != expected_status.trim() {
+        return Err(format!(
+            "{}: expected exit code {} with output:\n{}\ngot exit code {} with output:\n{}",
+            name,
+            expected_status.trim(),
+            expected_stdout,
+            status,
+            stdout
+        ));
+    }
+    Ok(())
+}
+
+#[test]
+fn test_programs() {
+    if !available("as") || !available("gcc") {
+        eprintln!("Skipping end-to-end programs: `as` and `gcc` are required");
+        return;
+    }
+
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/programs");
+    let directory = temp_dir().join(format!("nilang-programs-{}", std::process::id()));
+    create_dir_all(&directory).unwrap();
+
+    let mut programs = Vec::<PathBuf>::new();
+    for entry in read_dir(&source).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().is_some_and(|extension| extension == "ni") {
+            copy(&path, directory.join(path.file_name().unwrap())).unwrap();
+            if ["stdout", "exit", "stderr"]
+                .iter()
+                .any(|extension| path.with_extension(extension).exists())
+            {
+                programs.push(path);
+            }
+        }
+    }
+    programs.sort();
+    assert!(!programs.is_empty());
+
+    let failures = programs
+        .iter()
+        .flat_map(|program| {
+            [
+                check(program, &directory, &[]),
+                check(program, &directory, &["-O2"]),
+            ]
+        })
+        .filter_map(Result::err)
+        .collect::<Vec<_>>();
+    remove_dir_all(&directory).unwrap();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn test_programs_format_stably() {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/programs");
+
+    let mut programs = read_dir(&source)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ni"))
+        .collect::<Vec<_>>();
+    programs.sort();
+
+    let failures = programs
+        .iter()
+        .filter_map(|program| {
+            let name = program.file_stem().unwrap().to_str().unwrap();
+            let once = nilang_formatter::format(&read_to_string(program).unwrap()).ok()?;
+            let twice = nilang_formatter::format(&once)
+                .map_err(|err| format!("{}: formatted code does not parse: {}", name, err));
+            match twice {
+                Ok(twice) if twice == once => None,
+                Ok(twice) => Some(format!(
+                    "{}: formatting is not stable:\n{}\nthen:\n{}",
+                    name, once, twice
+                )),
+                Err(err) => Some(err),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n\n"));
+}
+
+#[test]
+fn test_programs_emit_parsable_ir() {
+    let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/programs");
+
+    let mut programs = read_dir(&source)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "ni"))
+        .filter(|path| path.with_extension("stdout").exists())
+        .collect::<Vec<_>>();
+    programs.sort();
+    assert!(!programs.is_empty());
+
+    for program in programs {
+        let emitted = run(nilang().arg("--emit=ir").arg(&program));
+        assert!(emitted.status.success(), "{}", program.display());
+
+        let ir = String::from_utf8(emitted.stdout).unwrap();
+        let (data, functions) =
+            parse_program(&ir).unwrap_or_else(|err| panic!("{}: {}", program.display(), err));
+        assert_eq!(format_program(&data, &functions), ir);
+    }
+}
@@ -0,0 +1,258 @@
+.data
+printi_format: .asciz "%ld\n"
+print_format: .asciz "%s\n"
+printc_format: .asciz "%c\n"
+.text                         # 
+.globl _start                 # 
+_start:                       # 
+call main                     # 
+movq %rax, %rdi               # 
+call exit                     # 
+.globl main
+main:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $8, %rsp
+    pushq %rbx
+    pushq %r12
+    pushq %r13
+    pushq %r14
+    pushq %r15
+    movq $5, %rbx                 # Load number '5' into `offset`
+    movq $1, %r12                 # Load number '1' into `origin.x`
+    movq $2, %r13                 # Load number '2' into `origin.y`
+    movq $16, %rdi                # Load number '16' into `temp_0`
+    call malloc                   # Call function `malloc`
+    movq %rax, %r14               # Move result of `malloc` to return register
+    movq $main__closure_0, %rax   # Load `main__closure_0` function pointer into `temp_1`
+    movq %r14, %r10               # Load address `f`
+    movq %rax, %r11               # Load `temp_1` for store
+    movq %r11, 0(%r10)            # Store `temp_1` as value 0 of `f`
+    movq %r14, %r10               # Load address `f`
+    movq %rbx, %r11               # Load `offset` for store
+    movq %r11, 8(%r10)            # Store `offset` as value 1 of `f`
+    movq $24, %rdi                # Load number '24' into `temp_2`
+    call malloc                   # Call function `malloc`
+    movq %rax, %r15               # Move result of `malloc` to return register
+    movq $main__closure_1, %rax   # Load `main__closure_1` function pointer into `temp_3`
+    movq %r15, %r10               # Load address `g`
+    movq %rax, %r11               # Load `temp_3` for store
+    movq %r11, 0(%r10)            # Store `temp_3` as value 0 of `g`
+    movq %r15, %r10               # Load address `g`
+    movq %r12, %r11               # Load `origin.x` for store
+    movq %r11, 8(%r10)            # Store `origin.x` as value 1 of `g`
+    movq %r15, %r10               # Load address `g`
+    movq %r13, %r11               # Load `origin.y` for store
+    movq %r11, 16(%r10)           # Store `origin.y` as value 2 of `g`
+    movq $32, %rdi                # Load number '32' into `temp_4`
+    call malloc                   # Call function `malloc`
+    movq %rax, -8(%rbp)           # Move result of `malloc` to return register
+    movq $main__closure_2, %rax   # Load `main__closure_2` function pointer into `temp_5`
+    movq -8(%rbp), %r10           # Load address `h`
+    movq %rax, %r11               # Load `temp_5` for store
+    movq %r11, 0(%r10)            # Store `temp_5` as value 0 of `h`
+    movq -8(%rbp), %r10           # Load address `h`
+    movq %r14, %r11               # Load `f` for store
+    movq %r11, 8(%r10)            # Store `f` as value 1 of `h`
+    movq -8(%rbp), %r10           # Load address `h`
+    movq %r15, %r11               # Load `g` for store
+    movq %r11, 16(%r10)           # Store `g` as value 2 of `h`
+    movq -8(%rbp), %r10           # Load address `h`
+    movq %rbx, %r11               # Load `offset` for store
+    movq %r11, 24(%r10)           # Store `offset` as value 3 of `h`
+    movq %r13, %rdi               # Load `origin.y` as argument 0
+    movq %r14, %rsi               # Load `f` as argument 1
+    movq %r14, %r11               # Load `f` as callee
+    movq 0(%r11), %r11            # Load code pointer of `f`
+    call *%r11                    # Call function value `f`
+    movq %rax, %rsi               # Move result of `f` to return register
+    movq $printi_format, %rdi     # Load `printi_format` as argument 0
+    movb $0, %al                  # No vector registers used by variadic call
+    call printf                   # Call function `printf`
+    movq $4, %rax                 # Load number '4' into `temp_9`
+    movq %rax, %rdi               # Load `temp_9` as argument 0
+    movq %r15, %rsi               # Load `g` as argument 1
+    movq %r15, %r11               # Load `g` as callee
+    movq 0(%r11), %r11            # Load code pointer of `g`
+    call *%r11                    # Call function value `g`
+    movq %rax, %rsi               # Move result of `g` to return register
+    movq $printi_format, %rdi     # Load `printi_format` as argument 0
+    movb $0, %al                  # No vector registers used by variadic call
+    call printf                   # Call function `printf`
+    movq %r12, %rdi               # Load `origin.x` as argument 0
+    movq -8(%rbp), %rsi           # Load `h` as argument 1
+    movq -8(%rbp), %r11           # Load `h` as callee
+    movq 0(%r11), %r11            # Load code pointer of `h`
+    call *%r11                    # Call function value `h`
+    movq %rax, %rsi               # Move result of `h` to return register
+    movq $printi_format, %rdi     # Load `printi_format` as argument 0
+    movb $0, %al                  # No vector registers used by variadic call
+    call printf                   # Call function `printf`
+    movq $0, %rax                 # Load number '0' into `temp_14`
+    movq %rax, %rdi               # Load `temp_14` as argument 0
+    movq -8(%rbp), %rsi           # Load `h` as argument 1
+    movq -8(%rbp), %r11           # Load `h` as callee
+    movq 0(%r11), %r11            # Load code pointer of `h`
+    call *%r11                    # Call function value `h`
+    movq %rax, %rbx               # Move result of `h` to return register
+    movq $10, %rax                # Load number '10' into `temp_15`
+    movq %rbx, %rcx               # Prepare `temp_12` for subtraction
+    subq %rax, %rcx               # Subtract `temp_15` from `temp_12`
+    movq %rcx, %rax               # Move `temp_12` to return register
+    popq %r15                     # Return `temp_12`
+    popq %r14
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %r15
+    popq %r14
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+
+.globl main__closure_2
+main__closure_2:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    pushq %rbx
+    pushq %r12
+    pushq %r13
+    pushq %r14
+    movq %rdi, %rbx               # Load `n` as argument 0
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 8(%r11), %r11            # Load value 1 of `closure__env`
+    movq %r11, %r12               # Move value 1 of `closure__env` into `f`
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 16(%r11), %r11           # Load value 2 of `closure__env`
+    movq %r11, %r13               # Move value 2 of `closure__env` into `g`
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 24(%r11), %r11           # Load value 3 of `closure__env`
+    movq %r11, %r14               # Move value 3 of `closure__env` into `offset`
+    movq $24, %rdi                # Load number '24' into `temp_0`
+    call malloc                   # Call function `malloc`
+    movq $main__closure_2__closure_0, %rcx # Load `main__closure_2__closure_0` function pointer into `temp_1`
+    movq %rax, %r10               # Load address `inner`
+    movq %rcx, %r11               # Load `temp_1` for store
+    movq %r11, 0(%r10)            # Store `temp_1` as value 0 of `inner`
+    movq %rax, %r10               # Load address `inner`
+    movq %r12, %r11               # Load `f` for store
+    movq %r11, 8(%r10)            # Store `f` as value 1 of `inner`
+    movq %rax, %r10               # Load address `inner`
+    movq %r14, %r11               # Load `offset` for store
+    movq %r11, 16(%r10)           # Store `offset` as value 2 of `inner`
+    movq %rbx, %rdi               # Load `n` as argument 0
+    movq %rax, %rsi               # Load `inner` as argument 1
+    movq %rax, %r11               # Load `inner` as callee
+    movq 0(%r11), %r11            # Load code pointer of `inner`
+    call *%r11                    # Call function value `inner`
+    movq %rax, %r12               # Move result of `inner` to return register
+    movq %rbx, %rdi               # Load `n` as argument 0
+    movq %r13, %rsi               # Load `g` as argument 1
+    movq %r13, %r11               # Load `g` as callee
+    movq 0(%r11), %r11            # Load code pointer of `g`
+    call *%r11                    # Call function value `g`
+    leaq (%r12,%rax), %rbx        # Add `temp_3` and `temp_5` into `temp_2`
+    movq %rbx, %rax               # Move `temp_2` to return register
+    popq %r14                     # Return `temp_2`
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %r14
+    popq %r13
+    popq %r12
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+
+.globl main__closure_2__closure_0
+main__closure_2__closure_0:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $8, %rsp
+    pushq %rbx
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 8(%r11), %r11            # Load value 1 of `closure__env`
+    movq %r11, %rax               # Move value 1 of `closure__env` into `f`
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 16(%r11), %r11           # Load value 2 of `closure__env`
+    movq %r11, %rbx               # Move value 2 of `closure__env` into `offset`
+    movq %rax, %rsi               # Load `f` as argument 1
+    movq %rax, %r11               # Load `f` as callee
+    movq 0(%r11), %r11            # Load code pointer of `f`
+    call *%r11                    # Call function value `f`
+    movq %rax, %rcx               # Move result of `f` to return register
+    leaq (%rcx,%rbx), %rax        # Add `temp_1` and `offset` into `temp_0`
+    popq %rbx                     # Return `temp_0`
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+
+.globl main__closure_1
+main__closure_1:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $8, %rsp
+    pushq %rbx
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 8(%r11), %r11            # Load value 1 of `closure__env`
+    movq %r11, %rax               # Move value 1 of `closure__env` into `origin.x`
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 16(%r11), %r11           # Load value 2 of `closure__env`
+    movq %r11, %rbx               # Move value 2 of `closure__env` into `origin.y`
+    leaq (%rax,%rbx), %rcx        # Add `origin.x` and `origin.y` into `temp_2`
+    leaq (%rdi,%rcx), %rax        # Add `n` and `temp_2` into `temp_0`
+    popq %rbx                     # Return `temp_0`
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+
+.globl main__closure_0
+main__closure_0:
+    # Prologue
+    pushq %rbp
+    movq %rsp, %rbp
+    subq $8, %rsp
+    pushq %rbx
+    movq %rsi, %r11               # Load address `closure__env`
+    movq 8(%r11), %r11            # Load value 1 of `closure__env`
+    movq %r11, %rax               # Move value 1 of `closure__env` into `offset`
+    movq $6, %rbx                 # Load number '6' into `temp_3`
+    movq %rdi, %rcx               # Prepare `temp_1` for multiplication
+    imulq %rbx, %rcx              # Multiply `n` and `temp_3` into `temp_1`
+    leaq (%rcx,%rax), %rbx        # Add `temp_1` and `offset` into `temp_0`
+    movq %rbx, %rax               # Move `temp_0` to return register
+    popq %rbx                     # Return `temp_0`
+    movq %rbp, %rsp
+    pop %rbp
+    ret
+    # Epilogue
+    popq %rbx
+    movq %rbp, %rsp
+    pop %rbp
+    ret
@@ -0,0 +1,32 @@
+mod common;
+
+use common::{interact, nilang};
+
+fn repl(input: &str) -> (String, String) {
+    interact(nilang().arg("repl"), input)
+}
+
+#[test]
+fn test_repl_keeps_state() {
+    let (stdout, stderr) = repl("vr x: int = 2;\nx = x + 3;\nprint(x);\nx * 2\n");
+
+    assert_eq!(stdout, "> > > 5\n> 10\n> ");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn test_repl_continues_lines() {
+    let (stdout, stderr) = repl("fn double(n: int): int {\n    rt n * 2;\n}\nprint(double(4));\n");
+
+    assert_eq!(stdout, "> . . > 8\n> ");
+    assert_eq!(stderr, "");
+}
+
+#[test]
+fn test_repl_recovers_from_errors() {
+    let (stdout, stderr) = repl("vr x: int = 1;\nprint(missing);\nvr y: int = ;\nprint(x);\n");
+
+    assert_eq!(stdout, "> > > > 1\n> ");
+    assert!(stderr.contains("Variable not found: `missing`"));
+    assert!(stderr.contains("Parser: Unexpected token semicolon"));
+}
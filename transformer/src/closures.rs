@@ -0,0 +1,272 @@
+use std::{cell::RefCell, collections::BTreeSet};
+
+use nilang_types::nodes::{
+    expressions::{Conditional, ExpressionNode, FunctionCall, Operation},
+    statements::{Parameter, StatementNode},
+    Type,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Closure {
+    pub symbol: Box<str>,
+    pub parameters: Box<[Parameter]>,
+    pub captures: Box<[Parameter]>,
+    pub return_type: Type,
+    pub body: Box<[StatementNode]>,
+}
+
+#[derive(Debug, Default)]
+pub struct Closures(Box<str>, RefCell<Vec<Closure>>);
+
+impl Closures {
+    pub fn new(prefix: &str) -> Self {
+        Self(prefix.into(), RefCell::default())
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.0
+    }
+
+    pub fn lift(
+        &self,
+        parameters: Box<[Parameter]>,
+        captures: Box<[Parameter]>,
+        return_type: Type,
+        body: Box<[StatementNode]>,
+    ) -> Box<str> {
+        let mut lifted = self.1.borrow_mut();
+        let symbol = <Box<str>>::from(format!("{}__closure_{}", self.0, lifted.len()));
+        lifted.push(Closure {
+            symbol: symbol.clone(),
+            parameters,
+            captures,
+            return_type,
+            body,
+        });
+        symbol
+    }
+
+    pub fn take(&self) -> Vec<Closure> {
+        self.1.take()
+    }
+}
+
+type Scopes = Vec<BTreeSet<Box<str>>>;
+
+pub fn free_variables(parameters: &[Parameter], body: &[StatementNode]) -> BTreeSet<Box<str>> {
+    let mut free = BTreeSet::new();
+    let mut scopes = vec![parameters
+        .iter()
+        .map(|(name, _)| name.clone())
+        .collect::<BTreeSet<_>>()];
+
+    block_names(body, &mut free, &mut scopes);
+
+    free
+}
+
+fn reference(name: &str, free: &mut BTreeSet<Box<str>>, scopes: &Scopes) {
+    if !scopes.iter().any(|scope| scope.contains(name)) {
+        free.insert(name.into());
+    }
+}
+
+fn block_names(body: &[StatementNode], free: &mut BTreeSet<Box<str>>, scopes: &mut Scopes) {
+    scopes.push(BTreeSet::new());
+    for statement in body {
+        statement_names(statement, free, scopes);
+    }
+    scopes.pop();
+}
+
+fn statement_names(statement: &StatementNode, free: &mut BTreeSet<Box<str>>, scopes: &mut Scopes) {
+    match statement {
+        StatementNode::VariableDeclaration { name, value, .. } => {
+            expression_names(value, free, scopes);
+            scopes.last_mut().unwrap().insert(name.clone());
+        }
+        StatementNode::VariableAssignment { name, value, .. } => {
+            reference(name, free, scopes);
+            expression_names(value, free, scopes);
+        }
+        StatementNode::Return { value, .. } => expression_names(value, free, scopes),
+        StatementNode::FunctionCall(FunctionCall {
+            name, arguments, ..
+        }) => {
+            reference(name, free, scopes);
+            for argument in arguments.iter() {
+                expression_names(argument, free, scopes);
+            }
+        }
+        StatementNode::Conditional(conditional) => {
+            let mut conditional = Some(conditional);
+            while let Some(Conditional {
+                condition,
+                body,
+                chained,
+                ..
+            }) = conditional
+            {
+                expression_names(condition, free, scopes);
+                block_names(body, free, scopes);
+                conditional = chained.as_deref();
+            }
+        }
+        StatementNode::WhileLoop {
+            condition, body, ..
+        } => {
+            expression_names(condition, free, scopes);
+            block_names(body, free, scopes);
+        }
+    }
+}
+
+fn expression_names(expression: &ExpressionNode, free: &mut BTreeSet<Box<str>>, scopes: &Scopes) {
+    match expression {
+        ExpressionNode::Primitive(_) => {}
+        ExpressionNode::VariableReference(name) => reference(name, free, scopes),
+        ExpressionNode::FieldAccess { structure, .. } => expression_names(structure, free, scopes),
+        ExpressionNode::FunctionCall(FunctionCall {
+            name, arguments, ..
+        }) => {
+            reference(name, free, scopes);
+            for argument in arguments.iter() {
+                expression_names(argument, free, scopes);
+            }
+        }
+        ExpressionNode::Parenthesis(expression) => expression_names(expression, free, scopes),
+        ExpressionNode::Operation(Operation { a, b, .. }) => {
+            expression_names(a, free, scopes);
+            expression_names(b, free, scopes);
+        }
+        ExpressionNode::Object { fields, .. } => {
+            for value in fields.values() {
+                expression_names(value, free, scopes);
+            }
+        }
+        ExpressionNode::Closure {
+            parameters, body, ..
+        } => {
+            for name in free_variables(parameters, body) {
+                reference(&name, free, scopes);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use nilang_types::nodes::{
+        expressions::{Arithmetic, Conditional, ExpressionNode, Operation, Operator, Primitive},
+        statements::StatementNode,
+        Span, Type,
+    };
+
+    use super::{free_variables, Closures};
+
+    #[test]
+    fn test_free_variables() {
+        assert_eq!(
+            free_variables(
+                &[("x".into(), Type::Int)],
+                &[
+                    StatementNode::VariableDeclaration {
+                        name: "local".into(),
+                        r#type: Type::Int,
+                        value: Box::new(ExpressionNode::FieldAccess {
+                            structure: Box::new(ExpressionNode::VariableReference("point".into())),
+                            field: "x".into(),
+                        }),
+                        span: Span::default(),
+                    },
+                    StatementNode::Return {
+                        value: Box::new(ExpressionNode::Operation(Operation {
+                            operator: Operator::Arithmetic(Arithmetic::Add),
+                            a: Box::new(ExpressionNode::VariableReference("x".into())),
+                            b: Box::new(ExpressionNode::Closure {
+                                parameters: [].into(),
+                                return_type: Type::Int,
+                                body: [StatementNode::Return {
+                                    value: Box::new(ExpressionNode::VariableReference(
+                                        "offset".into()
+                                    ),),
+                                    span: Span::default()
+                                }]
+                                .into(),
+                            }),
+                            span: Span::default()
+                        })),
+                        span: Span::default()
+                    },
+                    StatementNode::VariableAssignment {
+                        name: "local".into(),
+                        value: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                        span: Span::default()
+                    },
+                ],
+            ),
+            BTreeSet::from(["offset".into(), "point".into()])
+        );
+    }
+
+    #[test]
+    fn test_free_variables_respect_scopes() {
+        let reference = |name: &str| Box::new(ExpressionNode::VariableReference(name.into()));
+        let declaration = |name: &str| StatementNode::VariableDeclaration {
+            name: name.into(),
+            r#type: Type::Int,
+            value: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+            span: Span::default(),
+        };
+
+        assert_eq!(
+            free_variables(
+                &[],
+                &[
+                    StatementNode::Conditional(Conditional {
+                        condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
+                        body: [declaration("inner")].into(),
+                        chained: None,
+                        span: Span::default()
+                    }),
+                    StatementNode::Return {
+                        value: reference("before"),
+                        span: Span::default()
+                    },
+                    declaration("before"),
+                    StatementNode::WhileLoop {
+                        condition: ExpressionNode::Primitive(Primitive::Boolean(true)),
+                        body: [StatementNode::Return {
+                            value: reference("before"),
+                            span: Span::default()
+                        }]
+                        .into(),
+                        span: Span::default()
+                    },
+                    StatementNode::Return {
+                        value: reference("inner"),
+                        span: Span::default()
+                    },
+                ],
+            ),
+            BTreeSet::from(["before".into(), "inner".into()])
+        );
+    }
+
+    #[test]
+    fn test_lift() {
+        let closures = Closures::new("main");
+
+        assert_eq!(
+            closures.lift([].into(), [].into(), Type::Void, [].into()),
+            "main__closure_0".into()
+        );
+        assert_eq!(
+            closures.lift([].into(), [].into(), Type::Void, [].into()),
+            "main__closure_1".into()
+        );
+        assert_eq!(closures.take().len(), 2);
+    }
+}
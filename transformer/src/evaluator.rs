@@ -0,0 +1,457 @@
+use std::collections::HashMap;
+
+use errors::TransformerErrors;
+use nilang_types::nodes::{
+    expressions::{
+        Arithmetic, Boolean, Conditional, ExpressionNode, FunctionCall, Operation, Operator,
+        Primitive,
+    },
+    statements::{FunctionDeclaration, StatementNode},
+    Span, Type,
+};
+
+use crate::FunctionsRef;
+
+const MAX_STEPS: usize = 1_000_000;
+const MAX_DEPTH: usize = 128;
+
+type Lookup<'a> = dyn FnMut(&str) -> Result<Primitive, TransformerErrors> + 'a;
+type Scope = HashMap<Box<str>, Primitive>;
+
+pub struct Evaluator<'a, 'b> {
+    functions: &'a FunctionsRef,
+    constants: &'a mut Lookup<'b>,
+    location: Box<str>,
+    steps: usize,
+    depth: usize,
+}
+
+impl<'a, 'b> Evaluator<'a, 'b> {
+    pub fn new(functions: &'a FunctionsRef, location: &str, constants: &'a mut Lookup<'b>) -> Self {
+        Self {
+            functions,
+            constants,
+            location: location.into(),
+            steps: 0,
+            depth: 0,
+        }
+    }
+
+    pub fn evaluate(
+        &mut self,
+        expression: &ExpressionNode,
+    ) -> Result<Primitive, TransformerErrors> {
+        self.expression(expression, &Scope::new())
+    }
+
+    fn expression(
+        &mut self,
+        expression: &ExpressionNode,
+        scope: &Scope,
+    ) -> Result<Primitive, TransformerErrors> {
+        self.step()?;
+
+        match expression {
+            ExpressionNode::Primitive(Primitive::String(_)) => Err(self.not_constant()),
+            ExpressionNode::Primitive(primitive) => Ok(primitive.clone()),
+            ExpressionNode::VariableReference(name) => match scope.get(name) {
+                Some(primitive) => Ok(primitive.clone()),
+                None => (self.constants)(name),
+            },
+            ExpressionNode::Parenthesis(expression) => self.expression(expression, scope),
+            ExpressionNode::Operation(Operation {
+                operator,
+                a,
+                b,
+                span,
+            }) => {
+                let a = self.expression(a, scope)?;
+                let b = self.expression(b, scope)?;
+                self.operation(*operator, *span, a, b)
+            }
+            ExpressionNode::FunctionCall(call) => self.call(call, scope),
+            ExpressionNode::FieldAccess { .. }
+            | ExpressionNode::Object { .. }
+            | ExpressionNode::Closure { .. } => Err(self.not_constant()),
+        }
+    }
+
+    fn call(
+        &mut self,
+        FunctionCall {
+            name, arguments, ..
+        }: &FunctionCall,
+        scope: &Scope,
+    ) -> Result<Primitive, TransformerErrors> {
+        let arguments = arguments
+            .iter()
+            .map(|argument| self.expression(argument, scope))
+            .collect::<Result<Vec<_>, _>>()?;
+        let types = arguments.iter().map(type_of_primitive).collect::<Vec<_>>();
+
+        let functions = self.functions;
+        let (symbol, _, _) = functions.resolve(name, &types)?;
+        let Some(FunctionDeclaration {
+            parameters,
+            return_type,
+            body,
+            ..
+        }) = functions.constant(symbol)
+        else {
+            return Err(TransformerErrors::NotConstant { name: name.clone() });
+        };
+
+        if self.depth == MAX_DEPTH {
+            return Err(TransformerErrors::EvaluationLimit { name: name.clone() });
+        }
+
+        let mut locals = parameters
+            .iter()
+            .map(|(parameter, _)| parameter.clone())
+            .zip(arguments)
+            .collect::<Scope>();
+
+        let location = std::mem::replace(&mut self.location, name.clone());
+        self.depth += 1;
+        let returned = self.body(body, &mut locals);
+        self.depth -= 1;
+
+        let returned = match returned? {
+            Some(primitive) => primitive,
+            None => return Err(self.not_constant()),
+        };
+        let found = type_of_primitive(&returned);
+        if found != *return_type {
+            return Err(TransformerErrors::TypeMismatch {
+                expected: return_type.clone(),
+                found,
+            });
+        }
+
+        self.location = location;
+        Ok(returned)
+    }
+
+    fn body(
+        &mut self,
+        body: &[StatementNode],
+        scope: &mut Scope,
+    ) -> Result<Option<Primitive>, TransformerErrors> {
+        for statement in body {
+            self.step()?;
+
+            match statement {
+                StatementNode::VariableDeclaration {
+                    name,
+                    r#type,
+                    value,
+                    ..
+                } => {
+                    let value = self.expression(value, scope)?;
+                    let found = type_of_primitive(&value);
+                    if found != *r#type {
+                        return Err(TransformerErrors::TypeMismatch {
+                            expected: r#type.clone(),
+                            found,
+                        });
+                    }
+                    scope.insert(name.clone(), value);
+                }
+                StatementNode::VariableAssignment { name, value, .. } => {
+                    let value = self.expression(value, scope)?;
+                    let Some(variable) = scope.get_mut(name) else {
+                        return Err(TransformerErrors::NotConstant { name: name.clone() });
+                    };
+                    if type_of_primitive(variable) != type_of_primitive(&value) {
+                        return Err(TransformerErrors::TypeMismatch {
+                            expected: type_of_primitive(variable),
+                            found: type_of_primitive(&value),
+                        });
+                    }
+                    *variable = value;
+                }
+                StatementNode::Return { value, .. } => {
+                    return self.expression(value, scope).map(Some)
+                }
+                StatementNode::FunctionCall(call) => {
+                    self.call(call, scope)?;
+                }
+                StatementNode::Conditional(conditional) => {
+                    if let Some(returned) = self.conditional(conditional, scope)? {
+                        return Ok(Some(returned));
+                    }
+                }
+                StatementNode::WhileLoop {
+                    condition, body, ..
+                } => {
+                    while self.condition(condition, scope)? {
+                        if let Some(returned) = self.block(body, scope)? {
+                            return Ok(Some(returned));
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(None)
+    }
+
+    fn conditional(
+        &mut self,
+        Conditional {
+            condition,
+            body,
+            chained,
+            ..
+        }: &Conditional,
+        scope: &mut Scope,
+    ) -> Result<Option<Primitive>, TransformerErrors> {
+        if self.condition(condition, scope)? {
+            self.block(body, scope)
+        } else if let Some(chained) = chained {
+            self.conditional(chained, scope)
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn block(
+        &mut self,
+        body: &[StatementNode],
+        scope: &mut Scope,
+    ) -> Result<Option<Primitive>, TransformerErrors> {
+        let mut inner = scope.clone();
+        let returned = self.body(body, &mut inner)?;
+        for (name, value) in scope.iter_mut() {
+            *value = inner[name].clone();
+        }
+        Ok(returned)
+    }
+
+    fn condition(
+        &mut self,
+        condition: &ExpressionNode,
+        scope: &Scope,
+    ) -> Result<bool, TransformerErrors> {
+        match self.expression(condition, scope)? {
+            Primitive::Boolean(condition) => Ok(condition),
+            primitive => Err(TransformerErrors::TypeMismatch {
+                expected: Type::Bool,
+                found: type_of_primitive(&primitive),
+            }),
+        }
+    }
+
+    fn operation(
+        &self,
+        operator: Operator,
+        span: Span,
+        a: Primitive,
+        b: Primitive,
+    ) -> Result<Primitive, TransformerErrors> {
+        Ok(match (operator, a, b) {
+            (Operator::Arithmetic(arithmetic), Primitive::Number(a), Primitive::Number(b)) => {
+                let (a, b) = (a as i64, b as i64);
+                if b == 0 && matches!(arithmetic, Arithmetic::Divide | Arithmetic::Modulo) {
+                    return Err(TransformerErrors::DivisionByZero {
+                        name: self.location.clone(),
+                        span,
+                    });
+                }
+
+                let result = match arithmetic {
+                    Arithmetic::Add => a.checked_add(b),
+                    Arithmetic::Subtract => a.checked_sub(b),
+                    Arithmetic::Multiply => a.checked_mul(b),
+                    Arithmetic::Divide => a.checked_div(b),
+                    Arithmetic::Modulo => a.checked_rem(b),
+                };
+                Primitive::Number(result.ok_or_else(|| TransformerErrors::Overflow {
+                    name: self.location.clone(),
+                    span,
+                })? as f64)
+            }
+            (Operator::Boolean(boolean), a, b)
+                if type_of_primitive(&a) == type_of_primitive(&b) =>
+            {
+                let ordering = match (a, b) {
+                    (Primitive::Number(a), Primitive::Number(b)) => a.partial_cmp(&b),
+                    (Primitive::Char(a), Primitive::Char(b)) => a.partial_cmp(&b),
+                    (Primitive::Boolean(a), Primitive::Boolean(b)) => a.partial_cmp(&b),
+                    _ => None,
+                }
+                .ok_or_else(|| self.not_constant())?;
+
+                Primitive::Boolean(match boolean {
+                    Boolean::Equal => ordering.is_eq(),
+                    Boolean::NotEqual => ordering.is_ne(),
+                    Boolean::Less => ordering.is_lt(),
+                    Boolean::More => ordering.is_gt(),
+                    Boolean::LessOrEqual => ordering.is_le(),
+                    Boolean::MoreOrEqual => ordering.is_ge(),
+                })
+            }
+            _ => return Err(self.not_constant()),
+        })
+    }
+
+    fn step(&mut self) -> Result<(), TransformerErrors> {
+        self.steps += 1;
+        if self.steps > MAX_STEPS {
+            return Err(TransformerErrors::EvaluationLimit {
+                name: self.location.clone(),
+            });
+        }
+        Ok(())
+    }
+
+    fn not_constant(&self) -> TransformerErrors {
+        TransformerErrors::NotConstant {
+            name: self.location.clone(),
+        }
+    }
+}
+
+pub fn type_of_primitive(primitive: &Primitive) -> Type {
+    match primitive {
+        Primitive::Boolean(_) => Type::Bool,
+        Primitive::Number(_) => Type::Int,
+        Primitive::Char(_) => Type::Char,
+        Primitive::String(_) => Type::String,
+    }
+}
+
+pub fn is_evaluation_error(error: &TransformerErrors) -> bool {
+    matches!(
+        error,
+        TransformerErrors::Overflow { .. } | TransformerErrors::DivisionByZero { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use errors::TransformerErrors;
+    use nilang_types::nodes::{
+        expressions::{
+            Arithmetic, Boolean, Conditional, ExpressionNode, FunctionCall, Operation, Operator,
+            Primitive,
+        },
+        statements::{FunctionDeclaration, StatementNode},
+        Span, Type,
+    };
+
+    use crate::FunctionsRef;
+
+    use super::Evaluator;
+
+    fn operation(operator: Operator, a: ExpressionNode, b: ExpressionNode) -> ExpressionNode {
+        located(operator, a, b, Span::default())
+    }
+
+    fn located(
+        operator: Operator,
+        a: ExpressionNode,
+        b: ExpressionNode,
+        span: Span,
+    ) -> ExpressionNode {
+        ExpressionNode::Operation(Operation {
+            operator,
+            a: Box::new(a),
+            b: Box::new(b),
+            span,
+        })
+    }
+
+    fn number(number: f64) -> ExpressionNode {
+        ExpressionNode::Primitive(Primitive::Number(number))
+    }
+
+    fn call(name: &str, argument: ExpressionNode) -> ExpressionNode {
+        ExpressionNode::FunctionCall(FunctionCall {
+            name: name.into(),
+            arguments: Box::new([argument]),
+            span: Span::default(),
+        })
+    }
+
+    fn factorial() -> FunctionDeclaration {
+        let n = || ExpressionNode::VariableReference("n".into());
+        FunctionDeclaration {
+            public: false,
+            constant: true,
+            name: "factorial".into(),
+            parameters: Box::new([("n".into(), Type::Int)]),
+            return_type: Type::Int,
+            body: Box::new([
+                StatementNode::Conditional(Conditional {
+                    condition: operation(Operator::Boolean(Boolean::LessOrEqual), n(), number(1.)),
+                    body: Box::new([StatementNode::Return {
+                        value: Box::new(number(1.)),
+                        span: Span::default(),
+                    }]),
+                    chained: None,
+                    span: Span::default(),
+                }),
+                StatementNode::Return {
+                    value: Box::new(located(
+                        Operator::Arithmetic(Arithmetic::Multiply),
+                        n(),
+                        call(
+                            "factorial",
+                            operation(Operator::Arithmetic(Arithmetic::Subtract), n(), number(1.)),
+                        ),
+                        Span::new((6, 8), (6, 8)),
+                    )),
+                    span: Span::default(),
+                },
+            ]),
+            span: Span::default(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_constant_function() {
+        let functions = FunctionsRef::try_from([factorial()].as_slice()).unwrap();
+        let mut constants = |name: &str| match name {
+            "N" => Ok(Primitive::Number(5.)),
+            _ => Err(TransformerErrors::NotConstant { name: name.into() }),
+        };
+
+        assert_eq!(
+            Evaluator::new(&functions, "X", &mut constants)
+                .evaluate(&call(
+                    "factorial",
+                    ExpressionNode::VariableReference("N".into())
+                ))
+                .unwrap(),
+            Primitive::Number(120.)
+        );
+    }
+
+    #[test]
+    fn test_evaluation_errors() {
+        let functions = FunctionsRef::try_from([factorial()].as_slice()).unwrap();
+        let mut constants = |name: &str| Err(TransformerErrors::NotConstant { name: name.into() });
+
+        assert!(matches!(
+            Evaluator::new(&functions, "X", &mut constants).evaluate(&located(
+                Operator::Arithmetic(Arithmetic::Divide),
+                number(1.),
+                number(0.),
+                Span::new((0, 17), (0, 17))
+            )),
+            Err(TransformerErrors::DivisionByZero { name, span })
+                if &*name == "X" && span == Span::new((0, 17), (0, 17))
+        ));
+        assert!(matches!(
+            Evaluator::new(&functions, "X", &mut constants)
+                .evaluate(&call("factorial", number(30.))),
+            Err(TransformerErrors::Overflow { name, span })
+                if &*name == "factorial" && span == Span::new((6, 8), (6, 8))
+        ));
+        assert!(matches!(
+            Evaluator::new(&functions, "X", &mut constants).evaluate(&call("printi", number(1.))),
+            Err(TransformerErrors::NotConstant { .. })
+        ));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, iter::once};
 
 use errors::TransformerErrors;
 use nilang_types::nodes::{
@@ -6,62 +6,402 @@ use nilang_types::nodes::{
     Type,
 };
 
+type Overload = (Box<str>, Type, Box<[Parameter]>);
+
 #[derive(Debug, Default)]
-pub struct FunctionsRef(HashMap<Box<str>, (Type, Box<[Parameter]>)>);
+pub struct FunctionsRef(
+    HashMap<Box<str>, Vec<Overload>>,
+    HashMap<Box<str>, FunctionDeclaration>,
+);
 
 impl FunctionsRef {
-    pub fn get_parameters(&self, name: &str) -> Result<&[Parameter], TransformerErrors> {
-        self.0
-            .get(name)
-            .map(|(_, parameters)| parameters.as_ref())
-            .ok_or(TransformerErrors::FunctionNotFound { name: name.into() })
+    pub fn resolve(
+        &self,
+        name: &str,
+        arguments: &[Type],
+    ) -> Result<(&str, &Type, &[Parameter]), TransformerErrors> {
+        let Some(overloads) = self.0.get(name) else {
+            return Err(TransformerErrors::FunctionNotFound { name: name.into() });
+        };
+
+        if let [(_, _, parameters)] = overloads.as_slice() {
+            if parameters.len() != arguments.len() {
+                return Err(TransformerErrors::FunctionCallArgumentsMismatch {
+                    name: name.into(),
+                    expected: parameters.len(),
+                    got: arguments.len(),
+                });
+            }
+        }
+
+        overloads
+            .iter()
+            .find(|(_, _, parameters)| {
+                parameters.len() == arguments.len()
+                    && parameters
+                        .iter()
+                        .zip(arguments)
+                        .all(|((_, parameter), argument)| parameter == argument)
+            })
+            .map(|(symbol, return_type, parameters)| {
+                (symbol.as_ref(), return_type, parameters.as_ref())
+            })
+            .ok_or_else(|| TransformerErrors::NoMatchingOverload {
+                name: name.into(),
+                arguments: arguments.into(),
+            })
+    }
+
+    pub fn function_type(&self, name: &str) -> Result<Type, TransformerErrors> {
+        match self.0.get(name).map(Vec::as_slice) {
+            Some([(_, return_type, parameters)]) => Ok(Type::Function {
+                parameters: parameters
+                    .iter()
+                    .map(|(_, r#type)| r#type.clone())
+                    .collect(),
+                return_type: Box::new(return_type.clone()),
+            }),
+            Some(_) => Err(TransformerErrors::AmbiguousFunctionReference { name: name.into() }),
+            None => Err(TransformerErrors::FunctionNotFound { name: name.into() }),
+        }
+    }
+
+    pub fn constant(&self, symbol: &str) -> Option<&FunctionDeclaration> {
+        self.1.get(symbol)
+    }
+
+    pub fn get_symbol(
+        &self,
+        name: &str,
+        parameters: &[Parameter],
+    ) -> Result<&str, TransformerErrors> {
+        let parameters = parameters
+            .iter()
+            .map(|(_, r#type)| r#type.clone())
+            .collect::<Vec<_>>();
+        self.resolve(name, &parameters).map(|(symbol, _, _)| symbol)
+    }
+
+    fn insert(
+        &mut self,
+        name: Box<str>,
+        symbol: Box<str>,
+        return_type: Type,
+        parameters: Box<[Parameter]>,
+    ) -> Result<(), TransformerErrors> {
+        let overloads = self.0.entry(name.clone()).or_default();
+
+        if overloads.iter().any(|(_, _, existing)| {
+            existing.len() == parameters.len()
+                && existing
+                    .iter()
+                    .zip(parameters.iter())
+                    .all(|((_, a), (_, b))| a == b)
+        }) {
+            return Err(TransformerErrors::FunctionAlreadyDefined {
+                name,
+                parameters: parameters
+                    .iter()
+                    .map(|(_, r#type)| r#type.clone())
+                    .collect(),
+            });
+        }
+
+        overloads.push((symbol, return_type, parameters));
+        Ok(())
     }
 }
 
-impl From<&[FunctionDeclaration]> for FunctionsRef {
-    fn from(functions: &[FunctionDeclaration]) -> Self {
-        let mut functions = FunctionsRef(
-            functions
+const BUILTINS: [(&str, &str, Type); 7] = [
+    ("printb", "printb", Type::Bool),
+    ("printi", "printi", Type::Int),
+    ("printc", "printc", Type::Char),
+    ("print", "printb", Type::Bool),
+    ("print", "printi", Type::Int),
+    ("print", "printc", Type::Char),
+    ("print", "print", Type::String),
+];
+
+impl FunctionsRef {
+    pub fn for_module(
+        prefix: &str,
+        functions: &[FunctionDeclaration],
+        imports: &[(&str, &[FunctionDeclaration])],
+    ) -> Result<Self, TransformerErrors> {
+        let mut functions_ref = FunctionsRef::default();
+
+        for (name, symbol, parameter) in BUILTINS {
+            functions_ref.insert(
+                name.into(),
+                symbol.into(),
+                Type::Void,
+                Box::new([("value".into(), parameter)]),
+            )?;
+        }
+
+        let imported = imports.iter().flat_map(|(prefix, functions)| {
+            symbols(prefix, functions)
+                .into_iter()
+                .filter(|(_, function)| function.public)
+        });
+
+        for (
+            symbol,
+            function @ FunctionDeclaration {
+                name,
+                parameters,
+                return_type,
+                constant,
+                ..
+            },
+        ) in symbols(prefix, functions).into_iter().chain(imported)
+        {
+            if *constant {
+                functions_ref.1.insert(symbol.clone(), function.clone());
+            }
+
+            functions_ref.insert(
+                name.clone(),
+                symbol,
+                return_type.clone(),
+                parameters.clone(),
+            )?;
+        }
+
+        Ok(functions_ref)
+    }
+}
+
+impl TryFrom<&[FunctionDeclaration]> for FunctionsRef {
+    type Error = TransformerErrors;
+
+    fn try_from(functions: &[FunctionDeclaration]) -> Result<Self, Self::Error> {
+        FunctionsRef::for_module("", functions, &[])
+    }
+}
+
+fn symbols<'a>(
+    prefix: &str,
+    functions: &'a [FunctionDeclaration],
+) -> Vec<(Box<str>, &'a FunctionDeclaration)> {
+    let mut overloads_count = HashMap::<&str, usize>::new();
+    for FunctionDeclaration { name, .. } in functions {
+        *overloads_count.entry(name).or_insert_with(|| {
+            BUILTINS
+                .iter()
+                .filter(|(builtin, _, _)| builtin == &name.as_ref())
+                .count()
+        }) += 1;
+    }
+
+    functions
+        .iter()
+        .map(
+            |function @ FunctionDeclaration {
+                 name, parameters, ..
+             }| {
+                let symbol = if overloads_count[name.as_ref()] > 1 {
+                    mangle(name, parameters)
+                } else {
+                    name.clone()
+                };
+
+                if prefix.is_empty() {
+                    (symbol, function)
+                } else {
+                    (format!("{}__{}", prefix, symbol).into(), function)
+                }
+            },
+        )
+        .collect()
+}
+
+fn mangle(name: &str, parameters: &[Parameter]) -> Box<str> {
+    format!(
+        "{}__{}",
+        name,
+        parameters
+            .iter()
+            .map(|(_, r#type)| mangle_type(r#type))
+            .collect::<Vec<_>>()
+            .join("_")
+    )
+    .into()
+}
+
+fn mangle_type(r#type: &Type) -> String {
+    match r#type {
+        Type::Function {
+            parameters,
+            return_type,
+        } => once(format!("fn{}", parameters.len()))
+            .chain(parameters.iter().map(mangle_type))
+            .chain(["ret".to_owned(), mangle_type(return_type), "end".to_owned()])
+            .collect::<Vec<_>>()
+            .join("_"),
+        r#type => r#type.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use errors::TransformerErrors;
+    use nilang_types::nodes::{statements::FunctionDeclaration, Span, Type};
+
+    use super::FunctionsRef;
+
+    fn function(name: &str, parameters: &[Type], return_type: Type) -> FunctionDeclaration {
+        FunctionDeclaration {
+            public: false,
+            constant: false,
+            name: name.into(),
+            parameters: parameters
                 .iter()
-                .map(
-                    |FunctionDeclaration {
-                         return_type,
-                         parameters,
-                         name,
-                         ..
-                     }| {
-                        (
-                            name.clone(),
-                            (
-                                return_type.clone(),
-                                parameters
-                                    .iter()
-                                    .map(|(name, r#type)| (name.clone(), r#type.clone()))
-                                    .collect(),
-                            ),
-                        )
-                    },
-                )
+                .enumerate()
+                .map(|(i, r#type)| (format!("p{i}").into(), r#type.clone()))
                 .collect(),
+            return_type,
+            body: [].into(),
+            span: Span::default(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_builtin_print() {
+        let functions = FunctionsRef::try_from([].as_slice()).unwrap();
+
+        assert_eq!(
+            functions.resolve("print", &[Type::Int]).unwrap().0,
+            "printi"
+        );
+        assert_eq!(
+            functions.resolve("print", &[Type::Bool]).unwrap().0,
+            "printb"
         );
+        assert_eq!(
+            functions.resolve("print", &[Type::Char]).unwrap().0,
+            "printc"
+        );
+        assert_eq!(
+            functions.resolve("print", &[Type::String]).unwrap().0,
+            "print"
+        );
+        assert!(matches!(
+            functions.resolve("print", &[Type::Object("Point".into())]),
+            Err(TransformerErrors::NoMatchingOverload { .. })
+        ));
+    }
+
+    #[test]
+    fn test_resolve_user_overloads() {
+        let functions = FunctionsRef::try_from(
+            [
+                function("main", &[], Type::Int),
+                function("add", &[Type::Int, Type::Int], Type::Int),
+                function("add", &[Type::Char], Type::Char),
+                function("print", &[Type::Object("Point".into())], Type::Void),
+            ]
+            .as_slice(),
+        )
+        .unwrap();
 
-        functions.0.insert(
-            "printb".into(),
-            (Type::Void, Box::new([("value".into(), Type::Bool)])),
+        assert_eq!(functions.resolve("main", &[]).unwrap().0, "main");
+        assert_eq!(
+            functions.resolve("add", &[Type::Int, Type::Int]).unwrap(),
+            (
+                "add__int_int",
+                &Type::Int,
+                [("p0".into(), Type::Int), ("p1".into(), Type::Int)].as_slice()
+            )
         );
-        functions.0.insert(
-            "printi".into(),
-            (Type::Void, Box::new([("value".into(), Type::Int)])),
+        assert_eq!(
+            functions.resolve("add", &[Type::Char]).unwrap().0,
+            "add__char"
         );
-        functions.0.insert(
-            "printc".into(),
-            (Type::Void, Box::new([("value".into(), Type::Char)])),
+        assert_eq!(
+            functions
+                .resolve("print", &[Type::Object("Point".into())])
+                .unwrap()
+                .0,
+            "print__Point"
         );
-        functions.0.insert(
-            "print".into(),
-            (Type::Void, Box::new([("value".into(), Type::String)])),
+        assert!(matches!(
+            functions.resolve("main", &[Type::Int]),
+            Err(TransformerErrors::FunctionCallArgumentsMismatch { .. })
+        ));
+        assert!(matches!(
+            functions.resolve("sub", &[]),
+            Err(TransformerErrors::FunctionNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_mangle_function_parameters() {
+        let callback = |arity| Type::Function {
+            parameters: vec![Type::Int; arity].into(),
+            return_type: Box::new(Type::Int),
+        };
+        let functions = FunctionsRef::try_from(
+            [
+                function("apply", &[callback(1), Type::Int], Type::Int),
+                function("apply", &[callback(2)], Type::Int),
+            ]
+            .as_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            functions
+                .resolve("apply", &[callback(1), Type::Int])
+                .unwrap()
+                .0,
+            "apply__fn1_int_ret_int_end_int"
+        );
+        assert_eq!(
+            functions.resolve("apply", &[callback(2)]).unwrap().0,
+            "apply__fn2_int_int_ret_int_end"
         );
+    }
 
-        functions
+    #[test]
+    fn test_duplicate_overload() {
+        assert!(matches!(
+            FunctionsRef::try_from(
+                [
+                    function("add", &[Type::Int], Type::Int),
+                    function("add", &[Type::Int], Type::Void),
+                ]
+                .as_slice(),
+            ),
+            Err(TransformerErrors::FunctionAlreadyDefined { .. })
+        ));
+    }
+
+    #[test]
+    fn test_module_imports() {
+        let math = [
+            FunctionDeclaration {
+                public: true,
+                constant: false,
+                ..function("square", &[Type::Int], Type::Int)
+            },
+            function("helper", &[Type::Int], Type::Int),
+        ];
+        let functions = FunctionsRef::for_module(
+            "",
+            &[function("main", &[], Type::Int)],
+            &[("math", math.as_slice())],
+        )
+        .unwrap();
+
+        assert_eq!(functions.resolve("main", &[]).unwrap().0, "main");
+        assert_eq!(
+            functions.resolve("square", &[Type::Int]).unwrap().0,
+            "math__square"
+        );
+        assert!(matches!(
+            functions.resolve("helper", &[Type::Int]),
+            Err(TransformerErrors::FunctionNotFound { .. })
+        ));
     }
 }
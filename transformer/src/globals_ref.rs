@@ -0,0 +1,256 @@
+use std::collections::{HashMap, HashSet};
+
+use errors::TransformerErrors;
+use nilang_types::{
+    instructions::{DataValue, Width},
+    nodes::{expressions::Primitive, statements::GlobalDeclaration, Type},
+};
+
+use crate::{
+    evaluator::{type_of_primitive, Evaluator},
+    Declaration, FunctionsRef,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Global {
+    Constant(Type, Primitive),
+    Variable(Box<str>, Type),
+}
+
+#[derive(Debug, Default)]
+pub struct GlobalsRef(HashMap<Box<str>, Global>, Vec<Declaration>);
+
+impl GlobalsRef {
+    pub fn get(&self, name: &str) -> Option<&Global> {
+        self.0.get(name)
+    }
+
+    pub fn type_of(&self, name: &str) -> Option<Type> {
+        self.0.get(name).map(|global| match global {
+            Global::Constant(r#type, _) | Global::Variable(_, r#type) => r#type.clone(),
+        })
+    }
+
+    pub fn data(&self) -> &[Declaration] {
+        &self.1
+    }
+
+    pub fn for_module(
+        functions: &FunctionsRef,
+        prefix: &str,
+        globals: &[GlobalDeclaration],
+        imports: &[(&str, &[GlobalDeclaration], &FunctionsRef)],
+    ) -> Result<Self, TransformerErrors> {
+        let mut globals_ref = evaluate_module(functions, prefix, globals)?;
+
+        for (prefix, globals, functions) in imports {
+            let imported = evaluate_module(functions, prefix, globals)?;
+            for GlobalDeclaration { name, .. } in globals.iter().filter(|global| global.public) {
+                globals_ref.0.insert(name.clone(), imported.0[name].clone());
+            }
+        }
+
+        Ok(globals_ref)
+    }
+}
+
+impl TryFrom<&[GlobalDeclaration]> for GlobalsRef {
+    type Error = TransformerErrors;
+
+    fn try_from(globals: &[GlobalDeclaration]) -> Result<Self, Self::Error> {
+        GlobalsRef::for_module(&FunctionsRef::default(), "", globals, &[])
+    }
+}
+
+pub fn width_of(r#type: &Type) -> Width {
+    match r#type {
+        Type::Bool | Type::Char => Width::Byte,
+        _ => Width::Quad,
+    }
+}
+
+fn evaluate_module(
+    functions: &FunctionsRef,
+    prefix: &str,
+    globals: &[GlobalDeclaration],
+) -> Result<GlobalsRef, TransformerErrors> {
+    let mut constants = HashMap::new();
+    let mut evaluated = HashMap::new();
+    let mut data = Vec::new();
+
+    for GlobalDeclaration {
+        constant,
+        name,
+        r#type,
+        value,
+        ..
+    } in globals
+    {
+        let primitive = if *constant {
+            evaluate_constant(
+                functions,
+                globals,
+                name,
+                &mut constants,
+                &mut HashSet::new(),
+            )?
+        } else {
+            Evaluator::new(functions, name, &mut |constant| {
+                evaluate_constant(
+                    functions,
+                    globals,
+                    constant,
+                    &mut constants,
+                    &mut HashSet::new(),
+                )
+            })
+            .evaluate(value)?
+        };
+
+        let found = type_of_primitive(&primitive);
+        if found != *r#type {
+            return Err(TransformerErrors::TypeMismatch {
+                expected: r#type.clone(),
+                found,
+            });
+        }
+
+        if *constant {
+            evaluated.insert(name.clone(), Global::Constant(r#type.clone(), primitive));
+        } else {
+            let symbol = <Box<str>>::from(if prefix.is_empty() {
+                format!("global__{}", name)
+            } else {
+                format!("{}__global__{}", prefix, name)
+            });
+            data.push((symbol.clone(), data_value(&primitive)));
+            evaluated.insert(name.clone(), Global::Variable(symbol, r#type.clone()));
+        }
+    }
+
+    Ok(GlobalsRef(evaluated, data))
+}
+
+fn evaluate_constant(
+    functions: &FunctionsRef,
+    globals: &[GlobalDeclaration],
+    name: &str,
+    constants: &mut HashMap<Box<str>, Primitive>,
+    visiting: &mut HashSet<Box<str>>,
+) -> Result<Primitive, TransformerErrors> {
+    if let Some(primitive) = constants.get(name) {
+        return Ok(primitive.clone());
+    }
+
+    let Some(GlobalDeclaration { value, .. }) = globals
+        .iter()
+        .find(|global| global.constant && &*global.name == name)
+    else {
+        return Err(TransformerErrors::NotConstant { name: name.into() });
+    };
+
+    if !visiting.insert(name.into()) {
+        return Err(TransformerErrors::NotConstant { name: name.into() });
+    }
+
+    let primitive = Evaluator::new(functions, name, &mut |constant| {
+        evaluate_constant(functions, globals, constant, constants, visiting)
+    })
+    .evaluate(value)?;
+
+    visiting.remove(name);
+    constants.insert(name.into(), primitive.clone());
+    Ok(primitive)
+}
+
+fn data_value(primitive: &Primitive) -> DataValue {
+    match primitive {
+        Primitive::Boolean(boolean) => DataValue::Byte(*boolean as u8),
+        Primitive::Number(number) => DataValue::Quad(*number as i64),
+        Primitive::Char(char) => DataValue::Byte(*char as u8),
+        Primitive::String(text) => DataValue::String(text.clone()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use errors::TransformerErrors;
+    use nilang_types::{
+        instructions::DataValue,
+        nodes::{
+            expressions::{Arithmetic, ExpressionNode, Operation, Operator, Primitive},
+            statements::GlobalDeclaration,
+            Span, Type,
+        },
+    };
+
+    use super::{Global, GlobalsRef};
+
+    fn global(constant: bool, name: &str, value: ExpressionNode) -> GlobalDeclaration {
+        GlobalDeclaration {
+            public: false,
+            constant,
+            name: name.into(),
+            r#type: Type::Int,
+            value: Box::new(value),
+            span: Span::default(),
+        }
+    }
+
+    #[test]
+    fn test_evaluate_globals() {
+        let globals = GlobalsRef::try_from(
+            [
+                global(
+                    false,
+                    "counter",
+                    ExpressionNode::VariableReference("AREA".into()),
+                ),
+                global(
+                    true,
+                    "AREA",
+                    ExpressionNode::Operation(Operation {
+                        operator: Operator::Arithmetic(Arithmetic::Multiply),
+                        a: Box::new(ExpressionNode::VariableReference("SIDE".into())),
+                        b: Box::new(ExpressionNode::VariableReference("SIDE".into())),
+                        span: Span::default(),
+                    }),
+                ),
+                global(
+                    true,
+                    "SIDE",
+                    ExpressionNode::Primitive(Primitive::Number(4.)),
+                ),
+            ]
+            .as_slice(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            globals.get("AREA"),
+            Some(&Global::Constant(Type::Int, Primitive::Number(16.)))
+        );
+        assert_eq!(
+            globals.get("counter"),
+            Some(&Global::Variable("global__counter".into(), Type::Int))
+        );
+        assert_eq!(
+            globals.data(),
+            [("global__counter".into(), DataValue::Quad(16))]
+        );
+    }
+
+    #[test]
+    fn test_recursive_constant() {
+        assert!(matches!(
+            GlobalsRef::try_from(
+                [
+                    global(true, "A", ExpressionNode::VariableReference("B".into())),
+                    global(true, "B", ExpressionNode::VariableReference("A".into())),
+                ]
+                .as_slice(),
+            ),
+            Err(TransformerErrors::NotConstant { .. })
+        ));
+    }
+}
@@ -1,15 +1,24 @@
+mod closures;
+mod evaluator;
 mod functions_ref;
+mod globals_ref;
 mod labels;
+mod modules;
 mod structures_ref;
 mod temporaries;
 mod transformers;
+mod type_inference;
 
 use std::{cell::RefCell, iter::once};
 
+pub use closures::Closure;
+use closures::Closures;
 use errors::TransformerErrors;
 pub use functions_ref::FunctionsRef;
+pub use globals_ref::GlobalsRef;
+pub use modules::{module_context, Module};
 use nilang_types::{
-    instructions::Instruction,
+    instructions::{DataValue, Instruction},
     nodes::{
         statements::{FunctionDeclaration, Parameter, StatementNode},
         Type,
@@ -23,28 +32,32 @@ use crate::labels::Labels;
 type InstructionsIterator<'a> =
     Box<dyn Iterator<Item = Result<Instruction, TransformerErrors>> + 'a>;
 
-type Declaration = (Box<str>, Box<str>);
+type Declaration = (Box<str>, DataValue);
 type Data = RefCell<Vec<Declaration>>;
 
 struct Context<'a> {
     functions: &'a FunctionsRef,
     structures: &'a StructuresRef,
+    globals: &'a GlobalsRef,
     temporaries: Temporaries,
     labels: Labels,
     data: &'a Data,
+    closures: &'a Closures,
 }
 
 pub fn transform_function<'a>(
-    refs: &'a (FunctionsRef, StructuresRef),
+    refs: &'a (FunctionsRef, StructuresRef, GlobalsRef),
     FunctionDeclaration {
+        name,
         body,
         return_type,
         parameters,
         ..
     }: &'a FunctionDeclaration,
-) -> (InstructionsIterator<'a>, Vec<Declaration>) {
+) -> (InstructionsIterator<'a>, Vec<Declaration>, Vec<Closure>) {
     let temporaries = Temporaries::default();
     let labels = Labels::default();
+    let closures = Closures::new(refs.0.get_symbol(name, parameters).unwrap_or(name));
 
     let parameters = transform_parameters(
         &refs.1,
@@ -59,9 +72,11 @@ pub fn transform_function<'a>(
     let context = Context {
         functions: &refs.0,
         structures: &refs.1,
+        globals: &refs.2,
         temporaries,
         labels,
         data: &data,
+        closures: &closures,
     };
 
     let body = transform_body(&context, body, return_type);
@@ -69,6 +84,73 @@ pub fn transform_function<'a>(
     (
         Box::new(parameters.chain(body).collect::<Vec<_>>().into_iter()),
         data.take(),
+        closures.take(),
+    )
+}
+
+pub fn transform_closure<'a>(
+    refs: &'a (FunctionsRef, StructuresRef, GlobalsRef),
+    Closure {
+        symbol,
+        parameters,
+        captures,
+        return_type,
+        body,
+    }: &'a Closure,
+) -> (InstructionsIterator<'a>, Vec<Declaration>, Vec<Closure>) {
+    let temporaries = Temporaries::default();
+    let labels = Labels::default();
+    let closures = Closures::new(symbol);
+
+    let mut instructions =
+        transform_parameters(&refs.1, &temporaries, parameters).collect::<Vec<_>>();
+
+    let environment = <Box<str>>::from("closure__env");
+    temporaries.declare_named(environment.clone(), Type::Int);
+    instructions.push(Ok(Instruction::TakeArgument(
+        instructions.len(),
+        environment.clone(),
+    )));
+
+    let mut offset = 1;
+    for (capture, capture_type) in captures.iter() {
+        temporaries.declare_named(capture.clone(), capture_type.clone());
+        if let Type::Object(_) = capture_type {
+            continue;
+        }
+
+        instructions.push(Ok(Instruction::Declare(capture.clone())));
+        instructions.push(Ok(Instruction::LoadFromAddress(
+            capture.clone(),
+            environment.clone(),
+            offset,
+        )));
+        offset += 1;
+    }
+
+    let data: Data = RefCell::new(Vec::new());
+    let context = Context {
+        functions: &refs.0,
+        structures: &refs.1,
+        globals: &refs.2,
+        temporaries,
+        labels,
+        data: &data,
+        closures: &closures,
+    };
+
+    let body = transform_body(&context, body, return_type);
+
+    (
+        Box::new(
+            instructions
+                .into_iter()
+                .chain(body)
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+        data.take(),
+        closures.take(),
     )
 }
 
@@ -79,7 +161,9 @@ fn transform_body<'a>(
     return_type: &'a Type,
 ) -> InstructionsIterator<'a> {
     Box::new(body.iter().flat_map(move |node| {
+        let span = node.span();
         transformers::transform_statement(context, node.clone(), return_type)
+            .map(move |instruction| instruction.map_err(|error| error.at(span)))
     }))
 }
 
@@ -98,6 +182,7 @@ fn transform_parameters<'a>(
                 Err(e) => return Box::new(once(Err(e))),
             };
 
+            temporaries.declare_named(parameter_name.clone(), parameter_type.clone());
             for (field, field_type) in object_fields_recursive {
                 let field = Into::<Box<str>>::into(format!("{}.{}", parameter_name, field));
                 temporaries.declare_named(field.clone(), field_type.to_owned());
@@ -0,0 +1,89 @@
+use errors::TransformerErrors;
+use nilang_types::nodes::statements::{
+    FunctionDeclaration, GlobalDeclaration, ModulePath, StructureDeclaration,
+};
+
+use crate::{FunctionsRef, GlobalsRef, StructuresRef};
+
+#[derive(Debug, Default)]
+pub struct Module {
+    pub path: ModulePath,
+    pub functions: Vec<FunctionDeclaration>,
+    pub structures: Vec<StructureDeclaration>,
+    pub uses: Vec<ModulePath>,
+    pub globals: Vec<GlobalDeclaration>,
+}
+
+impl Module {
+    pub fn prefix(&self) -> Box<str> {
+        self.path.join("__").into()
+    }
+
+    pub fn imports<'a>(&self, modules: &'a [Module]) -> Vec<&'a Module> {
+        self.uses
+            .iter()
+            .filter_map(|path| modules.iter().find(|module| &module.path == path))
+            .collect()
+    }
+}
+
+pub fn module_context(
+    module: &Module,
+    modules: &[Module],
+) -> Result<(FunctionsRef, StructuresRef, GlobalsRef), TransformerErrors> {
+    let imports = module.imports(modules);
+
+    let prefixes = imports
+        .iter()
+        .map(|module| module.prefix())
+        .collect::<Vec<_>>();
+    let imported_functions_refs = imports
+        .iter()
+        .map(|module| module_functions(module, modules))
+        .collect::<Result<Vec<_>, _>>()?;
+    let imported_globals = imports
+        .iter()
+        .zip(&prefixes)
+        .zip(&imported_functions_refs)
+        .map(|((module, prefix), functions)| {
+            (prefix.as_ref(), module.globals.as_slice(), functions)
+        })
+        .collect::<Vec<_>>();
+    let imported_structures = imports
+        .iter()
+        .map(|module| module.structures.as_slice())
+        .collect::<Vec<_>>();
+
+    let functions = module_functions(module, modules)?;
+    let globals = GlobalsRef::for_module(
+        &functions,
+        &module.prefix(),
+        &module.globals,
+        &imported_globals,
+    )?;
+
+    Ok((
+        functions,
+        StructuresRef::for_module(&module.structures, &imported_structures)?,
+        globals,
+    ))
+}
+
+fn module_functions(
+    module: &Module,
+    modules: &[Module],
+) -> Result<FunctionsRef, TransformerErrors> {
+    let imports = module.imports(modules);
+
+    let prefixes = imports
+        .iter()
+        .map(|module| module.prefix())
+        .collect::<Vec<_>>();
+    let imported_functions = imports
+        .iter()
+        .zip(&prefixes)
+        .map(|(module, prefix)| (prefix.as_ref(), module.functions.as_slice()))
+        .collect::<Vec<_>>();
+
+    FunctionsRef::for_module(&module.prefix(), &module.functions, &imported_functions)
+}
@@ -32,6 +32,23 @@ impl StructuresRef {
                 name: object_type.into(),
             })
     }
+
+    pub fn for_module(
+        structures: &[StructureDeclaration],
+        imports: &[&[StructureDeclaration]],
+    ) -> Result<Self, TransformerErrors> {
+        let visible = [structures, &imports.concat()].concat();
+        let mut structures_ref = StructuresRef::try_from(visible.as_slice())?;
+
+        for StructureDeclaration { name, public, .. } in imports.iter().copied().flatten() {
+            if !public && !structures.iter().any(|structure| &structure.name == name) {
+                structures_ref.0.remove(name);
+                structures_ref.1.remove(name);
+            }
+        }
+
+        Ok(structures_ref)
+    }
 }
 
 impl TryFrom<&[StructureDeclaration]> for StructuresRef {
@@ -42,7 +59,7 @@ impl TryFrom<&[StructureDeclaration]> for StructuresRef {
     ) -> Result<StructuresRef, errors::TransformerErrors> {
         let nested_structures = structures
             .iter()
-            .map(|StructureDeclaration { name, fields }| (name.clone(), fields.clone()))
+            .map(|StructureDeclaration { name, fields, .. }| (name.clone(), fields.clone()))
             .collect::<HashMap<_, _>>();
         let flattened_structures = nested_structures
             .keys()
@@ -116,7 +133,7 @@ pub fn copy_all_fields<'a>(
     let object_type = match object_type {
         Type::Object(object_type) => object_type,
         Type::Void => return Box::new(empty()),
-        Type::Bool | Type::Int | Type::Char | Type::String => {
+        Type::Bool | Type::Int | Type::Char | Type::String | Type::Function { .. } => {
             return Box::new(once(Ok(Instruction::Copy(destination, source))));
         }
     };
@@ -131,7 +148,8 @@ pub fn copy_all_fields<'a>(
 
     let instructions = object_fields_from_to.into_iter().flat_map(
         |(destination_temporary, source_temporary, field_type)| {
-            temporaries.declare_named(source_temporary.clone(), field_type);
+            temporaries.declare_named(source_temporary.clone(), field_type.clone());
+            temporaries.declare_named(destination_temporary.clone(), field_type);
 
             once(Ok(Instruction::Declare(destination_temporary.clone()))).chain(Ok::<
                 Result<Instruction, TransformerErrors>,
@@ -151,7 +169,7 @@ pub fn copy_all_fields<'a>(
 pub mod tests {
     use std::collections::HashMap;
 
-    use nilang_types::nodes::{statements::StructureDeclaration, Type};
+    use nilang_types::nodes::{statements::StructureDeclaration, Span, Type};
 
     use crate::structures_ref::{object_fields_recursive, StructuresRef};
 
@@ -159,22 +177,28 @@ pub mod tests {
         StructuresRef::try_from(
             [
                 StructureDeclaration {
+                    public: false,
                     name: "Point".into(),
                     fields: HashMap::from([("x".into(), Type::Int), ("y".into(), Type::Int)]),
+                    span: Span::default(),
                 },
                 StructureDeclaration {
+                    public: false,
                     name: "Rect".into(),
                     fields: HashMap::from([
                         ("start".into(), Type::Object("Point".into())),
                         ("end".into(), Type::Object("Point".into())),
                     ]),
+                    span: Span::default(),
                 },
                 StructureDeclaration {
+                    public: false,
                     name: "Label".into(),
                     fields: HashMap::from([
                         ("text".into(), Type::Char),
                         ("anchor".into(), Type::Object("Point".into())),
                     ]),
+                    span: Span::default(),
                 },
             ]
             .as_ref(),
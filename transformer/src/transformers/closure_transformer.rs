@@ -0,0 +1,228 @@
+use std::iter::once;
+
+use errors::TransformerErrors;
+use nilang_types::{
+    instructions::Instruction,
+    nodes::{
+        statements::{Parameter, StatementNode},
+        Type,
+    },
+};
+
+use crate::{closures::free_variables, Context, InstructionsIterator};
+
+pub fn transform_closure<'a>(
+    context @ Context {
+        structures,
+        temporaries,
+        closures,
+        ..
+    }: &'a Context,
+
+    parameters: Box<[Parameter]>,
+    return_type: Type,
+    body: Box<[StatementNode]>,
+
+    result: Box<str>,
+    r#type: &Type,
+) -> InstructionsIterator<'a> {
+    let closure_type = Type::Function {
+        parameters: parameters
+            .iter()
+            .map(|(_, r#type)| r#type.clone())
+            .collect(),
+        return_type: Box::new(return_type.clone()),
+    };
+    if closure_type != *r#type {
+        return Box::new(once(Err(TransformerErrors::TypeMismatch {
+            expected: r#type.clone(),
+            found: closure_type,
+        })));
+    }
+
+    let mut captures = Vec::new();
+    for name in free_variables(&parameters, &body) {
+        let Ok(capture_type) = temporaries.type_of(&name) else {
+            continue;
+        };
+
+        if let Type::Object(object_type) = &capture_type {
+            let mut fields = match structures.get_fields_flattened(object_type) {
+                Ok(fields) => fields.iter().collect::<Vec<_>>(),
+                Err(e) => return Box::new(once(Err(e))),
+            };
+            fields.sort();
+
+            captures.push((name.clone(), capture_type.clone()));
+            captures.extend(fields.into_iter().map(|(field, field_type)| {
+                (
+                    <Box<str>>::from(format!("{}.{}", name, field)),
+                    field_type.clone(),
+                )
+            }));
+        } else {
+            captures.push((name, capture_type));
+        }
+    }
+
+    let captured_values = captures
+        .iter()
+        .filter(|(_, r#type)| !matches!(r#type, Type::Object(_)))
+        .map(|(name, _)| name.clone())
+        .collect::<Vec<_>>();
+
+    let symbol = closures.lift(parameters, captures.into(), return_type, body);
+    create_closure(context, symbol, &captured_values, result)
+}
+
+pub fn create_closure<'a>(
+    Context { temporaries, .. }: &'a Context,
+
+    symbol: Box<str>,
+    captures: &[Box<str>],
+
+    result: Box<str>,
+) -> InstructionsIterator<'a> {
+    for capture in captures {
+        if let Err(e) = temporaries.access(capture) {
+            return Box::new(once(Err(e)));
+        }
+    }
+
+    let size_temporary = temporaries.declare(Type::Int);
+    let code_temporary = temporaries.declare(Type::Int);
+
+    Box::new(
+        [
+            Ok(Instruction::Declare(size_temporary.clone())),
+            Ok(Instruction::LoadNumber(
+                size_temporary.clone(),
+                (8 * (captures.len() + 1)) as f64,
+            )),
+            Ok(Instruction::FunctionCall(
+                "malloc".into(),
+                [size_temporary].into(),
+                Some(result.clone()),
+            )),
+            Ok(Instruction::Declare(code_temporary.clone())),
+            Ok(Instruction::LoadFunctionLocation(
+                code_temporary.clone(),
+                symbol,
+            )),
+            Ok(Instruction::StoreToAddress(
+                result.clone(),
+                0,
+                code_temporary,
+            )),
+        ]
+        .into_iter()
+        .chain(
+            captures
+                .iter()
+                .enumerate()
+                .map(|(i, capture)| {
+                    Ok(Instruction::StoreToAddress(
+                        result.clone(),
+                        i + 1,
+                        capture.clone(),
+                    ))
+                })
+                .collect::<Vec<_>>(),
+        ),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use nilang_types::{
+        instructions::Instruction,
+        nodes::{
+            expressions::{Arithmetic, ExpressionNode, Operation, Operator},
+            statements::StatementNode,
+            Span, Type,
+        },
+    };
+
+    use crate::{
+        closures::{Closure, Closures},
+        labels::Labels,
+        structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries,
+        Context, FunctionsRef, GlobalsRef,
+    };
+
+    use super::transform_closure;
+
+    #[test]
+    fn test_transform_closure() {
+        let context = Context {
+            functions: &FunctionsRef::default(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &RefCell::new(Vec::new()),
+            closures: &Closures::new("main"),
+        };
+
+        context
+            .temporaries
+            .declare_named("offset".into(), Type::Int);
+        context
+            .temporaries
+            .declare_named("add".into(), Type::Object("Point".into()));
+
+        let body: Box<[StatementNode]> = [StatementNode::Return {
+            value: Box::new(ExpressionNode::Operation(Operation {
+                operator: Operator::Arithmetic(Arithmetic::Add),
+                a: Box::new(ExpressionNode::VariableReference("x".into())),
+                b: Box::new(ExpressionNode::VariableReference("offset".into())),
+                span: Span::default(),
+            })),
+            span: Span::default(),
+        }]
+        .into();
+
+        assert_eq!(
+            transform_closure(
+                &context,
+                [("x".into(), Type::Int)].into(),
+                Type::Int,
+                body.clone(),
+                "f".into(),
+                &Type::Function {
+                    parameters: [Type::Int].into(),
+                    return_type: Box::new(Type::Int)
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            [
+                Instruction::Declare("temp_0".into()),
+                Instruction::LoadNumber("temp_0".into(), 16.),
+                Instruction::FunctionCall(
+                    "malloc".into(),
+                    ["temp_0".into()].into(),
+                    Some("f".into())
+                ),
+                Instruction::Declare("temp_1".into()),
+                Instruction::LoadFunctionLocation("temp_1".into(), "main__closure_0".into()),
+                Instruction::StoreToAddress("f".into(), 0, "temp_1".into()),
+                Instruction::StoreToAddress("f".into(), 1, "offset".into()),
+            ]
+        );
+
+        assert_eq!(
+            context.closures.take(),
+            [Closure {
+                symbol: "main__closure_0".into(),
+                parameters: [("x".into(), Type::Int)].into(),
+                captures: [("offset".into(), Type::Int)].into(),
+                return_type: Type::Int,
+                body,
+            }]
+        );
+    }
+}
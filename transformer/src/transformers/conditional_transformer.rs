@@ -18,6 +18,7 @@ pub fn transform_conditional<'a>(
         condition,
         body,
         chained,
+        ..
     }: Conditional,
 ) -> InstructionsIterator<'a> {
     let condition_temporary = temporaries.declare(Type::Bool);
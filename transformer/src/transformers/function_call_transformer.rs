@@ -3,7 +3,7 @@ use std::iter::once;
 use errors::TransformerErrors;
 use nilang_types::nodes::expressions::ExpressionNode;
 
-use crate::{Context, Instruction, InstructionsIterator, Type};
+use crate::{type_inference::infer_type, Context, Instruction, InstructionsIterator, Type};
 
 use super::transform_expression;
 
@@ -20,59 +20,64 @@ pub fn transform_function_call<'a>(
     result: Box<str>,
     r#type: &Type,
 ) -> InstructionsIterator<'a> {
-    let Ok(function_parameters) = functions.get_parameters(&name) else {
-        return Box::new(once(Err(TransformerErrors::FunctionNotFound { name })));
+    let arguments_types = match arguments
+        .iter()
+        .map(|argument| infer_type(context, argument))
+        .collect::<Result<Vec<_>, _>>()
+    {
+        Ok(arguments_types) => arguments_types,
+        Err(e) => return Box::new(once(Err(e))),
     };
-    let mut function_parameters = function_parameters.iter();
 
-    let mut instructions = vec![];
-    let mut arguments_names = vec![];
-
-    for node in arguments {
-        if let Some((_, argument_type)) = function_parameters.next() {
-            let argument_temporary = temporaries.declare(argument_type.clone());
-            instructions.push(Ok(Instruction::Declare(argument_temporary.clone())));
-            instructions.append(
-                &mut transform_expression(
-                    context,
-                    node.clone(),
-                    argument_temporary.clone(),
-                    &argument_type.clone(),
-                )
-                .collect(),
-            );
+    if let Ok(Type::Function {
+        parameters,
+        return_type,
+    }) = temporaries.type_of(&name)
+    {
+        return transform_indirect_call(
+            context,
+            name,
+            arguments,
+            &arguments_types,
+            (&parameters, &return_type),
+            result,
+            r#type,
+        );
+    }
 
-            if let Type::Object(object_type) = argument_type {
-                let fields = match context.structures.get_fields_flattened(object_type) {
-                    Ok(fields) => fields,
-                    Err(e) => return Box::new(once(Err(e))),
-                };
-
-                arguments_names.append(
-                    &mut fields
-                        .iter()
-                        .map(|(field, _)| format!("{}.{}", argument_temporary, field).into())
-                        .collect(),
-                );
-            } else {
-                arguments_names.push(argument_temporary);
+    let (symbol, return_type, function_parameters) =
+        match functions.resolve(&name, &arguments_types) {
+            Ok(resolved) => resolved,
+            Err(TransformerErrors::NoMatchingOverload { .. })
+                if &*name == "print" && matches!(arguments_types[..], [Type::Object(_)]) =>
+            {
+                return transform_structure_print(context, &arguments[0], &arguments_types[0]);
             }
-        } else {
-            return Box::new(once(Err(
-                TransformerErrors::FunctionCallArgumentsMismatch {
-                    name,
-                    expected: function_parameters.len() + 1,
-                    got: arguments.len(),
-                },
-            )));
-        }
+            Err(e) => return Box::new(once(Err(e))),
+        };
+
+    if *r#type != Type::Void && r#type != return_type {
+        return Box::new(once(Err(TransformerErrors::TypeMismatch {
+            expected: r#type.clone(),
+            found: return_type.clone(),
+        })));
     }
 
+    let parameters_types = function_parameters
+        .iter()
+        .map(|(_, r#type)| r#type.clone())
+        .collect::<Vec<_>>();
+    let (instructions, arguments_names) =
+        match transform_arguments(context, arguments, &parameters_types) {
+            Ok(transformed) => transformed,
+            Err(e) => return Box::new(once(Err(e))),
+        };
+
     Box::new(
         instructions
             .into_iter()
             .chain(once(Ok(Instruction::FunctionCall(
-                name,
+                symbol.into(),
                 arguments_names.into(),
                 if let Type::Void = r#type {
                     None
@@ -82,3 +87,281 @@ pub fn transform_function_call<'a>(
             )))),
     )
 }
+
+fn transform_indirect_call<'a>(
+    context: &'a Context,
+
+    name: Box<str>,
+    arguments: &[ExpressionNode],
+    arguments_types: &[Type],
+    (parameters, return_type): (&[Type], &Type),
+
+    result: Box<str>,
+    r#type: &Type,
+) -> InstructionsIterator<'a> {
+    if parameters.len() != arguments.len() {
+        return Box::new(once(Err(
+            TransformerErrors::FunctionCallArgumentsMismatch {
+                name,
+                expected: parameters.len(),
+                got: arguments.len(),
+            },
+        )));
+    }
+
+    if arguments_types != parameters {
+        return Box::new(once(Err(TransformerErrors::NoMatchingOverload {
+            name,
+            arguments: arguments_types.into(),
+        })));
+    }
+
+    if *r#type != Type::Void && r#type != return_type {
+        return Box::new(once(Err(TransformerErrors::TypeMismatch {
+            expected: r#type.clone(),
+            found: return_type.clone(),
+        })));
+    }
+
+    if let Err(e) = context.temporaries.access(&name) {
+        return Box::new(once(Err(e)));
+    }
+
+    let (instructions, arguments_names) = match transform_arguments(context, arguments, parameters)
+    {
+        Ok(transformed) => transformed,
+        Err(e) => return Box::new(once(Err(e))),
+    };
+
+    Box::new(
+        instructions
+            .into_iter()
+            .chain(once(Ok(Instruction::IndirectFunctionCall(
+                name,
+                arguments_names.into(),
+                if let Type::Void = r#type {
+                    None
+                } else {
+                    Some(result)
+                },
+            )))),
+    )
+}
+
+type TransformedArguments = (Vec<Result<Instruction, TransformerErrors>>, Vec<Box<str>>);
+
+fn transform_arguments(
+    context: &Context,
+
+    arguments: &[ExpressionNode],
+    parameters: &[Type],
+) -> Result<TransformedArguments, TransformerErrors> {
+    let mut instructions = vec![];
+    let mut arguments_names = vec![];
+
+    for (node, argument_type) in arguments.iter().zip(parameters) {
+        let argument_temporary = context.temporaries.declare(argument_type.clone());
+        instructions.push(Ok(Instruction::Declare(argument_temporary.clone())));
+        instructions.append(
+            &mut transform_expression(
+                context,
+                node.clone(),
+                argument_temporary.clone(),
+                argument_type,
+            )
+            .collect(),
+        );
+
+        if let Type::Object(object_type) = argument_type {
+            let fields = context.structures.get_fields_flattened(object_type)?;
+
+            arguments_names.append(
+                &mut fields
+                    .keys()
+                    .map(|field| format!("{}.{}", argument_temporary, field).into())
+                    .collect(),
+            );
+        } else {
+            arguments_names.push(argument_temporary);
+        }
+    }
+
+    Ok((instructions, arguments_names))
+}
+
+fn transform_structure_print<'a>(
+    context: &'a Context,
+    structure: &ExpressionNode,
+    structure_type: &Type,
+) -> InstructionsIterator<'a> {
+    let Type::Object(object_type) = structure_type else {
+        unreachable!("only structures are printed field by field")
+    };
+
+    let mut fields = match context.structures.get_fields_flattened(object_type) {
+        Ok(fields) => fields.keys().collect::<Vec<_>>(),
+        Err(e) => return Box::new(once(Err(e))),
+    };
+    fields.sort();
+
+    let fields = fields
+        .into_iter()
+        .map(|field| {
+            field
+                .split('.')
+                .fold(structure.clone(), |structure, field| {
+                    ExpressionNode::FieldAccess {
+                        structure: Box::new(structure),
+                        field: field.into(),
+                    }
+                })
+        })
+        .collect::<Vec<_>>();
+
+    Box::new(fields.into_iter().flat_map(move |field| {
+        transform_function_call(context, "print".into(), &[field], "".into(), &Type::Void)
+            .collect::<Vec<_>>()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use nilang_types::{
+        instructions::Instruction,
+        nodes::{
+            expressions::{ExpressionNode, Primitive},
+            statements::FunctionDeclaration,
+            Span, Type,
+        },
+    };
+
+    use crate::{
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries, Context, FunctionsRef, GlobalsRef,
+    };
+
+    use super::transform_function_call;
+
+    #[test]
+    fn test_transform_overloaded_function_call() {
+        let context = Context {
+            functions: &FunctionsRef::try_from(
+                [
+                    FunctionDeclaration {
+                        public: false,
+                        constant: false,
+                        name: "double".into(),
+                        parameters: [("value".into(), Type::Int)].into(),
+                        return_type: Type::Int,
+                        body: [].into(),
+                        span: Span::default(),
+                    },
+                    FunctionDeclaration {
+                        public: false,
+                        constant: false,
+                        name: "double".into(),
+                        parameters: [("value".into(), Type::Char)].into(),
+                        return_type: Type::Char,
+                        body: [].into(),
+                        span: Span::default(),
+                    },
+                ]
+                .as_slice(),
+            )
+            .unwrap(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
+        };
+
+        context
+            .temporaries
+            .declare_named("result".into(), Type::Char);
+
+        assert_eq!(
+            transform_function_call(
+                &context,
+                "double".into(),
+                &[ExpressionNode::Primitive(Primitive::Char('a'))],
+                "result".into(),
+                &Type::Char,
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            [
+                Instruction::Declare("temp_0".into()),
+                Instruction::LoadChar("temp_0".into(), 'a'),
+                Instruction::FunctionCall(
+                    "double__char".into(),
+                    ["temp_0".into()].into(),
+                    Some("result".into())
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_transform_print() {
+        let context = Context {
+            functions: &FunctionsRef::try_from([].as_slice()).unwrap(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
+        };
+
+        context
+            .temporaries
+            .declare_named("point".into(), Type::Object("Point".into()));
+        context
+            .temporaries
+            .declare_named("point.x".into(), Type::Int);
+        context
+            .temporaries
+            .declare_named("point.y".into(), Type::Int);
+
+        assert_eq!(
+            transform_function_call(
+                &context,
+                "print".into(),
+                &[ExpressionNode::Primitive(Primitive::Boolean(true))],
+                "".into(),
+                &Type::Void,
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            [
+                Instruction::Declare("temp_0".into()),
+                Instruction::LoadBoolean("temp_0".into(), true),
+                Instruction::FunctionCall("printb".into(), ["temp_0".into()].into(), None),
+            ]
+        );
+
+        assert_eq!(
+            transform_function_call(
+                &context,
+                "print".into(),
+                &[ExpressionNode::VariableReference("point".into())],
+                "".into(),
+                &Type::Void,
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            [
+                Instruction::Declare("temp_1".into()),
+                Instruction::Copy("temp_1".into(), "point.x".into()),
+                Instruction::FunctionCall("printi".into(), ["temp_1".into()].into(), None),
+                Instruction::Declare("temp_2".into()),
+                Instruction::Copy("temp_2".into(), "point.y".into()),
+                Instruction::FunctionCall("printi".into(), ["temp_2".into()].into(), None),
+            ]
+        );
+    }
+}
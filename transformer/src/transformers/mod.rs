@@ -1,3 +1,4 @@
+mod closure_transformer;
 mod conditional_transformer;
 mod field_access_transformator;
 mod function_call_transformer;
@@ -11,11 +12,17 @@ mod while_loop_transformer;
 
 use std::iter::once;
 
+use errors::TransformerErrors;
+
+use closure_transformer::transform_closure;
 use field_access_transformator::transform_field_access;
 use function_call_transformer::transform_function_call;
-use nilang_types::nodes::{
-    expressions::{ExpressionNode, FunctionCall, Primitive},
-    statements::StatementNode,
+use nilang_types::{
+    instructions::DataValue,
+    nodes::{
+        expressions::{ExpressionNode, FunctionCall, Primitive},
+        statements::StatementNode,
+    },
 };
 use object_transformer::transform_object;
 use operation_transformer::transform_operation;
@@ -24,6 +31,8 @@ use variable_declaration_transformer::transform_variable_declaration;
 use variable_reference_transformer::transform_variable_reference;
 
 use crate::{
+    evaluator::{is_evaluation_error, type_of_primitive, Evaluator},
+    globals_ref::Global,
     transformers::{
         conditional_transformer::transform_conditional,
         variable_assignment_transformer::transform_variable_assignment,
@@ -39,22 +48,23 @@ pub fn transform_statement<'a>(
     return_type: &Type,
 ) -> InstructionsIterator<'a> {
     match node {
-        StatementNode::Return(node) => transform_return(context, *node, return_type),
+        StatementNode::Return { value: node, .. } => transform_return(context, *node, return_type),
         StatementNode::VariableDeclaration {
             name,
             r#type,
             value,
+            ..
         } => transform_variable_declaration(context, name, &r#type, *value),
-        StatementNode::VariableAssignment { name, value } => {
+        StatementNode::VariableAssignment { name, value, .. } => {
             transform_variable_assignment(context, name, *value)
         }
-        StatementNode::FunctionCall(FunctionCall { name, arguments }) => {
-            transform_function_call(context, name, &arguments, "".into(), &Type::Void)
-        }
+        StatementNode::FunctionCall(FunctionCall {
+            name, arguments, ..
+        }) => transform_function_call(context, name, &arguments, "".into(), &Type::Void),
         StatementNode::Conditional(conditional) => transform_conditional(context, conditional),
-        StatementNode::WhileLoop { condition, body } => {
-            transform_while_loop(context, condition, &body)
-        }
+        StatementNode::WhileLoop {
+            condition, body, ..
+        } => transform_while_loop(context, condition, &body),
     }
 }
 
@@ -66,6 +76,12 @@ pub fn transform_expression<'a>(
     result: Box<str>,
     r#type: &Type,
 ) -> InstructionsIterator<'a> {
+    if let ExpressionNode::Operation(_) | ExpressionNode::FunctionCall(_) = node {
+        if let Some(folded) = transform_folded(context, &node, result.clone(), r#type) {
+            return folded;
+        }
+    }
+
     match node {
         ExpressionNode::Primitive(primitive) => transform_primitive(context, primitive, result),
         ExpressionNode::Parenthesis(expression) => {
@@ -83,9 +99,49 @@ pub fn transform_expression<'a>(
         ExpressionNode::Operation(operation) => {
             transform_operation(context, operation, result, r#type)
         }
-        ExpressionNode::FunctionCall(FunctionCall { name, arguments }) => {
-            transform_function_call(context, name, &arguments, result, r#type)
+        ExpressionNode::FunctionCall(FunctionCall {
+            name, arguments, ..
+        }) => transform_function_call(context, name, &arguments, result, r#type),
+        ExpressionNode::Closure {
+            parameters,
+            return_type,
+            body,
+        } => transform_closure(context, parameters, return_type, body, result, r#type),
+    }
+}
+
+fn transform_folded<'a>(
+    context @ Context {
+        functions,
+        globals,
+        temporaries,
+        closures,
+        ..
+    }: &'a Context,
+
+    node: &ExpressionNode,
+
+    result: Box<str>,
+    r#type: &Type,
+) -> Option<InstructionsIterator<'a>> {
+    let folded = Evaluator::new(
+        functions,
+        closures.prefix(),
+        &mut |name| match globals.get(name) {
+            Some(Global::Constant(_, primitive)) if temporaries.type_of(name).is_err() => {
+                Ok(primitive.clone())
+            }
+            _ => Err(TransformerErrors::NotConstant { name: name.into() }),
+        },
+    )
+    .evaluate(node);
+
+    match folded {
+        Ok(primitive) if type_of_primitive(&primitive) == *r#type => {
+            Some(transform_primitive(context, primitive, result))
         }
+        Err(e) if is_evaluation_error(&e) => Some(Box::new(once(Err(e)))),
+        _ => None,
     }
 }
 
@@ -108,16 +164,20 @@ fn transform_primitive<'a>(
 
 fn transform_string_declaration<'a>(
     Context {
-        temporaries, data, ..
+        temporaries,
+        data,
+        closures,
+        ..
     }: &'a Context,
 
     text: &str,
     result: Box<str>,
 ) -> InstructionsIterator<'a> {
     let size_temporary = temporaries.declare(Type::Int);
-    let name = <Box<str>>::from(format!("string__{}", result));
+    let name = <Box<str>>::from(format!("{}__string__{}", closures.prefix(), result));
 
-    data.borrow_mut().push((name.clone(), text.into()));
+    data.borrow_mut()
+        .push((name.clone(), DataValue::String(text.into())));
 
     Box::new(
         [
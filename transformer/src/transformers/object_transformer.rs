@@ -42,7 +42,7 @@ pub fn transform_object<'a>(
     }
 
     let mut object_fields = object_fields.iter().collect::<Vec<_>>();
-    object_fields.sort_by(|(a, _), (b, _)| a.cmp(b));
+    object_fields.sort_by_key(|(a, _)| *a);
     let mut provided_fields = fields.into_iter().collect::<Vec<_>>();
     provided_fields.sort_by(|(a, _), (b, _)| a.cmp(b));
 
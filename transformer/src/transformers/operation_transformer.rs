@@ -13,7 +13,7 @@ use super::transform_expression;
 pub fn transform_operation<'a>(
     context @ Context { temporaries, .. }: &'a Context,
 
-    Operation { operator, a, b }: Operation,
+    Operation { operator, a, b, .. }: Operation,
 
     result: Box<str>,
     r#type: &Type,
@@ -34,14 +34,21 @@ pub fn transform_return<'a>(
 mod tests {
     use std::cell::RefCell;
 
+    use nilang_interpreter::IrInterpreter;
     use nilang_types::{
         instructions::Instruction,
-        nodes::{expressions::ExpressionNode, Type},
+        nodes::{
+            expressions::{Arithmetic, ExpressionNode, Operation, Operator, Primitive},
+            statements::{FunctionDeclaration, StatementNode},
+            Span, Type,
+        },
     };
 
     use crate::{
-        labels::Labels, structures_ref::tests::test_structures_ref, temporaries::Temporaries,
-        transformers::return_transformer::transform_return, Context, FunctionsRef,
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries, transform_function,
+        transformers::return_transformer::transform_return, Context, FunctionsRef, GlobalsRef,
+        StructuresRef,
     };
 
     #[test]
@@ -49,9 +56,11 @@ mod tests {
         let context = Context {
             functions: &FunctionsRef::default(),
             structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
             temporaries: Temporaries::default(),
             labels: Labels::default(),
             data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
         };
 
         context.temporaries.declare_named("x".into(), Type::Int);
@@ -77,9 +86,11 @@ mod tests {
         let context = Context {
             functions: &FunctionsRef::default(),
             structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
             temporaries: Temporaries::default(),
             labels: Labels::default(),
             data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
         };
 
         context
@@ -105,4 +116,44 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_transform_return_operation() {
+        let function = FunctionDeclaration {
+            public: false,
+            constant: false,
+            name: "scale".into(),
+            parameters: Box::new([("a".into(), Type::Int), ("b".into(), Type::Int)]),
+            return_type: Type::Int,
+            body: Box::new([StatementNode::Return {
+                value: Box::new(ExpressionNode::Operation(Operation {
+                    operator: Operator::Arithmetic(Arithmetic::Multiply),
+                    a: Box::new(ExpressionNode::VariableReference("a".into())),
+                    b: Box::new(ExpressionNode::Parenthesis(Box::new(
+                        ExpressionNode::Operation(Operation {
+                            operator: Operator::Arithmetic(Arithmetic::Add),
+                            a: Box::new(ExpressionNode::VariableReference("b".into())),
+                            b: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                            span: Span::default(),
+                        }),
+                    ))),
+                    span: Span::default(),
+                })),
+                span: Span::default(),
+            }]),
+            span: Span::default(),
+        };
+        let refs = (
+            FunctionsRef::try_from([function.clone()].as_slice()).unwrap(),
+            StructuresRef::default(),
+            GlobalsRef::default(),
+        );
+
+        let (instructions, _, _) = transform_function(&refs, &function);
+        let mut interpreter = IrInterpreter::new(Vec::new());
+        interpreter.declare_function("scale", instructions.collect::<Result<_, _>>().unwrap());
+
+        assert_eq!(interpreter.call("scale", &[3, 4]).unwrap(), Some(15));
+        assert_eq!(interpreter.call("scale", &[-2, 0]).unwrap(), Some(-2));
+    }
 }
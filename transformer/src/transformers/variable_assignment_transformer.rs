@@ -1,19 +1,50 @@
 use std::iter::once;
 
 use errors::TransformerErrors;
-use nilang_types::nodes::expressions::ExpressionNode;
+use nilang_types::{instructions::Instruction, nodes::expressions::ExpressionNode};
 
-use crate::{Context, InstructionsIterator};
+use crate::{
+    globals_ref::{width_of, Global},
+    Context, InstructionsIterator,
+};
 
 use super::transform_expression;
 
 pub fn transform_variable_assignment<'a>(
-    context @ Context { temporaries, .. }: &'a Context,
+    context @ Context {
+        globals,
+        temporaries,
+        ..
+    }: &'a Context,
 
     name: Box<str>,
     node: ExpressionNode,
 ) -> InstructionsIterator<'a> {
     let Ok(original_type) = temporaries.type_of(&name) else {
+        match globals.get(&name) {
+            Some(Global::Variable(symbol, global_type)) => {
+                let temporary = temporaries.declare(global_type.clone());
+                return Box::new(
+                    once(Ok(Instruction::Declare(temporary.clone())))
+                        .chain(transform_expression(
+                            context,
+                            node,
+                            temporary.clone(),
+                            global_type,
+                        ))
+                        .chain(once(Ok(Instruction::StoreGlobal(
+                            symbol.clone(),
+                            width_of(global_type),
+                            temporary,
+                        )))),
+                );
+            }
+            Some(Global::Constant(_, _)) => {
+                return Box::new(once(Err(TransformerErrors::AssignmentToConstant { name })));
+            }
+            None => {}
+        }
+
         return Box::new(once(Err(TransformerErrors::TemporaryNotFound {
             name: name.clone(),
         })));
@@ -32,8 +63,8 @@ mod tests {
     };
 
     use crate::{
-        labels::Labels, structures_ref::tests::test_structures_ref, temporaries::Temporaries,
-        FunctionsRef,
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries, FunctionsRef, GlobalsRef,
     };
 
     use super::*;
@@ -43,9 +74,11 @@ mod tests {
         let context = Context {
             functions: &FunctionsRef::default(),
             structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
             temporaries: Temporaries::default(),
             labels: Labels::default(),
             data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
         };
 
         context.temporaries.declare_named("a".into(), Type::Int);
@@ -32,11 +32,11 @@ pub fn transform_variable_declaration<'a>(
 mod tests {
     use std::cell::RefCell;
 
-    use nilang_types::nodes::expressions::Primitive;
+    use nilang_types::{instructions::DataValue, nodes::expressions::Primitive};
 
     use crate::{
-        labels::Labels, structures_ref::tests::test_structures_ref, temporaries::Temporaries,
-        FunctionsRef,
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries, FunctionsRef, GlobalsRef,
     };
 
     use super::*;
@@ -46,9 +46,11 @@ mod tests {
         let context = Context {
             functions: &FunctionsRef::default(),
             structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
             temporaries: Temporaries::default(),
             labels: Labels::default(),
             data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
         };
 
         assert_eq!(
@@ -81,4 +83,42 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_string_declaration() {
+        let data = RefCell::new(Vec::new());
+        let context = Context {
+            functions: &FunctionsRef::default(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &data,
+            closures: &Closures::new("math__hello"),
+        };
+
+        assert_eq!(
+            transform_variable_declaration(
+                &context,
+                "a".into(),
+                &Type::String,
+                ExpressionNode::Primitive(Primitive::String("hi".into())),
+            )
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+            [
+                Instruction::Declare("a".into()),
+                Instruction::Declare("temp_0".into()),
+                Instruction::LoadNumber("temp_0".into(), 2.),
+                Instruction::LoadStringLocation("a".into(), "math__hello__string__a".into()),
+            ]
+        );
+        assert_eq!(
+            data.take(),
+            [(
+                "math__hello__string__a".into(),
+                DataValue::String("hi".into())
+            )]
+        );
+    }
 }
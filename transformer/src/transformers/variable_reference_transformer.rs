@@ -2,11 +2,21 @@ use std::iter::once;
 
 use errors::TransformerErrors;
 
-use crate::{structures_ref::copy_all_fields, Context, InstructionsIterator, Type};
+use nilang_types::instructions::Instruction;
+
+use crate::{
+    globals_ref::{width_of, Global},
+    structures_ref::copy_all_fields,
+    Context, InstructionsIterator, Type,
+};
+
+use super::{closure_transformer::create_closure, transform_primitive};
 
 pub fn transform_variable_reference<'a>(
-    Context {
+    context @ Context {
+        functions,
         structures,
+        globals,
         temporaries,
         ..
     }: &'a Context,
@@ -16,6 +26,27 @@ pub fn transform_variable_reference<'a>(
     r#type: &Type,
 ) -> InstructionsIterator<'a> {
     let Ok(source_type) = temporaries.type_of(&variable) else {
+        if let Some(global) = globals.get(&variable) {
+            return transform_global_reference(context, global, result, r#type);
+        }
+
+        if let Type::Function {
+            parameters,
+            return_type,
+        } = r#type
+        {
+            return match functions.resolve(&variable, parameters) {
+                Ok((symbol, found, _)) if found == &**return_type => {
+                    create_closure(context, symbol.into(), &[], result)
+                }
+                Ok((_, found, _)) => Box::new(once(Err(TransformerErrors::TypeMismatch {
+                    expected: *return_type.clone(),
+                    found: found.clone(),
+                }))),
+                Err(e) => Box::new(once(Err(e))),
+            };
+        }
+
         return Box::new(once(Err(TransformerErrors::TemporaryNotFound {
             name: variable.clone(),
         })));
@@ -31,16 +62,49 @@ pub fn transform_variable_reference<'a>(
     copy_all_fields(structures, temporaries, variable, result, &source_type)
 }
 
+fn transform_global_reference<'a>(
+    context: &'a Context,
+
+    global: &Global,
+    result: Box<str>,
+    r#type: &Type,
+) -> InstructionsIterator<'a> {
+    let (Global::Constant(global_type, _) | Global::Variable(_, global_type)) = global;
+    if r#type != global_type {
+        return Box::new(once(Err(TransformerErrors::TypeMismatch {
+            expected: r#type.clone(),
+            found: global_type.clone(),
+        })));
+    }
+
+    match global {
+        Global::Constant(_, primitive) => transform_primitive(context, primitive.clone(), result),
+        Global::Variable(symbol, global_type) => Box::new(once(Ok(Instruction::LoadGlobal(
+            result,
+            symbol.clone(),
+            width_of(global_type),
+        )))),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
 
-    use nilang_types::instructions::Instruction;
+    use nilang_types::{
+        instructions::{Instruction, Width},
+        nodes::{
+            expressions::{ExpressionNode, Primitive},
+            statements::GlobalDeclaration,
+            Span,
+        },
+    };
 
     use crate::{
-        labels::Labels, structures_ref::tests::test_structures_ref, temporaries::Temporaries,
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries,
         transformers::variable_reference_transformer::transform_variable_reference, Context,
-        FunctionsRef, Type,
+        FunctionsRef, GlobalsRef, Type,
     };
 
     #[test]
@@ -48,9 +112,11 @@ mod tests {
         let context = Context {
             functions: &FunctionsRef::default(),
             structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
             temporaries: Temporaries::default(),
             labels: Labels::default(),
             data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
         };
 
         context
@@ -81,4 +147,55 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_transform_global_reference() {
+        let context = Context {
+            functions: &FunctionsRef::default(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::try_from(
+                [
+                    GlobalDeclaration {
+                        public: false,
+                        constant: true,
+                        name: "LIMIT".into(),
+                        r#type: Type::Int,
+                        value: Box::new(ExpressionNode::Primitive(Primitive::Number(8.))),
+                        span: Span::default(),
+                    },
+                    GlobalDeclaration {
+                        public: false,
+                        constant: false,
+                        name: "ready".into(),
+                        r#type: Type::Bool,
+                        value: Box::new(ExpressionNode::Primitive(Primitive::Boolean(false))),
+                        span: Span::default(),
+                    },
+                ]
+                .as_slice(),
+            )
+            .unwrap(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
+        };
+
+        assert_eq!(
+            transform_variable_reference(&context, "LIMIT".into(), "a".into(), &Type::Int)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            [Instruction::LoadNumber("a".into(), 8.)]
+        );
+        assert_eq!(
+            transform_variable_reference(&context, "ready".into(), "b".into(), &Type::Bool)
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap(),
+            [Instruction::LoadGlobal(
+                "b".into(),
+                "global__ready".into(),
+                Width::Byte
+            )]
+        );
+    }
 }
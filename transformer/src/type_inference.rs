@@ -0,0 +1,169 @@
+use errors::TransformerErrors;
+use nilang_types::nodes::{
+    expressions::{ExpressionNode, FunctionCall, Operation, Operator, Primitive},
+    Type,
+};
+
+use crate::Context;
+
+pub fn infer_type(context: &Context, node: &ExpressionNode) -> Result<Type, TransformerErrors> {
+    Ok(match node {
+        ExpressionNode::Primitive(primitive) => match primitive {
+            Primitive::Boolean(_) => Type::Bool,
+            Primitive::Number(_) => Type::Int,
+            Primitive::Char(_) => Type::Char,
+            Primitive::String(_) => Type::String,
+        },
+        ExpressionNode::VariableReference(name) => context
+            .temporaries
+            .type_of(name)
+            .or_else(|e| context.globals.type_of(name).ok_or(e))
+            .or_else(|e| match context.functions.function_type(name) {
+                Err(TransformerErrors::FunctionNotFound { .. }) => Err(e),
+                result => result,
+            })?,
+        ExpressionNode::FieldAccess { structure, field } => {
+            let Type::Object(structure_type) = infer_type(context, structure)? else {
+                return Err(TransformerErrors::FieldsMismatch {
+                    expected: [].into(),
+                    found: [field.clone()].into(),
+                });
+            };
+
+            let fields = context.structures.get_fields(&structure_type).ok_or(
+                TransformerErrors::TypeNotFound {
+                    name: structure_type.clone(),
+                },
+            )?;
+
+            fields
+                .get(field)
+                .cloned()
+                .ok_or_else(|| TransformerErrors::FieldsMismatch {
+                    expected: fields.keys().cloned().collect(),
+                    found: [field.clone()].into(),
+                })?
+        }
+        ExpressionNode::FunctionCall(FunctionCall {
+            name, arguments, ..
+        }) => {
+            let arguments = arguments
+                .iter()
+                .map(|argument| infer_type(context, argument))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Ok(Type::Function { return_type, .. }) = context.temporaries.type_of(name) {
+                return Ok(*return_type);
+            }
+
+            context.functions.resolve(name, &arguments)?.1.clone()
+        }
+        ExpressionNode::Parenthesis(expression) => infer_type(context, expression)?,
+        ExpressionNode::Operation(Operation { operator, .. }) => match operator {
+            Operator::Arithmetic(_) => Type::Int,
+            Operator::Boolean(_) => Type::Bool,
+        },
+        ExpressionNode::Object { r#type, .. } => r#type.clone(),
+        ExpressionNode::Closure {
+            parameters,
+            return_type,
+            ..
+        } => Type::Function {
+            parameters: parameters
+                .iter()
+                .map(|(_, r#type)| r#type.clone())
+                .collect(),
+            return_type: Box::new(return_type.clone()),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::HashMap};
+
+    use nilang_types::nodes::{
+        expressions::{Arithmetic, Boolean, ExpressionNode, Operation, Operator, Primitive},
+        Span, Type,
+    };
+
+    use crate::{
+        closures::Closures, labels::Labels, structures_ref::tests::test_structures_ref,
+        temporaries::Temporaries, Context, FunctionsRef, GlobalsRef,
+    };
+
+    use super::infer_type;
+
+    #[test]
+    fn test_infer_type() {
+        let context = Context {
+            functions: &FunctionsRef::try_from([].as_slice()).unwrap(),
+            structures: &test_structures_ref(),
+            globals: &GlobalsRef::default(),
+            temporaries: Temporaries::default(),
+            labels: Labels::default(),
+            data: &RefCell::new(Vec::new()),
+            closures: &Closures::default(),
+        };
+
+        context
+            .temporaries
+            .declare_named("rect".into(), Type::Object("Rect".into()));
+
+        assert_eq!(
+            infer_type(&context, &ExpressionNode::Primitive(Primitive::Char('a'))).unwrap(),
+            Type::Char
+        );
+        assert_eq!(
+            infer_type(
+                &context,
+                &ExpressionNode::FieldAccess {
+                    structure: Box::new(ExpressionNode::FieldAccess {
+                        structure: Box::new(ExpressionNode::VariableReference("rect".into())),
+                        field: "start".into(),
+                    }),
+                    field: "x".into(),
+                }
+            )
+            .unwrap(),
+            Type::Int
+        );
+        assert_eq!(
+            infer_type(
+                &context,
+                &ExpressionNode::Operation(Operation {
+                    operator: Operator::Boolean(Boolean::Less),
+                    a: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: Span::default()
+                })
+            )
+            .unwrap(),
+            Type::Bool
+        );
+        assert_eq!(
+            infer_type(
+                &context,
+                &ExpressionNode::Parenthesis(Box::new(ExpressionNode::Operation(Operation {
+                    operator: Operator::Arithmetic(Arithmetic::Add),
+                    a: Box::new(ExpressionNode::Primitive(Primitive::Number(1.))),
+                    b: Box::new(ExpressionNode::Primitive(Primitive::Number(2.))),
+                    span: Span::default()
+                })))
+            )
+            .unwrap(),
+            Type::Int
+        );
+        assert_eq!(
+            infer_type(
+                &context,
+                &ExpressionNode::Object {
+                    r#type: Type::Object("Point".into()),
+                    fields: HashMap::new(),
+                }
+            )
+            .unwrap(),
+            Type::Object("Point".into())
+        );
+    }
+}
@@ -1,9 +1,15 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
+
 type Temporary = Box<str>;
 type Label = Box<str>;
 type Boolean = bool;
 type Number = f64;
 type Char = char;
 type Function = Box<str>;
+type Global = Box<str>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Instruction {
@@ -14,6 +20,7 @@ pub enum Instruction {
     Declare(Temporary),
 
     FunctionCall(Function, Box<[Temporary]>, Option<Temporary>),
+    IndirectFunctionCall(Temporary, Box<[Temporary]>, Option<Temporary>),
     TakeArgument(usize, Temporary),
 
     ReturnVariable(Temporary),
@@ -22,6 +29,13 @@ pub enum Instruction {
     LoadNumber(Temporary, Number),
     LoadChar(Temporary, Char),
     LoadStringLocation(Temporary, Temporary),
+    LoadFunctionLocation(Temporary, Function),
+
+    LoadGlobal(Temporary, Global, Width),
+    StoreGlobal(Global, Width, Temporary),
+
+    LoadFromAddress(Temporary, Temporary, usize),
+    StoreToAddress(Temporary, usize, Temporary),
 
     Copy(Temporary, Temporary),
 
@@ -38,3 +52,520 @@ pub enum Instruction {
     TestLessOrEqual(Temporary, Temporary, Temporary),
     TestMoreOrEqual(Temporary, Temporary, Temporary),
 }
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Width {
+    Byte,
+    Quad,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataValue {
+    String(Box<str>),
+    Quad(i64),
+    Byte(u8),
+}
+
+pub type Data = Vec<(Box<str>, DataValue)>;
+pub type Functions = Vec<(Box<str>, Vec<Instruction>)>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct InvalidInstruction {
+    pub line: usize,
+    pub text: Box<str>,
+}
+
+impl Display for InvalidInstruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid instruction at line {}: `{}`",
+            self.line, self.text
+        )
+    }
+}
+
+impl std::error::Error for InvalidInstruction {}
+
+impl Instruction {
+    pub fn uses(&self) -> Vec<&Temporary> {
+        match self {
+            Instruction::ConditionalJump(temporary, _)
+            | Instruction::ReturnVariable(temporary)
+            | Instruction::StoreGlobal(_, _, temporary)
+            | Instruction::LoadFromAddress(_, temporary, _)
+            | Instruction::Copy(_, temporary) => vec![temporary],
+            Instruction::FunctionCall(_, arguments, _) => arguments.iter().collect(),
+            Instruction::IndirectFunctionCall(function, arguments, _) => {
+                [function].into_iter().chain(arguments.iter()).collect()
+            }
+            Instruction::StoreToAddress(address, _, temporary) => vec![address, temporary],
+            Instruction::AddVariables(_, a, b)
+            | Instruction::SubtractVariables(_, a, b)
+            | Instruction::MultiplyVariables(_, a, b)
+            | Instruction::DivideVariables(_, a, b)
+            | Instruction::ModuloVariables(_, a, b)
+            | Instruction::TestEqual(_, a, b)
+            | Instruction::TestNotEqual(_, a, b)
+            | Instruction::TestLess(_, a, b)
+            | Instruction::TestMore(_, a, b)
+            | Instruction::TestLessOrEqual(_, a, b)
+            | Instruction::TestMoreOrEqual(_, a, b) => vec![a, b],
+            Instruction::Label(_)
+            | Instruction::Jump(_)
+            | Instruction::Declare(_)
+            | Instruction::TakeArgument(_, _)
+            | Instruction::LoadBoolean(_, _)
+            | Instruction::LoadNumber(_, _)
+            | Instruction::LoadChar(_, _)
+            | Instruction::LoadStringLocation(_, _)
+            | Instruction::LoadFunctionLocation(_, _)
+            | Instruction::LoadGlobal(_, _, _) => Vec::new(),
+        }
+    }
+
+    pub fn definition(&self) -> Option<&Temporary> {
+        match self {
+            Instruction::FunctionCall(_, _, result)
+            | Instruction::IndirectFunctionCall(_, _, result) => result.as_ref(),
+            Instruction::TakeArgument(_, temporary)
+            | Instruction::LoadBoolean(temporary, _)
+            | Instruction::LoadNumber(temporary, _)
+            | Instruction::LoadChar(temporary, _)
+            | Instruction::LoadStringLocation(temporary, _)
+            | Instruction::LoadFunctionLocation(temporary, _)
+            | Instruction::LoadGlobal(temporary, _, _)
+            | Instruction::LoadFromAddress(temporary, _, _)
+            | Instruction::Copy(temporary, _)
+            | Instruction::AddVariables(temporary, _, _)
+            | Instruction::SubtractVariables(temporary, _, _)
+            | Instruction::MultiplyVariables(temporary, _, _)
+            | Instruction::DivideVariables(temporary, _, _)
+            | Instruction::ModuloVariables(temporary, _, _)
+            | Instruction::TestEqual(temporary, _, _)
+            | Instruction::TestNotEqual(temporary, _, _)
+            | Instruction::TestLess(temporary, _, _)
+            | Instruction::TestMore(temporary, _, _)
+            | Instruction::TestLessOrEqual(temporary, _, _)
+            | Instruction::TestMoreOrEqual(temporary, _, _) => Some(temporary),
+            Instruction::Label(_)
+            | Instruction::Jump(_)
+            | Instruction::ConditionalJump(_, _)
+            | Instruction::Declare(_)
+            | Instruction::ReturnVariable(_)
+            | Instruction::StoreGlobal(_, _, _)
+            | Instruction::StoreToAddress(_, _, _) => None,
+        }
+    }
+
+    pub fn uses_mut(&mut self) -> Vec<&mut Temporary> {
+        match self {
+            Instruction::ConditionalJump(temporary, _)
+            | Instruction::ReturnVariable(temporary)
+            | Instruction::StoreGlobal(_, _, temporary)
+            | Instruction::LoadFromAddress(_, temporary, _)
+            | Instruction::Copy(_, temporary) => vec![temporary],
+            Instruction::FunctionCall(_, arguments, _) => arguments.iter_mut().collect(),
+            Instruction::IndirectFunctionCall(function, arguments, _) => {
+                [function].into_iter().chain(arguments.iter_mut()).collect()
+            }
+            Instruction::StoreToAddress(address, _, temporary) => vec![address, temporary],
+            Instruction::AddVariables(_, a, b)
+            | Instruction::SubtractVariables(_, a, b)
+            | Instruction::MultiplyVariables(_, a, b)
+            | Instruction::DivideVariables(_, a, b)
+            | Instruction::ModuloVariables(_, a, b)
+            | Instruction::TestEqual(_, a, b)
+            | Instruction::TestNotEqual(_, a, b)
+            | Instruction::TestLess(_, a, b)
+            | Instruction::TestMore(_, a, b)
+            | Instruction::TestLessOrEqual(_, a, b)
+            | Instruction::TestMoreOrEqual(_, a, b) => vec![a, b],
+            Instruction::Label(_)
+            | Instruction::Jump(_)
+            | Instruction::Declare(_)
+            | Instruction::TakeArgument(_, _)
+            | Instruction::LoadBoolean(_, _)
+            | Instruction::LoadNumber(_, _)
+            | Instruction::LoadChar(_, _)
+            | Instruction::LoadStringLocation(_, _)
+            | Instruction::LoadFunctionLocation(_, _)
+            | Instruction::LoadGlobal(_, _, _) => Vec::new(),
+        }
+    }
+
+    pub fn definition_mut(&mut self) -> Option<&mut Temporary> {
+        match self {
+            Instruction::FunctionCall(_, _, result)
+            | Instruction::IndirectFunctionCall(_, _, result) => result.as_mut(),
+            Instruction::TakeArgument(_, temporary)
+            | Instruction::LoadBoolean(temporary, _)
+            | Instruction::LoadNumber(temporary, _)
+            | Instruction::LoadChar(temporary, _)
+            | Instruction::LoadStringLocation(temporary, _)
+            | Instruction::LoadFunctionLocation(temporary, _)
+            | Instruction::LoadGlobal(temporary, _, _)
+            | Instruction::LoadFromAddress(temporary, _, _)
+            | Instruction::Copy(temporary, _)
+            | Instruction::AddVariables(temporary, _, _)
+            | Instruction::SubtractVariables(temporary, _, _)
+            | Instruction::MultiplyVariables(temporary, _, _)
+            | Instruction::DivideVariables(temporary, _, _)
+            | Instruction::ModuloVariables(temporary, _, _)
+            | Instruction::TestEqual(temporary, _, _)
+            | Instruction::TestNotEqual(temporary, _, _)
+            | Instruction::TestLess(temporary, _, _)
+            | Instruction::TestMore(temporary, _, _)
+            | Instruction::TestLessOrEqual(temporary, _, _)
+            | Instruction::TestMoreOrEqual(temporary, _, _) => Some(temporary),
+            Instruction::Label(_)
+            | Instruction::Jump(_)
+            | Instruction::ConditionalJump(_, _)
+            | Instruction::Declare(_)
+            | Instruction::ReturnVariable(_)
+            | Instruction::StoreGlobal(_, _, _)
+            | Instruction::StoreToAddress(_, _, _) => None,
+        }
+    }
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Label(label) => write!(f, "{}:", label),
+            Instruction::Jump(label) => write!(f, "jmp {}", label),
+            Instruction::ConditionalJump(check, label) => write!(f, "jz {}, {}", check, label),
+            Instruction::Declare(temporary) => write!(f, "declare {}", temporary),
+            Instruction::FunctionCall(function, arguments, result) => {
+                write_call(f, function, arguments, result.as_deref())
+            }
+            Instruction::IndirectFunctionCall(function, arguments, result) => {
+                write_call(f, &format!("*{}", function), arguments, result.as_deref())
+            }
+            Instruction::TakeArgument(n, temporary) => write!(f, "{} = arg {}", temporary, n),
+            Instruction::ReturnVariable(temporary) => write!(f, "ret {}", temporary),
+            Instruction::LoadBoolean(temporary, boolean) => {
+                write!(f, "{} = bool {}", temporary, boolean)
+            }
+            Instruction::LoadNumber(temporary, number) => {
+                write!(f, "{} = num {}", temporary, number)
+            }
+            Instruction::LoadChar(temporary, char) => {
+                write!(f, "{} = char '{}'", temporary, char.escape_default())
+            }
+            Instruction::LoadStringLocation(temporary, data) => {
+                write!(f, "{} = string {}", temporary, data)
+            }
+            Instruction::LoadFunctionLocation(temporary, function) => {
+                write!(f, "{} = function {}", temporary, function)
+            }
+            Instruction::LoadGlobal(temporary, global, width) => {
+                write!(f, "{} = load.{} {}", temporary, width, global)
+            }
+            Instruction::StoreGlobal(global, width, temporary) => {
+                write!(f, "store.{} {}, {}", width, global, temporary)
+            }
+            Instruction::LoadFromAddress(temporary, address, offset) => {
+                write!(f, "{} = load {}[{}]", temporary, address, offset)
+            }
+            Instruction::StoreToAddress(address, offset, temporary) => {
+                write!(f, "store {}[{}], {}", address, offset, temporary)
+            }
+            Instruction::Copy(to, from) => write!(f, "{} = copy {}", to, from),
+            Instruction::AddVariables(result, a, b) => write!(f, "{} = add {}, {}", result, a, b),
+            Instruction::SubtractVariables(result, a, b) => {
+                write!(f, "{} = sub {}, {}", result, a, b)
+            }
+            Instruction::MultiplyVariables(result, a, b) => {
+                write!(f, "{} = mul {}, {}", result, a, b)
+            }
+            Instruction::DivideVariables(result, a, b) => {
+                write!(f, "{} = div {}, {}", result, a, b)
+            }
+            Instruction::ModuloVariables(result, a, b) => {
+                write!(f, "{} = mod {}, {}", result, a, b)
+            }
+            Instruction::TestEqual(result, a, b) => write!(f, "{} = eq {}, {}", result, a, b),
+            Instruction::TestNotEqual(result, a, b) => write!(f, "{} = ne {}, {}", result, a, b),
+            Instruction::TestLess(result, a, b) => write!(f, "{} = lt {}, {}", result, a, b),
+            Instruction::TestMore(result, a, b) => write!(f, "{} = gt {}, {}", result, a, b),
+            Instruction::TestLessOrEqual(result, a, b) => {
+                write!(f, "{} = le {}, {}", result, a, b)
+            }
+            Instruction::TestMoreOrEqual(result, a, b) => {
+                write!(f, "{} = ge {}, {}", result, a, b)
+            }
+        }
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = InvalidInstruction;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_instruction(text.trim()).ok_or_else(|| InvalidInstruction {
+            line: 0,
+            text: text.trim().into(),
+        })
+    }
+}
+
+impl Display for Width {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Width::Byte => write!(f, "b"),
+            Width::Quad => write!(f, "q"),
+        }
+    }
+}
+
+impl Display for DataValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DataValue::String(text) => write!(f, "string \"{}\"", text.escape_default()),
+            DataValue::Quad(number) => write!(f, "quad {}", number),
+            DataValue::Byte(byte) => write!(f, "byte {}", byte),
+        }
+    }
+}
+
+pub fn parse_instructions(code: &str) -> Result<Vec<Instruction>, InvalidInstruction> {
+    code.lines()
+        .enumerate()
+        .map(|(i, line)| (i, line.split("//").next().unwrap_or_default().trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| {
+            parse_instruction(line).ok_or_else(|| InvalidInstruction {
+                line: i,
+                text: line.into(),
+            })
+        })
+        .collect()
+}
+
+pub fn format_program(
+    data: &[(Box<str>, DataValue)],
+    functions: &[(Box<str>, Vec<Instruction>)],
+) -> String {
+    let mut program = String::new();
+    for (name, value) in data {
+        program += &format!("data {} = {}\n", name, value);
+    }
+    for (name, instructions) in functions {
+        program += &format!("\nfn {} {{\n", name);
+        for instruction in instructions {
+            match instruction {
+                Instruction::Label(_) => program += &format!("{}\n", instruction),
+                instruction => program += &format!("    {}\n", instruction),
+            }
+        }
+        program += "}\n";
+    }
+    program
+}
+
+pub fn parse_program(code: &str) -> Result<(Data, Functions), InvalidInstruction> {
+    let mut data = Vec::new();
+    let mut functions = Vec::<(Box<str>, Vec<Instruction>)>::new();
+    let mut open = None;
+
+    for (i, line) in code.lines().enumerate() {
+        let invalid = || InvalidInstruction {
+            line: i,
+            text: line.trim().into(),
+        };
+
+        if let Some(definition) = line.trim().strip_prefix("data ").filter(|_| open.is_none()) {
+            let (name, value) = definition.split_once(" = ").ok_or_else(invalid)?;
+            data.push((
+                self::name(name).ok_or_else(invalid)?,
+                parse_data(value).ok_or_else(invalid)?,
+            ));
+            continue;
+        }
+
+        let line = line.split("//").next().unwrap_or_default().trim();
+        match (&open, line) {
+            (_, "") => {}
+            (Some(_), "}") => open = None,
+            (Some(_), line) => {
+                let instruction = parse_instruction(line).ok_or_else(invalid)?;
+                if let Some((_, instructions)) = functions.last_mut() {
+                    instructions.push(instruction);
+                }
+            }
+            (None, line) => {
+                let function = line
+                    .strip_prefix("fn ")
+                    .and_then(|line| line.strip_suffix('{'))
+                    .and_then(name)
+                    .ok_or_else(invalid)?;
+                functions.push((function, Vec::new()));
+                open = Some(invalid());
+            }
+        }
+    }
+
+    match open {
+        Some(unclosed) => Err(unclosed),
+        None => Ok((data, functions)),
+    }
+}
+
+fn parse_data(text: &str) -> Option<DataValue> {
+    let (kind, value) = text.trim().split_once(' ')?;
+    Some(match kind {
+        "string" => {
+            DataValue::String(unescape(value.strip_prefix('"')?.strip_suffix('"')?)?.into())
+        }
+        "quad" => DataValue::Quad(value.parse().ok()?),
+        "byte" => DataValue::Byte(value.parse().ok()?),
+        _ => None?,
+    })
+}
+
+fn write_call(
+    f: &mut Formatter<'_>,
+    function: &str,
+    arguments: &[Temporary],
+    result: Option<&str>,
+) -> fmt::Result {
+    if let Some(result) = result {
+        write!(f, "{} = ", result)?;
+    }
+    write!(f, "call {}({})", function, arguments.join(", "))
+}
+
+fn parse_instruction(line: &str) -> Option<Instruction> {
+    if let Some(label) = line.strip_suffix(':') {
+        return Some(Instruction::Label(name(label)?));
+    }
+
+    let (result, operation) = match line.split_once(" = ") {
+        Some((result, operation)) => (Some(name(result)?), operation),
+        None => (None, line),
+    };
+    let (mnemonic, operands) = operation.split_once(' ').unwrap_or((operation, ""));
+    let operands = operands.trim();
+
+    Some(match (result, mnemonic) {
+        (None, "jmp") => Instruction::Jump(name(operands)?),
+        (None, "jz") => {
+            let [check, label] = names(operands)?;
+            Instruction::ConditionalJump(check, label)
+        }
+        (None, "declare") => Instruction::Declare(name(operands)?),
+        (None, "ret") => Instruction::ReturnVariable(name(operands)?),
+        (result, "call") => {
+            let (function, arguments) = operands.strip_suffix(')')?.split_once('(')?;
+            let arguments = match arguments.trim() {
+                "" => Box::default(),
+                arguments => arguments.split(',').map(name).collect::<Option<_>>()?,
+            };
+            match function.strip_prefix('*') {
+                Some(function) => {
+                    Instruction::IndirectFunctionCall(name(function)?, arguments, result)
+                }
+                None => Instruction::FunctionCall(name(function)?, arguments, result),
+            }
+        }
+        (Some(result), "arg") => Instruction::TakeArgument(operands.parse().ok()?, result),
+        (Some(result), "bool") => Instruction::LoadBoolean(result, operands.parse().ok()?),
+        (Some(result), "num") => Instruction::LoadNumber(result, operands.parse().ok()?),
+        (Some(result), "char") => {
+            let char = unescape(operands.strip_prefix('\'')?.strip_suffix('\'')?)?;
+            let mut chars = char.chars();
+            match (chars.next(), chars.next()) {
+                (Some(char), None) => Instruction::LoadChar(result, char),
+                _ => None?,
+            }
+        }
+        (Some(result), "string") => Instruction::LoadStringLocation(result, name(operands)?),
+        (Some(result), "function") => Instruction::LoadFunctionLocation(result, name(operands)?),
+        (Some(result), "load.b") => Instruction::LoadGlobal(result, name(operands)?, Width::Byte),
+        (Some(result), "load.q") => Instruction::LoadGlobal(result, name(operands)?, Width::Quad),
+        (None, "store.b" | "store.q") => {
+            let [global, temporary] = names(operands)?;
+            let width = if mnemonic == "store.b" {
+                Width::Byte
+            } else {
+                Width::Quad
+            };
+            Instruction::StoreGlobal(global, width, temporary)
+        }
+        (Some(result), "load") => {
+            let (address, offset) = address(operands)?;
+            Instruction::LoadFromAddress(result, address, offset)
+        }
+        (None, "store") => {
+            let (target, temporary) = operands.rsplit_once(',')?;
+            let (address, offset) = address(target)?;
+            Instruction::StoreToAddress(address, offset, name(temporary)?)
+        }
+        (Some(result), "copy") => Instruction::Copy(result, name(operands)?),
+        (Some(result), mnemonic) => {
+            let [a, b] = names(operands)?;
+            match mnemonic {
+                "add" => Instruction::AddVariables(result, a, b),
+                "sub" => Instruction::SubtractVariables(result, a, b),
+                "mul" => Instruction::MultiplyVariables(result, a, b),
+                "div" => Instruction::DivideVariables(result, a, b),
+                "mod" => Instruction::ModuloVariables(result, a, b),
+                "eq" => Instruction::TestEqual(result, a, b),
+                "ne" => Instruction::TestNotEqual(result, a, b),
+                "lt" => Instruction::TestLess(result, a, b),
+                "gt" => Instruction::TestMore(result, a, b),
+                "le" => Instruction::TestLessOrEqual(result, a, b),
+                "ge" => Instruction::TestMoreOrEqual(result, a, b),
+                _ => None?,
+            }
+        }
+        _ => None?,
+    })
+}
+
+fn name(text: &str) -> Option<Box<str>> {
+    let text = text.trim();
+    if text.is_empty()
+        || text
+            .chars()
+            .any(|char| char.is_whitespace() || "=,:()[]*'".contains(char))
+    {
+        return None;
+    }
+    Some(text.into())
+}
+
+fn names(text: &str) -> Option<[Box<str>; 2]> {
+    let (a, b) = text.split_once(',')?;
+    Some([name(a)?, name(b)?])
+}
+
+fn address(text: &str) -> Option<(Box<str>, usize)> {
+    let (address, offset) = text.trim().strip_suffix(']')?.split_once('[')?;
+    Some((name(address)?, offset.trim().parse().ok()?))
+}
+
+fn unescape(text: &str) -> Option<String> {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(char) = chars.next() {
+        unescaped.push(match char {
+            '\\' => match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                char @ ('\\' | '\'' | '"') => char,
+                'u' => {
+                    let (code, rest) = chars.as_str().strip_prefix('{')?.split_once('}')?;
+                    chars = rest.chars();
+                    char::from_u32(u32::from_str_radix(code, 16).ok()?)?
+                }
+                _ => return None,
+            },
+            char => char,
+        });
+    }
+    Some(unescaped)
+}
@@ -1,6 +1,12 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
 
-use super::{statements::StatementNode, Type};
+use super::{
+    statements::{write_body, write_parameters, Parameter, StatementNode},
+    Span, Type,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExpressionNode {
@@ -17,6 +23,11 @@ pub enum ExpressionNode {
         r#type: Type,
         fields: HashMap<Box<str>, ExpressionNode>,
     },
+    Closure {
+        parameters: Box<[Parameter]>,
+        return_type: Type,
+        body: Box<[StatementNode]>,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -32,6 +43,7 @@ pub struct Operation {
     pub operator: Operator,
     pub a: Box<ExpressionNode>,
     pub b: Box<ExpressionNode>,
+    pub span: Span,
 }
 
 #[derive(Debug, Copy, Clone, PartialEq)]
@@ -65,6 +77,7 @@ pub enum Boolean {
 pub struct FunctionCall {
     pub name: Box<str>,
     pub arguments: Box<[ExpressionNode]>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -72,6 +85,7 @@ pub struct Conditional {
     pub condition: ExpressionNode,
     pub body: Box<[StatementNode]>,
     pub chained: Option<Box<Conditional>>,
+    pub span: Span,
 }
 
 impl Default for ExpressionNode {
@@ -91,3 +105,212 @@ impl Default for Operator {
         Self::Arithmetic(Default::default())
     }
 }
+
+impl Operator {
+    pub fn precedence(self) -> u8 {
+        match self {
+            Operator::Arithmetic(Arithmetic::Add | Arithmetic::Subtract) => 0,
+            Operator::Arithmetic(
+                Arithmetic::Multiply | Arithmetic::Divide | Arithmetic::Modulo,
+            ) => 1,
+            Operator::Boolean(_) => 2,
+        }
+    }
+}
+
+impl Display for ExpressionNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_expression(f, self, 0)
+    }
+}
+
+impl Display for Primitive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Primitive::Boolean(boolean) => write!(f, "{}", boolean),
+            Primitive::Number(number) => write!(f, "{}", number),
+            Primitive::Char(char) => write!(f, "'{}'", char),
+            Primitive::String(string) => write!(f, "\"{}\"", string),
+        }
+    }
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_operation(f, self, 0)
+    }
+}
+
+impl Display for Operator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Operator::Arithmetic(arithmetic) => write!(f, "{}", arithmetic),
+            Operator::Boolean(boolean) => write!(f, "{}", boolean),
+        }
+    }
+}
+
+impl Display for Arithmetic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Arithmetic::Add => write!(f, "+"),
+            Arithmetic::Subtract => write!(f, "-"),
+            Arithmetic::Multiply => write!(f, "*"),
+            Arithmetic::Divide => write!(f, "/"),
+            Arithmetic::Modulo => write!(f, "%"),
+        }
+    }
+}
+
+impl Display for Boolean {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Boolean::Equal => write!(f, "=="),
+            Boolean::NotEqual => write!(f, "!="),
+            Boolean::Less => write!(f, "<"),
+            Boolean::More => write!(f, ">"),
+            Boolean::LessOrEqual => write!(f, "<="),
+            Boolean::MoreOrEqual => write!(f, ">="),
+        }
+    }
+}
+
+impl Display for FunctionCall {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_function_call(f, self, 0)
+    }
+}
+
+impl Display for Conditional {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_conditional(f, self, 0)
+    }
+}
+
+pub(super) fn write_expression(
+    f: &mut Formatter<'_>,
+    expression: &ExpressionNode,
+    indent: usize,
+) -> Result {
+    match expression {
+        ExpressionNode::Primitive(primitive) => write!(f, "{}", primitive),
+        ExpressionNode::VariableReference(name) => write!(f, "{}", name),
+        ExpressionNode::FieldAccess { structure, field } => {
+            write_expression(f, structure, indent)?;
+            write!(f, ".{}", field)
+        }
+        ExpressionNode::FunctionCall(call) => write_function_call(f, call, indent),
+        ExpressionNode::Parenthesis(inner) => {
+            write!(f, "(")?;
+            write_expression(f, inner, indent)?;
+            write!(f, ")")
+        }
+        ExpressionNode::Operation(operation) => write_operation(f, operation, indent),
+        ExpressionNode::Object { r#type, fields } => {
+            let mut fields = fields.iter().collect::<Vec<_>>();
+            fields.sort_by_key(|(name, _)| *name);
+
+            write!(f, "{} {{", r#type)?;
+            for (i, (name, value)) in fields.iter().enumerate() {
+                write!(f, "{} {}: ", if i == 0 { "" } else { "," }, name)?;
+                write_expression(f, value, indent)?;
+            }
+            write!(f, "{}}}", if fields.is_empty() { "" } else { " " })
+        }
+        ExpressionNode::Closure {
+            parameters,
+            return_type,
+            body,
+        } => {
+            write!(f, "fn(")?;
+            write_parameters(f, parameters)?;
+            write!(f, "): {} ", return_type)?;
+            write_body(f, body, indent)
+        }
+    }
+}
+
+fn write_operation(f: &mut Formatter<'_>, operation: &Operation, indent: usize) -> Result {
+    write_operand(f, &operation.a, Some(operation.operator), indent)?;
+    write!(f, " {} ", operation.operator)?;
+    write_operand(f, &operation.b, None, indent)
+}
+
+/// The parser folds operations from the left, so only a leading operation that
+/// binds tighter than the operator after it can be written without parentheses.
+fn write_operand(
+    f: &mut Formatter<'_>,
+    operand: &ExpressionNode,
+    following: Option<Operator>,
+    indent: usize,
+) -> Result {
+    match operand {
+        ExpressionNode::Operation(operation)
+            if following.is_some_and(|following| {
+                operation.operator.precedence() > following.precedence()
+            }) =>
+        {
+            write_operation(f, operation, indent)
+        }
+        ExpressionNode::Operation(_) => {
+            write!(f, "(")?;
+            write_expression(f, operand, indent)?;
+            write!(f, ")")
+        }
+        _ => write_expression(f, operand, indent),
+    }
+}
+
+pub(super) fn write_function_call(
+    f: &mut Formatter<'_>,
+    call: &FunctionCall,
+    indent: usize,
+) -> Result {
+    write!(f, "{}(", call.name)?;
+    for (i, argument) in call.arguments.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write_expression(f, argument, indent)?;
+    }
+    write!(f, ")")
+}
+
+pub(super) fn write_conditional(
+    f: &mut Formatter<'_>,
+    conditional: &Conditional,
+    indent: usize,
+) -> Result {
+    write!(f, "if ")?;
+    write_condition(f, &conditional.condition, indent)?;
+    write_body(f, &conditional.body, indent)?;
+
+    let mut chained = conditional.chained.as_deref();
+    while let Some(conditional) = chained {
+        if let ExpressionNode::Primitive(Primitive::Boolean(true)) = conditional.condition {
+            write!(f, " el ")?;
+        } else {
+            write!(f, " ef ")?;
+            write_condition(f, &conditional.condition, indent)?;
+        }
+        write_body(f, &conditional.body, indent)?;
+        chained = conditional.chained.as_deref();
+    }
+    Ok(())
+}
+
+pub(super) fn write_condition(
+    f: &mut Formatter<'_>,
+    condition: &ExpressionNode,
+    indent: usize,
+) -> Result {
+    match condition {
+        ExpressionNode::Parenthesis(_) => write_expression(f, condition, indent)?,
+        _ => {
+            write!(f, "(")?;
+            write_expression(f, condition, indent)?;
+            write!(f, ")")?;
+        }
+    }
+    write!(f, " ")
+}
@@ -1,7 +1,23 @@
 pub mod expressions;
 pub mod statements;
 
-use std::fmt::Debug;
+use std::fmt::{Debug, Display};
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+impl Span {
+    pub fn new(start: (usize, usize), end: (usize, usize)) -> Self {
+        Span { start, end }
+    }
+
+    pub fn contains(&self, position: (usize, usize)) -> bool {
+        self.start <= position && position <= self.end
+    }
+}
 
 #[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Type {
@@ -12,4 +28,34 @@ pub enum Type {
     Char,
     String,
     Object(Box<str>),
+    Function {
+        parameters: Box<[Type]>,
+        return_type: Box<Type>,
+    },
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Void => write!(f, "void"),
+            Type::Bool => write!(f, "bool"),
+            Type::Int => write!(f, "int"),
+            Type::Char => write!(f, "char"),
+            Type::String => write!(f, "string"),
+            Type::Object(name) => write!(f, "{}", name),
+            Type::Function {
+                parameters,
+                return_type,
+            } => write!(
+                f,
+                "fn({}): {}",
+                parameters
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                return_type
+            ),
+        }
+    }
 }
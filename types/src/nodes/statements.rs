@@ -1,24 +1,46 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result},
+};
 
 use super::{
-    expressions::{Conditional, ExpressionNode, FunctionCall},
-    Type,
+    expressions::{
+        write_condition, write_conditional, write_expression, write_function_call, Conditional,
+        ExpressionNode, FunctionCall,
+    },
+    Span, Type,
 };
 
 pub type Parameter = (Box<str>, Type);
+pub type ModulePath = Box<[Box<str>]>;
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct FunctionDeclaration {
+    pub public: bool,
+    pub constant: bool,
     pub name: Box<str>,
     pub parameters: Box<[Parameter]>,
     pub return_type: Type,
     pub body: Box<[StatementNode]>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct StructureDeclaration {
+    pub public: bool,
     pub name: Box<str>,
     pub fields: HashMap<Box<str>, Type>,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalDeclaration {
+    pub public: bool,
+    pub constant: bool,
+    pub name: Box<str>,
+    pub r#type: Type,
+    pub value: Box<ExpressionNode>,
+    pub span: Span,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -27,16 +49,149 @@ pub enum StatementNode {
         name: Box<str>,
         r#type: Type,
         value: Box<ExpressionNode>,
+        span: Span,
     },
     VariableAssignment {
         name: Box<str>,
         value: Box<ExpressionNode>,
+        span: Span,
+    },
+    Return {
+        value: Box<ExpressionNode>,
+        span: Span,
     },
-    Return(Box<ExpressionNode>),
     FunctionCall(FunctionCall),
     Conditional(Conditional),
     WhileLoop {
         condition: ExpressionNode,
         body: Box<[StatementNode]>,
+        span: Span,
     },
 }
+
+impl Display for FunctionDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}{}fn {}(",
+            if self.public { "pb " } else { "" },
+            if self.constant { "cn " } else { "" },
+            self.name
+        )?;
+        write_parameters(f, &self.parameters)?;
+        write!(f, "): {} ", self.return_type)?;
+        write_body(f, &self.body, 0)
+    }
+}
+
+impl Display for StructureDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let mut fields = self.fields.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|(name, _)| *name);
+
+        writeln!(
+            f,
+            "{}st {} {{",
+            if self.public { "pb " } else { "" },
+            self.name
+        )?;
+        for (name, r#type) in fields {
+            writeln!(f, "    {}: {},", name, r#type)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+impl Display for GlobalDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}{} {}: {} = {};",
+            if self.public { "pb " } else { "" },
+            if self.constant { "cn" } else { "vr" },
+            self.name,
+            self.r#type,
+            self.value
+        )
+    }
+}
+
+impl StatementNode {
+    pub fn span(&self) -> Span {
+        match self {
+            StatementNode::VariableDeclaration { span, .. }
+            | StatementNode::VariableAssignment { span, .. }
+            | StatementNode::Return { span, .. }
+            | StatementNode::WhileLoop { span, .. }
+            | StatementNode::FunctionCall(FunctionCall { span, .. })
+            | StatementNode::Conditional(Conditional { span, .. }) => *span,
+        }
+    }
+}
+
+impl Display for StatementNode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write_statement(f, self, 0)
+    }
+}
+
+fn write_statement(f: &mut Formatter<'_>, statement: &StatementNode, indent: usize) -> Result {
+    match statement {
+        StatementNode::VariableDeclaration {
+            name,
+            r#type,
+            value,
+            ..
+        } => {
+            write!(f, "vr {}: {} = ", name, r#type)?;
+            write_expression(f, value, indent)?;
+            write!(f, ";")
+        }
+        StatementNode::VariableAssignment { name, value, .. } => {
+            write!(f, "{} = ", name)?;
+            write_expression(f, value, indent)?;
+            write!(f, ";")
+        }
+        StatementNode::Return { value, .. } => {
+            write!(f, "rt ")?;
+            write_expression(f, value, indent)?;
+            write!(f, ";")
+        }
+        StatementNode::FunctionCall(call) => {
+            write_function_call(f, call, indent)?;
+            write!(f, ";")
+        }
+        StatementNode::Conditional(conditional) => write_conditional(f, conditional, indent),
+        StatementNode::WhileLoop {
+            condition, body, ..
+        } => {
+            write!(f, "wl ")?;
+            write_condition(f, condition, indent)?;
+            write_body(f, body, indent)
+        }
+    }
+}
+
+pub(super) fn write_body(f: &mut Formatter<'_>, body: &[StatementNode], indent: usize) -> Result {
+    if body.is_empty() {
+        return write!(f, "{{}}");
+    }
+
+    writeln!(f, "{{")?;
+    for statement in body {
+        write!(f, "{}", "    ".repeat(indent + 1))?;
+        write_statement(f, statement, indent + 1)?;
+        writeln!(f)?;
+    }
+    write!(f, "{}}}", "    ".repeat(indent))
+}
+
+pub(super) fn write_parameters(f: &mut Formatter<'_>, parameters: &[Parameter]) -> Result {
+    for (i, (name, r#type)) in parameters.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write!(f, "{}: {}", name, r#type)?;
+    }
+    Ok(())
+}
@@ -24,6 +24,7 @@ pub enum TokenType {
     Dot,
     Semicolon,
     Colon,
+    Comment(Box<str>),
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -36,4 +37,7 @@ pub enum Keyword {
     ElseIf,
     Else,
     While,
+    Use,
+    Public,
+    Constant,
 }